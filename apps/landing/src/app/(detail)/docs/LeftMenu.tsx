@@ -130,10 +130,18 @@ export function LeftMenu() {
             to: '/docs/devup/shadow',
             children: 'Shadow',
           },
+          {
+            to: '/docs/devup/tokens',
+            children: 'Other Tokens',
+          },
           {
             to: '/docs/devup/breakpoints',
             children: 'Breakpoints',
           },
+          {
+            to: '/docs/devup/tailwind',
+            children: 'Tailwind',
+          },
           {
             to: '/docs/devup/figma-plugin',
             children: 'Figma Plugin',
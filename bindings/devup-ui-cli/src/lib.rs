@@ -0,0 +1,578 @@
+use clap::Parser;
+use css::class_map::with_class_map;
+use css::file_map::with_file_map;
+use extractor::{ExtractInput, ExtractOption, ImportAlias, extract_batch, parse_custom_components};
+use sheet::StyleSheet;
+use sheet::format::CssFormat;
+use sheet::theme::Theme;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Extract a whole source tree without a JS bundler
+///
+/// Produces the same code, CSS and maps as the wasm binding driven by the
+/// bundler plugins, so the output can be diffed against a bundler build.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "devup-ui", version, about)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// Source directory to extract
+    #[arg(default_value = "src")]
+    pub src: PathBuf,
+    /// Directory receiving the transformed code, `sheet.json`, `classMap.json` and `fileMap.json`
+    #[arg(long, default_value = "df")]
+    pub out_dir: PathBuf,
+    /// Directory receiving `devup-ui.css` and the per-file CSS (defaults to `<out-dir>/devup-ui`)
+    #[arg(long)]
+    pub css_dir: Option<PathBuf>,
+    /// Path of `devup.json`
+    #[arg(long, default_value = "devup.json")]
+    pub config: PathBuf,
+    /// Package whose imports are extracted
+    #[arg(long, default_value = "@devup-ui/react")]
+    pub package: String,
+    /// Emit every style into `devup-ui.css`
+    #[arg(long)]
+    pub single_css: bool,
+    /// Fail instead of falling back to CSS variables for dynamic values
+    #[arg(long)]
+    pub strict: bool,
+    /// Class name prefix
+    #[arg(long)]
+    pub prefix: Option<String>,
+    /// Emit readable class names
+    #[arg(long)]
+    pub debug: bool,
+    /// Write a source map next to every CSS file
+    #[arg(long)]
+    pub source_map: bool,
+    /// Format of the written CSS: `minified` or `pretty`
+    #[arg(long, default_value = "minified")]
+    pub css_format: CssFormat,
+}
+
+/// Result of a whole-project extraction
+#[derive(Debug, Default)]
+pub struct Report {
+    /// Number of extracted source files
+    pub files: usize,
+    /// Number of written CSS files
+    pub css_files: usize,
+    /// Diagnostics of every file, formatted as `filename: diagnostic`
+    pub diagnostics: Vec<String>,
+}
+
+struct SourceFile {
+    filename: String,
+    code: String,
+    out_file: PathBuf,
+    css_dir: String,
+}
+
+/// Extract every source file under `options.src` in parallel and write the output
+pub fn run(options: &Options) -> Result<Report, Box<dyn Error>> {
+    css::debug::set_debug(options.debug);
+    css::set_prefix(options.prefix.clone());
+
+    let (theme, components) = load_config(&options.config)?;
+    let mut sheet = StyleSheet {
+        format: options.css_format,
+        ..StyleSheet::default()
+    };
+    sheet.set_theme(theme);
+
+    let out_dir = std::path::absolute(&options.out_dir)?;
+    let css_dir = match &options.css_dir {
+        Some(css_dir) => std::path::absolute(css_dir)?,
+        None => out_dir.join("devup-ui"),
+    };
+    let cwd = std::env::current_dir()?;
+    let mut report = Report::default();
+
+    let mut files = vec![];
+    for path in list_source_files(&options.src) {
+        let relative = path.strip_prefix(&options.src).unwrap_or(&path);
+        let out_file = out_dir.join(relative);
+        let out_parent = out_file.parent().unwrap_or(&out_dir);
+        let mut rel_css_dir = to_posix(&relative_path(out_parent, &css_dir));
+        if !rel_css_dir.starts_with("./") && !rel_css_dir.starts_with("../") {
+            rel_css_dir = format!("./{rel_css_dir}");
+        }
+        files.push(SourceFile {
+            // Keyed like the bundler plugins: POSIX path relative to the working directory
+            filename: to_posix(&relative_path(&cwd, &std::path::absolute(&path)?)),
+            code: fs::read_to_string(&path)?,
+            out_file,
+            css_dir: rel_css_dir,
+        });
+    }
+
+    let option = ExtractOption {
+        package: options.package.clone(),
+        css_dir: String::new(),
+        single_css: options.single_css,
+        import_main_css: false,
+        import_aliases: default_import_aliases(),
+        strict: options.strict,
+        components,
+    };
+    let outputs = extract_batch(
+        &files
+            .iter()
+            .map(|file| ExtractInput {
+                filename: &file.filename,
+                code: &file.code,
+                option: ExtractOption {
+                    css_dir: file.css_dir.clone(),
+                    ..option.clone()
+                },
+            })
+            .collect::<Vec<_>>(),
+    );
+    for (file, output) in files.iter().zip(outputs) {
+        let output = output.map_err(|error| format!("{}: {error}", file.filename))?;
+        report.diagnostics.extend(
+            output
+                .diagnostics
+                .iter()
+                .map(|diagnostic| format!("{}: {diagnostic}", file.filename)),
+        );
+        sheet.update_file(&output.styles, &file.filename, options.single_css, true);
+
+        if let Some(parent) = file.out_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&file.out_file, output.code)?;
+        report.files += 1;
+    }
+
+    // Emitted after the whole tree so every bucket contains all of its members
+    fs::create_dir_all(&css_dir)?;
+    let source_root = options
+        .source_map
+        .then(|| to_posix(&relative_path(&css_dir, &cwd)) + "/");
+    write_css(
+        &sheet,
+        &css_dir,
+        "devup-ui.css",
+        None,
+        false,
+        source_root.as_deref(),
+    )?;
+    report.css_files += 1;
+    if !options.single_css {
+        let mut files = with_file_map(|map| {
+            map.iter()
+                .map(|(filename, num)| (*num, filename.clone()))
+                .collect::<Vec<_>>()
+        });
+        files.sort();
+        for (num, filename) in files {
+            write_css(
+                &sheet,
+                &css_dir,
+                &format!("devup-ui-{num}.css"),
+                Some(&filename),
+                true,
+                source_root.as_deref(),
+            )?;
+            report.css_files += 1;
+        }
+    }
+
+    fs::write(out_dir.join("sheet.json"), serde_json::to_string(&sheet)?)?;
+    fs::write(
+        out_dir.join("classMap.json"),
+        with_class_map(serde_json::to_string)?,
+    )?;
+    fs::write(
+        out_dir.join("fileMap.json"),
+        with_file_map(serde_json::to_string)?,
+    )?;
+    Ok(report)
+}
+
+/// Write `name` into `css_dir`, with a source map whose sources are resolved
+/// against `source_root` when given
+fn write_css(
+    sheet: &StyleSheet,
+    css_dir: &Path,
+    name: &str,
+    filename: Option<&str>,
+    import_main_css: bool,
+    source_root: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let Some(source_root) = source_root else {
+        fs::write(
+            css_dir.join(name),
+            sheet.create_css(filename, import_main_css),
+        )?;
+        return Ok(());
+    };
+    let (css, map) = sheet.create_css_with_source_map(filename, import_main_css);
+    let mut map: serde_json::Value = serde_json::from_str(&map)?;
+    map["file"] = name.into();
+    map["sourceRoot"] = source_root.into();
+    fs::write(
+        css_dir.join(name),
+        format!("{css}\n/*# sourceMappingURL={name}.map */"),
+    )?;
+    fs::write(css_dir.join(format!("{name}.map")), map.to_string())?;
+    Ok(())
+}
+
+/// Read the `theme` and `components` sections of `devup.json`, if it exists
+fn load_config(
+    path: &Path,
+) -> Result<(Theme, HashMap<String, extractor::CustomComponent>), Box<dyn Error>> {
+    if !path.exists() {
+        return Ok((Theme::default(), HashMap::new()));
+    }
+    let config: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let theme = match config.get("theme") {
+        Some(theme) => serde_json::from_value(theme.clone())?,
+        None => Theme::default(),
+    };
+    let components = match config.get("components") {
+        Some(components) => parse_custom_components(components)?,
+        None => HashMap::new(),
+    };
+    Ok((theme, components))
+}
+
+/// Same defaults as `mergeImportAliases` in `@devup-ui/plugin-utils`
+fn default_import_aliases() -> HashMap<String, ImportAlias> {
+    HashMap::from([
+        (
+            "@emotion/styled".to_string(),
+            ImportAlias::DefaultToNamed("styled".to_string()),
+        ),
+        (
+            "styled-components".to_string(),
+            ImportAlias::DefaultToNamed("styled".to_string()),
+        ),
+        (
+            "@vanilla-extract/css".to_string(),
+            ImportAlias::NamedToNamed,
+        ),
+    ])
+}
+
+/// Source files in a stable order, skipping `node_modules` and test files
+fn list_source_files(src: &Path) -> Vec<PathBuf> {
+    WalkDir::new(src)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != "node_modules")
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| is_source_file(path))
+        .collect()
+}
+
+fn is_source_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let Some((stem, ext)) = name.rsplit_once('.') else {
+        return false;
+    };
+    matches!(
+        ext,
+        "ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs"
+    ) && !matches!(
+        Path::new(stem).extension().and_then(|ext| ext.to_str()),
+        Some("test" | "spec" | "d")
+    )
+}
+
+/// Lexical relative path from the `from` directory to `to`
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from = normalize(from);
+    let to = normalize(to);
+    let common = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut path = PathBuf::new();
+    for _ in common..from.len() {
+        path.push("..");
+    }
+    for component in &to[common..] {
+        path.push(component);
+    }
+    path
+}
+
+fn normalize(path: &Path) -> Vec<Component<'_>> {
+    let mut components = vec![];
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if matches!(components.last(), Some(Component::Normal(_))) => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    components
+}
+
+fn to_posix(path: &Path) -> String {
+    path.iter()
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use css::class_map::reset_class_map;
+    use css::file_map::reset_file_map;
+    use insta::assert_debug_snapshot;
+    use serial_test::serial;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn options(root: &Path) -> Options {
+        Options {
+            src: root.join("src"),
+            out_dir: root.join("df"),
+            css_dir: None,
+            config: root.join("devup.json"),
+            package: "@devup-ui/react".to_string(),
+            single_css: false,
+            strict: false,
+            prefix: None,
+            debug: false,
+            source_map: false,
+            css_format: CssFormat::Minified,
+        }
+    }
+
+    #[test]
+    fn test_is_source_file() {
+        assert!(is_source_file(Path::new("src/page.tsx")));
+        assert!(is_source_file(Path::new("src/util.mjs")));
+        assert!(!is_source_file(Path::new("src/page.test.tsx")));
+        assert!(!is_source_file(Path::new("src/page.spec.ts")));
+        assert!(is_source_file(Path::new("src/util.cjs")));
+        assert!(is_source_file(Path::new("src/util.mts")));
+        assert!(is_source_file(Path::new("src/util.cts")));
+        assert!(!is_source_file(Path::new("src/types.d.ts")));
+        assert!(!is_source_file(Path::new("src/types.d.mts")));
+        assert!(!is_source_file(Path::new("src/types.d.cts")));
+        assert!(is_source_file(Path::new("src/d.ts")));
+        assert!(!is_source_file(Path::new("src/style.css")));
+        assert!(!is_source_file(Path::new("README")));
+    }
+
+    #[test]
+    fn test_relative_path() {
+        assert_eq!(
+            to_posix(&relative_path(
+                Path::new("df/a/b"),
+                Path::new("df/devup-ui")
+            )),
+            "../../devup-ui"
+        );
+        assert_eq!(
+            to_posix(&relative_path(Path::new("df"), Path::new("df/devup-ui"))),
+            "devup-ui"
+        );
+        assert_eq!(
+            to_posix(&relative_path(
+                Path::new("/root/./df"),
+                Path::new("/root/x/../css")
+            )),
+            "../css"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_run() {
+        reset_class_map();
+        reset_file_map();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "devup.json",
+            r##"{"theme":{"colors":{"default":{"primary":"#000"}}},"components":{"HStack":{"tag":"div","styles":{"display":"flex"}}}}"##,
+        );
+        write(
+            root,
+            "src/index.tsx",
+            "import { Box } from '@devup-ui/react'\nexport default () => <Box color=\"$primary\" p={1} />\n",
+        );
+        write(
+            root,
+            "src/components/card.tsx",
+            "import { HStack } from '@devup-ui/react'\nexport const Card = ({ w }) => <HStack w={w} />\n",
+        );
+        write(root, "src/components/card.test.tsx", "throw new Error()\n");
+        write(root, "src/node_modules/a/index.js", "throw new Error()\n");
+
+        let report = run(&options(root)).unwrap();
+        assert_eq!(report.files, 2);
+        assert_eq!(report.css_files, 3);
+        assert_debug_snapshot!(
+            report
+                .diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.rsplit_once(": ").unwrap().1)
+                .collect::<Vec<_>>()
+        );
+
+        let out = root.join("df");
+        assert!(!out.join("components/card.test.tsx").exists());
+        assert!(!out.join("node_modules").exists());
+        let card = fs::read_to_string(out.join("components/card.tsx")).unwrap();
+        assert!(card.contains("\"../devup-ui/devup-ui-"));
+        let index = fs::read_to_string(out.join("index.tsx")).unwrap();
+        assert!(index.contains("\"./devup-ui/devup-ui-"));
+        assert_debug_snapshot!(fs::read_to_string(out.join("devup-ui/devup-ui.css")).unwrap());
+        assert!(out.join("sheet.json").exists());
+        assert_eq!(
+            fs::read_to_string(out.join("classMap.json")).unwrap(),
+            with_class_map(serde_json::to_string).unwrap()
+        );
+        assert_eq!(
+            fs::read_to_string(out.join("fileMap.json")).unwrap(),
+            with_file_map(serde_json::to_string).unwrap()
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_strict() {
+        reset_class_map();
+        reset_file_map();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "src/index.tsx",
+            "import { Box } from '@devup-ui/react'\nexport default ({ w }) => <Box w={w} />\n",
+        );
+        let error = run(&Options {
+            strict: true,
+            ..options(root)
+        })
+        .unwrap_err()
+        .to_string();
+        assert!(error.ends_with("index.tsx: error[dynamic-style] `width` is not static (`w`), falling back to a CSS variable (72..73)"), "{error}");
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_single_css() {
+        reset_class_map();
+        reset_file_map();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "src/index.tsx",
+            "import { Box } from '@devup-ui/react'\nexport default () => <Box p={1} />\n",
+        );
+        let report = run(&Options {
+            single_css: true,
+            css_dir: Some(root.join("css")),
+            ..options(root)
+        })
+        .unwrap();
+        assert_eq!(report.css_files, 1);
+        assert!(
+            fs::read_to_string(root.join("df/index.tsx"))
+                .unwrap()
+                .contains("\"../css/devup-ui.css\"")
+        );
+        assert_debug_snapshot!(fs::read_to_string(root.join("css/devup-ui.css")).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_source_map() {
+        reset_class_map();
+        reset_file_map();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "src/index.tsx",
+            "import { Box } from '@devup-ui/react'\nexport default () => <Box p={1} />\n",
+        );
+        run(&Options {
+            source_map: true,
+            ..options(root)
+        })
+        .unwrap();
+        let css_dir = root.join("df/devup-ui");
+        assert!(
+            fs::read_to_string(css_dir.join("devup-ui-0.css"))
+                .unwrap()
+                .ends_with("\n/*# sourceMappingURL=devup-ui-0.css.map */")
+        );
+        let map: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(css_dir.join("devup-ui-0.css.map")).unwrap())
+                .unwrap();
+        assert_eq!(map["file"], "devup-ui-0.css");
+        assert_eq!(map["mappings"], "6FAC0B");
+        let source = root.join("src/index.tsx");
+        assert_eq!(
+            normalize(&css_dir.join(format!(
+                "{}{}",
+                map["sourceRoot"].as_str().unwrap(),
+                map["sources"][0].as_str().unwrap()
+            ))),
+            normalize(&source)
+        );
+        assert!(css_dir.join("devup-ui.css.map").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_pretty() {
+        reset_class_map();
+        reset_file_map();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "src/index.tsx",
+            "import { Box } from '@devup-ui/react'\nexport default () => <Box p={1} />\n",
+        );
+        run(&Options {
+            css_format: CssFormat::Pretty,
+            ..options(root)
+        })
+        .unwrap();
+        assert!(
+            fs::read_to_string(root.join("df/devup-ui/devup-ui-0.css"))
+                .unwrap()
+                .ends_with(".a-a {\n  padding: 4px;\n}\n")
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "devup.json", r#"{"components":{"A":{"tag":1}}}"#);
+        assert!(run(&options(root)).is_err());
+    }
+}
@@ -0,0 +1,22 @@
+use clap::Parser;
+use devup_ui::{Options, run};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match run(&Options::parse()) {
+        Ok(report) => {
+            for diagnostic in &report.diagnostics {
+                eprintln!("{diagnostic}");
+            }
+            println!(
+                "[devup-ui] extracted {} files into {} css files",
+                report.files, report.css_files
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("[devup-ui] {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
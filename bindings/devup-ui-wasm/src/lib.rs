@@ -1,36 +1,29 @@
-use css::class_map::{set_class_map, with_class_map};
-use css::file_map::{
-    canonical, is_global, set_canonical_map, set_file_map, with_canonical_map, with_file_map,
+use extractor::{
+    CustomComponent, ExtractOption, ImportAlias, has_devup_ui, parse_custom_components,
 };
-use extractor::extract_style::extract_style_value::ExtractStyleValue;
-use extractor::{ExtractOption, ImportAlias, extract, has_devup_ui};
-use rustc_hash::FxHashSet;
-use sheet::StyleSheet;
-use std::collections::HashMap;
+use sheet::dtcg::theme_from_tokens;
+use sheet::format::CssFormat;
+use sheet::theme::ThemeDiff;
+use sheet::{StyleSheet, TokenInterfaceNames};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
-use std::sync::{LazyLock, Mutex};
+use std::sync::{LazyLock, Mutex, PoisonError};
 use wasm_bindgen::prelude::*;
 
-static GLOBAL_STYLE_SHEET: LazyLock<Mutex<StyleSheet>> =
-    LazyLock::new(|| Mutex::new(StyleSheet::default()));
-
-fn with_style_sheet<F, R>(f: F) -> R
-where
-    F: FnOnce(&StyleSheet) -> R,
-{
-    let guard = GLOBAL_STYLE_SHEET
-        .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
-    f(&guard)
-}
+/// The context the free functions operate on
+///
+/// Its naming state is the one of `css`'s free accessors, so the free
+/// functions and the other crates' defaults agree.
+static GLOBAL_CONTEXT: LazyLock<Mutex<DevupContext>> =
+    LazyLock::new(|| Mutex::new(DevupContext::shared()));
 
-fn with_style_sheet_mut<F, R>(f: F) -> R
+fn with_global_context<F, R>(f: F) -> R
 where
-    F: FnOnce(&mut StyleSheet) -> R,
+    F: FnOnce(&mut DevupContext) -> R,
 {
-    let mut guard = GLOBAL_STYLE_SHEET
+    let mut guard = GLOBAL_CONTEXT
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
+        .unwrap_or_else(PoisonError::into_inner);
     f(&mut guard)
 }
 
@@ -46,6 +39,69 @@ pub struct Output {
     css_file: Option<String>,
     updated_base_style: bool,
     css: Option<String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// A prop or expression that could not be statically extracted
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Diagnostic {
+    severity: String,
+    code: String,
+    message: String,
+    start: u32,
+    end: u32,
+}
+
+impl From<extractor::diagnostic::Diagnostic> for Diagnostic {
+    fn from(diagnostic: extractor::diagnostic::Diagnostic) -> Self {
+        Self {
+            severity: diagnostic.severity.to_string(),
+            code: diagnostic.code.to_string(),
+            message: diagnostic.message,
+            start: diagnostic.span.start,
+            end: diagnostic.span.end,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Diagnostic {
+    /// `warning` or `error`
+    #[wasm_bindgen(getter, js_name = "severity")]
+    #[must_use]
+    pub fn severity(&self) -> String {
+        self.severity.clone()
+    }
+
+    /// Stable kebab-case identifier (e.g. `dynamic-style`)
+    #[wasm_bindgen(getter, js_name = "code")]
+    #[must_use]
+    pub fn code(&self) -> String {
+        self.code.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "message")]
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// Start byte offset in the source
+    #[wasm_bindgen(getter, js_name = "start")]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// End byte offset in the source
+    #[wasm_bindgen(getter, js_name = "end")]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn end(&self) -> u32 {
+        self.end
+    }
 }
 // #[wasm_bindgen]
 // extern "C" {
@@ -61,53 +117,6 @@ pub struct Output {
 
 #[wasm_bindgen]
 impl Output {
-    fn new(
-        code: String,
-        styles: FxHashSet<ExtractStyleValue>,
-        map: Option<String>,
-        single_css: bool,
-        filename: String,
-        css_file: Option<String>,
-        import_main_css: bool,
-    ) -> Self {
-        // Use the bucket identity (single-importer collapse) so the sheet's CSS
-        // naming + property bucket + emitted chunk match the canonical class names
-        // the extractor already baked into `code`. Identity when no map is loaded.
-        // Global (shared-chunk) files are treated like single-css: emitted into the
-        // global bucket (devup-ui.css) with prefix-less naming.
-        let canonical_filename = canonical(&filename);
-        let global = single_css || is_global(&filename);
-        with_style_sheet_mut(|sheet| {
-            // globalCss (@font-face / global selectors) is per-SOURCE-file, never
-            // collapsed. rm_global_css MUST use the RAW filename so a collapsed
-            // member (sharing the bucket-root's canonical) never wipes the root's
-            // globalCss. Atom property bucketing still uses canonical_filename.
-            let default_collected = sheet.rm_global_css(&filename, global);
-            let (collected, updated_base_style) =
-                sheet.update_styles(&styles, &canonical_filename, global);
-            Self {
-                code,
-                map,
-                css_file,
-                updated_base_style: updated_base_style || default_collected,
-                css: {
-                    if !collected && !default_collected {
-                        None
-                    } else {
-                        Some(sheet.create_css(
-                            if global {
-                                None
-                            } else {
-                                Some(&canonical_filename)
-                            },
-                            import_main_css,
-                        ))
-                    }
-                },
-            }
-        })
-    }
-
     /// Get the code
     #[wasm_bindgen(getter, js_name = "code")]
     #[must_use]
@@ -140,17 +149,24 @@ impl Output {
     pub fn css(&self) -> Option<String> {
         self.css.clone()
     }
+
+    /// Get the props and expressions that could not be statically extracted
+    #[wasm_bindgen(getter, js_name = "diagnostics")]
+    #[must_use]
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics.clone()
+    }
 }
 
 #[wasm_bindgen(js_name = "setDebug")]
 pub fn set_debug(debug: bool) {
-    css::debug::set_debug(debug);
+    with_global_context(|context| context.set_debug(debug));
 }
 
 #[wasm_bindgen(js_name = "isDebug")]
 #[must_use]
 pub fn is_debug() -> bool {
-    css::debug::is_debug()
+    with_global_context(|context| context.is_debug())
 }
 
 /// Set the CSS class name prefix
@@ -195,31 +211,29 @@ pub fn is_debug() -> bool {
 /// ```
 #[wasm_bindgen(js_name = "setPrefix")]
 pub fn set_prefix(prefix: Option<String>) {
-    css::set_prefix(prefix);
+    with_global_context(|context| context.set_prefix(prefix));
 }
 
 #[wasm_bindgen(js_name = "getPrefix")]
 #[must_use]
 pub fn get_prefix() -> Option<String> {
-    css::get_prefix()
+    with_global_context(|context| context.get_prefix())
 }
 
 /// Internal function to import a `StyleSheet` (testable without `JsValue`)
 pub fn import_sheet_internal(sheet: StyleSheet) {
-    with_style_sheet_mut(|global_sheet| *global_sheet = sheet);
+    with_global_context(|context| context.import_sheet_internal(sheet));
 }
 
 #[wasm_bindgen(js_name = "importSheet")]
 #[cfg(not(tarpaulin_include))]
 pub fn import_sheet(sheet_object: JsValue) -> Result<(), JsValue> {
-    let sheet: StyleSheet = serde_wasm_bindgen::from_value(sheet_object).map_err(js_error)?;
-    import_sheet_internal(sheet);
-    Ok(())
+    with_global_context(|context| context.import_sheet(sheet_object))
 }
 
 /// Internal function to export `StyleSheet` as JSON string (testable without `JsValue`)
 pub fn export_sheet_internal() -> Result<String, String> {
-    with_style_sheet(serde_json::to_string).map_err(|e| e.to_string())
+    with_global_context(|context| context.export_sheet_internal())
 }
 
 #[wasm_bindgen(js_name = "exportSheet")]
@@ -230,14 +244,13 @@ pub fn export_sheet() -> Result<String, JsValue> {
 
 /// Internal function to export class map as JSON string (testable without `JsValue`)
 pub fn export_class_map_internal() -> Result<String, String> {
-    with_class_map(serde_json::to_string).map_err(|e| e.to_string())
+    with_global_context(|context| context.export_class_map_internal())
 }
 
 #[wasm_bindgen(js_name = "importClassMap")]
 #[cfg(not(tarpaulin_include))]
 pub fn import_class_map(sheet_object: JsValue) -> Result<(), JsValue> {
-    set_class_map(serde_wasm_bindgen::from_value(sheet_object).map_err(js_error)?);
-    Ok(())
+    with_global_context(|context| context.import_class_map(sheet_object))
 }
 
 #[wasm_bindgen(js_name = "exportClassMap")]
@@ -248,14 +261,13 @@ pub fn export_class_map() -> Result<String, JsValue> {
 
 /// Internal function to export file map as JSON string (testable without `JsValue`)
 pub fn export_file_map_internal() -> Result<String, String> {
-    with_file_map(serde_json::to_string).map_err(|e| e.to_string())
+    with_global_context(|context| context.export_file_map_internal())
 }
 
 #[wasm_bindgen(js_name = "importFileMap")]
 #[cfg(not(tarpaulin_include))]
 pub fn import_file_map(sheet_object: JsValue) -> Result<(), JsValue> {
-    set_file_map(serde_wasm_bindgen::from_value(sheet_object).map_err(js_error)?);
-    Ok(())
+    with_global_context(|context| context.import_file_map(sheet_object))
 }
 
 #[wasm_bindgen(js_name = "exportFileMap")]
@@ -266,19 +278,18 @@ pub fn export_file_map() -> Result<String, JsValue> {
 
 /// Internal function to import the canonical (bucket) map (testable without `JsValue`)
 pub fn import_canonical_map_internal(map: HashMap<String, String>) {
-    set_canonical_map(map);
+    with_global_context(|context| context.import_canonical_map_internal(map));
 }
 
 /// Internal function to export the canonical map as JSON string (testable without `JsValue`)
 pub fn export_canonical_map_internal() -> Result<String, String> {
-    with_canonical_map(serde_json::to_string).map_err(|e| e.to_string())
+    with_global_context(|context| context.export_canonical_map_internal())
 }
 
 #[wasm_bindgen(js_name = "importCanonicalMap")]
 #[cfg(not(tarpaulin_include))]
 pub fn import_canonical_map(map_object: JsValue) -> Result<(), JsValue> {
-    set_canonical_map(serde_wasm_bindgen::from_value(map_object).map_err(js_error)?);
-    Ok(())
+    with_global_context(|context| context.import_canonical_map(map_object))
 }
 
 #[wasm_bindgen(js_name = "exportCanonicalMap")]
@@ -299,12 +310,12 @@ pub fn export_canonical_map() -> Result<String, JsValue> {
 /// Pair with `importFileRoutes` to provide the file -> routes mapping.
 #[wasm_bindgen(js_name = "setAtomHoist")]
 pub fn set_atom_hoist(threshold: Option<usize>) {
-    css::atom_hoist::set_atom_hoist(threshold);
+    with_global_context(|context| context.set_atom_hoist(threshold));
 }
 
 /// Internal function to import the file -> routes map (testable without `JsValue`)
-pub fn import_file_routes_internal(map: HashMap<String, std::collections::HashSet<u32>>) {
-    css::file_routes::set_file_routes(map);
+pub fn import_file_routes_internal(map: HashMap<String, HashSet<u32>>) {
+    with_global_context(|context| context.import_file_routes_internal(map));
 }
 
 /// Import the file -> set-of-route-ids mapping used to decide atom hoisting.
@@ -315,14 +326,11 @@ pub fn import_file_routes_internal(map: HashMap<String, std::collections::HashSe
 #[wasm_bindgen(js_name = "importFileRoutes")]
 #[cfg(not(tarpaulin_include))]
 pub fn import_file_routes(map_object: JsValue) -> Result<(), JsValue> {
-    css::file_routes::set_file_routes(
-        serde_wasm_bindgen::from_value(map_object).map_err(js_error)?,
-    );
-    Ok(())
+    with_global_context(|context| context.import_file_routes(map_object))
 }
 
 /// Internal function to extract code (testable without `JsValue`)
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 pub fn code_extract_internal(
     filename: &str,
     code: &str,
@@ -332,29 +340,21 @@ pub fn code_extract_internal(
     import_main_css_in_code: bool,
     import_main_css_in_css: bool,
     import_aliases: HashMap<String, ImportAlias>,
+    strict: bool,
 ) -> Result<Output, String> {
-    match extract(
-        filename,
-        code,
-        ExtractOption {
-            package: package.to_string(),
+    with_global_context(|context| {
+        context.code_extract_internal(
+            filename,
+            code,
+            package,
             css_dir,
             single_css,
-            import_main_css: import_main_css_in_code,
-            import_aliases,
-        },
-    ) {
-        Ok(output) => Ok(Output::new(
-            output.code,
-            output.styles,
-            output.map,
-            single_css,
-            filename.to_string(),
-            output.css_file,
+            import_main_css_in_code,
             import_main_css_in_css,
-        )),
-        Err(error) => Err(error.to_string()),
-    }
+            import_aliases,
+            strict,
+        )
+    })
 }
 
 #[wasm_bindgen(js_name = "codeExtract")]
@@ -369,14 +369,32 @@ pub fn code_extract(
     import_main_css_in_code: bool,
     import_main_css_in_css: bool,
     import_aliases: JsValue,
+    strict: Option<bool>,
 ) -> Result<Output, JsValue> {
+    with_global_context(|context| {
+        context.code_extract(
+            filename,
+            code,
+            package,
+            css_dir,
+            single_css,
+            import_main_css_in_code,
+            import_main_css_in_css,
+            import_aliases,
+            strict,
+        )
+    })
+}
+
+#[cfg(not(tarpaulin_include))]
+fn parse_import_aliases(import_aliases: JsValue) -> Result<HashMap<String, ImportAlias>, JsValue> {
     // Deserialize import_aliases from JsValue
     // Format: { "package": "namedExport" } or { "package": null } for named exports
     let aliases: HashMap<String, Option<String>> =
         serde_wasm_bindgen::from_value(import_aliases).map_err(js_error)?;
 
     // Convert to ImportAlias enum
-    let import_aliases: HashMap<String, ImportAlias> = aliases
+    Ok(aliases
         .into_iter()
         .map(|(k, v)| {
             let alias = match v {
@@ -385,60 +403,97 @@ pub fn code_extract(
             };
             (k, alias)
         })
-        .collect();
-
-    code_extract_internal(
-        filename,
-        code,
-        package,
-        css_dir,
-        single_css,
-        import_main_css_in_code,
-        import_main_css_in_css,
-        import_aliases,
-    )
-    .map_err(js_error)
+        .collect())
 }
 
 /// Internal function to register theme (testable without `JsValue`)
 pub fn register_theme_internal(theme: sheet::theme::Theme) {
-    with_style_sheet_mut(|sheet| sheet.set_theme(theme));
+    with_global_context(|context| context.register_theme_internal(theme));
 }
 
 #[wasm_bindgen(js_name = "registerTheme")]
 #[cfg(not(tarpaulin_include))]
 pub fn register_theme(theme_object: JsValue) -> Result<(), JsValue> {
-    let theme: sheet::theme::Theme =
-        serde_wasm_bindgen::from_value(theme_object).map_err(js_error)?;
-    register_theme_internal(theme);
-    Ok(())
+    with_global_context(|context| context.register_theme(theme_object))
+}
+
+/// Register a theme from W3C Design Tokens (DTCG) JSON
+///
+/// # Example
+/// ```javascript
+/// registerThemeFromTokens({ color: { $type: 'color', primary: { $value: '#36f' } } })
+/// ```
+#[wasm_bindgen(js_name = "registerThemeFromTokens")]
+#[cfg(not(tarpaulin_include))]
+pub fn register_theme_from_tokens(tokens_object: JsValue) -> Result<(), JsValue> {
+    with_global_context(|context| context.register_theme_from_tokens(tokens_object))
+}
+
+/// Internal function to register custom components (testable without `JsValue`)
+pub fn register_components_internal(components: HashMap<String, CustomComponent>) {
+    with_global_context(|context| context.register_components_internal(components));
+}
+
+/// Register the `components` section of `devup.json`
+///
+/// # Example
+/// ```javascript
+/// registerComponents({ HStack: { tag: 'div', styles: { display: 'flex', gap: 2 } } })
+/// ```
+#[wasm_bindgen(js_name = "registerComponents")]
+#[cfg(not(tarpaulin_include))]
+pub fn register_components(components_object: JsValue) -> Result<(), JsValue> {
+    with_global_context(|context| context.register_components(components_object))
 }
 
 #[wasm_bindgen(js_name = "getDefaultTheme")]
 #[cfg(not(tarpaulin_include))]
 pub fn get_default_theme() -> Result<Option<String>, JsValue> {
-    Ok(with_style_sheet(|sheet| sheet.theme.get_default_theme()))
+    Ok(with_global_context(|context| context.get_default_theme()))
 }
 
+/// Set the format `getCss` returns: `"minified"` (default) or `"pretty"`
+#[wasm_bindgen(js_name = "setCssFormat")]
+#[cfg(not(tarpaulin_include))]
+pub fn set_css_format(format: &str) -> Result<(), JsValue> {
+    with_global_context(|context| context.set_css_format(format))
+}
+
+/// `format` overrides the one set with `setCssFormat` for this call
 #[wasm_bindgen(js_name = "getCss")]
 #[cfg(not(tarpaulin_include))]
-pub fn get_css(file_num: Option<usize>, import_main_css: bool) -> Result<String, JsValue> {
-    Ok(with_style_sheet(|sheet| {
-        if let Some(file_num) = file_num {
-            with_file_map(|map| {
-                sheet.create_css(
-                    map.get_by_right(&file_num).map(String::as_str),
-                    import_main_css,
-                )
-            })
-        } else {
-            sheet.create_css(None, import_main_css)
-        }
-    }))
+pub fn get_css(
+    file_num: Option<usize>,
+    import_main_css: bool,
+    format: Option<String>,
+) -> Result<String, JsValue> {
+    with_global_context(|context| context.get_css(file_num, import_main_css, format))
+}
+
+fn parse_css_format(format: Option<&str>) -> Result<Option<CssFormat>, String> {
+    format.map(str::parse).transpose()
+}
+
+/// Get the source map (v3) of the CSS `getCss` returns for the same arguments
+///
+/// Each rule of an extracted style maps to the JSX prop, styled template or
+/// `css()` call that produced it.
+#[wasm_bindgen(js_name = "getCssSourceMap")]
+#[cfg(not(tarpaulin_include))]
+pub fn get_css_source_map(file_num: Option<usize>, import_main_css: bool) -> String {
+    with_global_context(|context| context.get_css_source_map(file_num, import_main_css))
 }
 
+/// Interface of `@devup-ui/react` augmented with the breakpoint names
+const DEFAULT_BREAKPOINTS_INTERFACE_NAME: &str = "DevupBreakpoints";
+
+/// Interfaces listing the theme tokens
+///
+/// The breakpoint, radius, spacing, z-index, duration, easing and opacity
+/// interfaces default to the ones `@devup-ui/react` declares.
 #[wasm_bindgen(js_name = "getThemeInterface")]
 #[cfg(not(tarpaulin_include))]
+#[allow(clippy::too_many_arguments)]
 pub fn get_theme_interface(
     package_name: &str,
     color_interface_name: &str,
@@ -446,19 +501,123 @@ pub fn get_theme_interface(
     length_interface_name: &str,
     shadows_interface_name: &str,
     theme_interface_name: &str,
+    breakpoints_interface_name: Option<String>,
+    radii_interface_name: Option<String>,
+    spacing_interface_name: Option<String>,
+    z_index_interface_name: Option<String>,
+    durations_interface_name: Option<String>,
+    easings_interface_name: Option<String>,
+    opacity_interface_name: Option<String>,
 ) -> String {
-    with_style_sheet(|sheet| {
-        sheet.create_interface(
+    with_global_context(|context| {
+        context.get_theme_interface(
             package_name,
             color_interface_name,
             typography_interface_name,
             length_interface_name,
             shadows_interface_name,
             theme_interface_name,
+            breakpoints_interface_name,
+            radii_interface_name,
+            spacing_interface_name,
+            z_index_interface_name,
+            durations_interface_name,
+            easings_interface_name,
+            opacity_interface_name,
         )
     })
 }
 
+/// Result of `updateTheme`
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct ThemeUpdate {
+    css: String,
+    diff: ThemeDiff,
+}
+
+impl ThemeUpdate {
+    /// `sheet` with the new theme
+    fn new(sheet: &StyleSheet, diff: ThemeDiff) -> Self {
+        Self {
+            css: sheet.format.apply(&sheet.create_theme_layer()).into_owned(),
+            diff,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl ThemeUpdate {
+    /// The new `@layer t{...}` block, replacing the one `getCss` returned
+    #[wasm_bindgen(getter, js_name = "css")]
+    #[must_use]
+    pub fn css(&self) -> String {
+        self.css.clone()
+    }
+
+    /// Whether tokens were added or removed or the settings changed, so the
+    /// type declarations `getThemeInterface` returns must be rewritten
+    ///
+    /// The flag does not depend on the interface names passed to
+    /// `getThemeInterface`; edits of token values never set it.
+    #[wasm_bindgen(getter, js_name = "interfaceChanged")]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn interface_changed(&self) -> bool {
+        self.diff.settings_changed || !self.diff.added.is_empty() || !self.diff.removed.is_empty()
+    }
+
+    /// Whether the breakpoints or other settings, color or typography names
+    /// or the levels of responsive tokens changed, so the extracted styles are
+    /// stale and the whole CSS must be regenerated with `getCss`
+    #[wasm_bindgen(getter, js_name = "settingsChanged")]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn settings_changed(&self) -> bool {
+        self.diff.settings_changed
+    }
+
+    /// Paths of the added tokens, e.g. `colors.dark.primary`
+    #[wasm_bindgen(getter, js_name = "added")]
+    #[must_use]
+    pub fn added(&self) -> Vec<String> {
+        self.diff.added.clone()
+    }
+
+    /// Paths of the tokens whose value changed
+    #[wasm_bindgen(getter, js_name = "changed")]
+    #[must_use]
+    pub fn changed(&self) -> Vec<String> {
+        self.diff.changed.clone()
+    }
+
+    /// Paths of the removed tokens
+    #[wasm_bindgen(getter, js_name = "removed")]
+    #[must_use]
+    pub fn removed(&self) -> Vec<String> {
+        self.diff.removed.clone()
+    }
+}
+
+/// Internal function to update the theme (testable without `JsValue`)
+pub fn update_theme_internal(theme: sheet::theme::Theme) -> ThemeUpdate {
+    with_global_context(|context| context.update_theme_internal(theme))
+}
+
+/// Replace the registered theme without regenerating the extracted styles
+///
+/// # Example
+/// ```javascript
+/// const update = updateTheme(theme);
+/// if (update.interfaceChanged) writeFileSync(dts, getThemeInterface(...));
+/// replaceThemeLayer(update.css);
+/// ```
+#[wasm_bindgen(js_name = "updateTheme")]
+#[cfg(not(tarpaulin_include))]
+pub fn update_theme(theme_object: JsValue) -> Result<ThemeUpdate, JsValue> {
+    with_global_context(|context| context.update_theme(theme_object))
+}
+
 #[wasm_bindgen(js_name = "hasDevupUI")]
 #[cfg(not(tarpaulin_include))]
 #[must_use]
@@ -466,6 +625,413 @@ pub fn has_devup_ui_wasm(filename: &str, code: &str, package: &str) -> bool {
     has_devup_ui(filename, code, package)
 }
 
+/// Extraction state of one app
+///
+/// The free functions (`codeExtract`, `setPrefix`, `registerTheme`, ...)
+/// operate on one default context. A `DevupContext` owns its own class names,
+/// file numbers, prefix, theme, components and style sheet, so several apps can
+/// be built by one process without their names colliding.
+///
+/// # Example
+/// ```javascript
+/// const app = new DevupContext();
+/// app.setPrefix('app-');
+/// app.registerTheme(theme);
+/// const output = app.codeExtract('src/App.tsx', code, '@devup-ui/react', 'df', false, true, false, {});
+/// const css = app.getCss(null, false);
+/// ```
+#[wasm_bindgen(js_name = "DevupContext")]
+#[derive(Default)]
+pub struct DevupContext {
+    context: sheet::DevupContext,
+    components: HashMap<String, CustomComponent>,
+}
+
+#[wasm_bindgen(js_class = "DevupContext")]
+impl DevupContext {
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[wasm_bindgen(js_name = "setDebug")]
+    pub fn set_debug(&self, debug: bool) {
+        self.context.css_context().set_debug(debug);
+    }
+
+    #[wasm_bindgen(js_name = "isDebug")]
+    #[must_use]
+    pub fn is_debug(&self) -> bool {
+        self.context.css_context().is_debug()
+    }
+
+    #[wasm_bindgen(js_name = "setPrefix")]
+    pub fn set_prefix(&self, prefix: Option<String>) {
+        self.context.css_context().set_prefix(prefix);
+    }
+
+    #[wasm_bindgen(js_name = "getPrefix")]
+    #[must_use]
+    pub fn get_prefix(&self) -> Option<String> {
+        self.context.css_context().prefix()
+    }
+
+    #[wasm_bindgen(js_name = "setAtomHoist")]
+    pub fn set_atom_hoist(&self, threshold: Option<usize>) {
+        self.context.css_context().set_atom_hoist(threshold);
+    }
+
+    #[wasm_bindgen(js_name = "importSheet")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn import_sheet(&mut self, sheet_object: JsValue) -> Result<(), JsValue> {
+        self.import_sheet_internal(serde_wasm_bindgen::from_value(sheet_object).map_err(js_error)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "exportSheet")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn export_sheet(&self) -> Result<String, JsValue> {
+        self.export_sheet_internal().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "importClassMap")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn import_class_map(&self, sheet_object: JsValue) -> Result<(), JsValue> {
+        self.context
+            .css_context()
+            .set_class_map(serde_wasm_bindgen::from_value(sheet_object).map_err(js_error)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "exportClassMap")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn export_class_map(&self) -> Result<String, JsValue> {
+        self.export_class_map_internal().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "importFileMap")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn import_file_map(&self, sheet_object: JsValue) -> Result<(), JsValue> {
+        self.context
+            .css_context()
+            .set_file_map(serde_wasm_bindgen::from_value(sheet_object).map_err(js_error)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "exportFileMap")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn export_file_map(&self) -> Result<String, JsValue> {
+        self.export_file_map_internal().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "importCanonicalMap")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn import_canonical_map(&self, map_object: JsValue) -> Result<(), JsValue> {
+        self.import_canonical_map_internal(
+            serde_wasm_bindgen::from_value(map_object).map_err(js_error)?,
+        );
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "exportCanonicalMap")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn export_canonical_map(&self) -> Result<String, JsValue> {
+        self.export_canonical_map_internal().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "importFileRoutes")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn import_file_routes(&self, map_object: JsValue) -> Result<(), JsValue> {
+        self.import_file_routes_internal(
+            serde_wasm_bindgen::from_value(map_object).map_err(js_error)?,
+        );
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "codeExtract")]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(not(tarpaulin_include))]
+    pub fn code_extract(
+        &mut self,
+        filename: &str,
+        code: &str,
+        package: &str,
+        css_dir: String,
+        single_css: bool,
+        import_main_css_in_code: bool,
+        import_main_css_in_css: bool,
+        import_aliases: JsValue,
+        strict: Option<bool>,
+    ) -> Result<Output, JsValue> {
+        self.code_extract_internal(
+            filename,
+            code,
+            package,
+            css_dir,
+            single_css,
+            import_main_css_in_code,
+            import_main_css_in_css,
+            parse_import_aliases(import_aliases)?,
+            strict.unwrap_or(false),
+        )
+        .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "registerTheme")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn register_theme(&mut self, theme_object: JsValue) -> Result<(), JsValue> {
+        self.register_theme_internal(
+            serde_wasm_bindgen::from_value(theme_object).map_err(js_error)?,
+        );
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "updateTheme")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn update_theme(&mut self, theme_object: JsValue) -> Result<ThemeUpdate, JsValue> {
+        Ok(self
+            .update_theme_internal(serde_wasm_bindgen::from_value(theme_object).map_err(js_error)?))
+    }
+
+    #[wasm_bindgen(js_name = "registerThemeFromTokens")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn register_theme_from_tokens(&mut self, tokens_object: JsValue) -> Result<(), JsValue> {
+        let value: serde_json::Value =
+            serde_wasm_bindgen::from_value(tokens_object).map_err(js_error)?;
+        self.register_theme_internal(theme_from_tokens(&value).map_err(js_error)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "registerComponents")]
+    #[cfg(not(tarpaulin_include))]
+    pub fn register_components(&mut self, components_object: JsValue) -> Result<(), JsValue> {
+        let value: serde_json::Value =
+            serde_wasm_bindgen::from_value(components_object).map_err(js_error)?;
+        self.register_components_internal(parse_custom_components(&value).map_err(js_error)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "getDefaultTheme")]
+    #[must_use]
+    pub fn get_default_theme(&self) -> Option<String> {
+        self.context.sheet.theme.get_default_theme()
+    }
+
+    #[wasm_bindgen(js_name = "setCssFormat")]
+    pub fn set_css_format(&mut self, format: &str) -> Result<(), JsValue> {
+        self.context.sheet.format = format.parse().map_err(js_error)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "getCss")]
+    pub fn get_css(
+        &self,
+        file_num: Option<usize>,
+        import_main_css: bool,
+        format: Option<String>,
+    ) -> Result<String, JsValue> {
+        let format = parse_css_format(format.as_deref()).map_err(js_error)?;
+        Ok(self.create_css_by_file_num(file_num, import_main_css, format))
+    }
+
+    #[wasm_bindgen(js_name = "getCssSourceMap")]
+    #[must_use]
+    pub fn get_css_source_map(&self, file_num: Option<usize>, import_main_css: bool) -> String {
+        self.with_filename(file_num, |filename| {
+            self.context
+                .create_css_with_source_map(filename, import_main_css)
+                .1
+        })
+    }
+
+    #[wasm_bindgen(js_name = "getThemeInterface")]
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_theme_interface(
+        &self,
+        package_name: &str,
+        color_interface_name: &str,
+        typography_interface_name: &str,
+        length_interface_name: &str,
+        shadows_interface_name: &str,
+        theme_interface_name: &str,
+        breakpoints_interface_name: Option<String>,
+        radii_interface_name: Option<String>,
+        spacing_interface_name: Option<String>,
+        z_index_interface_name: Option<String>,
+        durations_interface_name: Option<String>,
+        easings_interface_name: Option<String>,
+        opacity_interface_name: Option<String>,
+    ) -> String {
+        let default = TokenInterfaceNames::default();
+        self.context.sheet.create_interface(
+            package_name,
+            color_interface_name,
+            typography_interface_name,
+            length_interface_name,
+            shadows_interface_name,
+            theme_interface_name,
+            breakpoints_interface_name
+                .as_deref()
+                .unwrap_or(DEFAULT_BREAKPOINTS_INTERFACE_NAME),
+            &TokenInterfaceNames {
+                radii: radii_interface_name.as_deref().unwrap_or(default.radii),
+                spacing: spacing_interface_name.as_deref().unwrap_or(default.spacing),
+                z_index: z_index_interface_name.as_deref().unwrap_or(default.z_index),
+                durations: durations_interface_name
+                    .as_deref()
+                    .unwrap_or(default.durations),
+                easings: easings_interface_name.as_deref().unwrap_or(default.easings),
+                opacity: opacity_interface_name.as_deref().unwrap_or(default.opacity),
+            },
+        )
+    }
+}
+
+impl DevupContext {
+    /// A context over the naming state of `css`'s free accessors
+    fn shared() -> Self {
+        Self {
+            context: sheet::DevupContext::shared(),
+            components: HashMap::new(),
+        }
+    }
+
+    /// Internal method to import a `StyleSheet` (testable without `JsValue`)
+    pub fn import_sheet_internal(&mut self, sheet: StyleSheet) {
+        self.context.sheet = sheet;
+    }
+
+    /// Internal method to export the `StyleSheet` as JSON string (testable without `JsValue`)
+    pub fn export_sheet_internal(&self) -> Result<String, String> {
+        serde_json::to_string(&self.context.sheet).map_err(|e| e.to_string())
+    }
+
+    /// Internal method to export the class map as JSON string (testable without `JsValue`)
+    pub fn export_class_map_internal(&self) -> Result<String, String> {
+        self.context
+            .css_context()
+            .with_class_map(serde_json::to_string)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Internal method to export the file map as JSON string (testable without `JsValue`)
+    pub fn export_file_map_internal(&self) -> Result<String, String> {
+        self.context
+            .css_context()
+            .with_file_map(serde_json::to_string)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Internal method to import the canonical (bucket) map (testable without `JsValue`)
+    pub fn import_canonical_map_internal(&self, map: HashMap<String, String>) {
+        self.context.css_context().set_canonical_map(map);
+    }
+
+    /// Internal method to export the canonical map as JSON string (testable without `JsValue`)
+    pub fn export_canonical_map_internal(&self) -> Result<String, String> {
+        self.context
+            .css_context()
+            .with_canonical_map(serde_json::to_string)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Internal method to import the file -> routes map (testable without `JsValue`)
+    pub fn import_file_routes_internal(&self, map: HashMap<String, HashSet<u32>>) {
+        self.context.css_context().set_file_routes(map);
+    }
+
+    fn create_css_by_file_num(
+        &self,
+        file_num: Option<usize>,
+        import_main_css: bool,
+        format: Option<CssFormat>,
+    ) -> String {
+        self.with_filename(file_num, |filename| {
+            self.context.create_css_with_format(
+                filename,
+                import_main_css,
+                format.unwrap_or(self.context.sheet.format),
+            )
+        })
+    }
+
+    /// Run `f` with the filename of `file_num`
+    fn with_filename<R>(&self, file_num: Option<usize>, f: impl FnOnce(Option<&str>) -> R) -> R {
+        if let Some(file_num) = file_num {
+            self.context
+                .css_context()
+                .with_file_map(|map| f(map.get_by_right(&file_num).map(String::as_str)))
+        } else {
+            f(None)
+        }
+    }
+
+    /// Internal method to extract code (testable without `JsValue`)
+    #[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+    pub fn code_extract_internal(
+        &mut self,
+        filename: &str,
+        code: &str,
+        package: &str,
+        css_dir: String,
+        single_css: bool,
+        import_main_css_in_code: bool,
+        import_main_css_in_css: bool,
+        import_aliases: HashMap<String, ImportAlias>,
+        strict: bool,
+    ) -> Result<Output, String> {
+        let output = self
+            .context
+            .extract(
+                filename,
+                code,
+                ExtractOption {
+                    package: package.to_string(),
+                    css_dir,
+                    single_css,
+                    import_main_css: import_main_css_in_code,
+                    import_aliases,
+                    strict,
+                    components: self.components.clone(),
+                },
+            )
+            .map_err(|error| error.to_string())?;
+        let (updated_base_style, css) =
+            self.context
+                .update_file(&output.styles, filename, single_css, import_main_css_in_css);
+        Ok(Output {
+            code: output.code,
+            map: output.map,
+            css_file: output.css_file,
+            updated_base_style,
+            css,
+            diagnostics: output
+                .diagnostics
+                .into_iter()
+                .map(Diagnostic::from)
+                .collect(),
+        })
+    }
+
+    /// Internal method to register theme (testable without `JsValue`)
+    pub fn register_theme_internal(&mut self, theme: sheet::theme::Theme) {
+        self.context.set_theme(theme);
+    }
+
+    /// Internal method to update the theme (testable without `JsValue`)
+    pub fn update_theme_internal(&mut self, theme: sheet::theme::Theme) -> ThemeUpdate {
+        let diff = self.context.update_theme(theme);
+        ThemeUpdate::new(&self.context.sheet, diff)
+    }
+
+    /// Internal method to register custom components (testable without `JsValue`)
+    pub fn register_components_internal(&mut self, components: HashMap<String, CustomComponent>) {
+        self.components = components;
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 mod tests {
@@ -475,6 +1041,10 @@ mod tests {
     use serial_test::serial;
     use sheet::theme::{ColorTheme, Theme, Typography};
 
+    fn with_style_sheet<R>(f: impl FnOnce(&StyleSheet) -> R) -> R {
+        with_global_context(|context| f(&context.context.sheet))
+    }
+
     fn make_named_color_theme(name: &str, value: &str) -> ColorTheme {
         let mut ct = ColorTheme::default();
         ct.add_color(name, value);
@@ -490,10 +1060,7 @@ mod tests {
         use css::file_routes::{reset_file_routes, set_file_routes};
         use std::collections::{HashMap, HashSet};
 
-        {
-            let mut s = GLOBAL_STYLE_SHEET.lock().unwrap();
-            *s = StyleSheet::default();
-        }
+        import_sheet_internal(StyleSheet::default());
         reset_class_map();
         reset_file_map();
         reset_file_routes();
@@ -518,6 +1085,7 @@ mod tests {
             false,
             false,
             HashMap::new(),
+            false,
         )
         .unwrap();
         code_extract_internal(
@@ -529,6 +1097,7 @@ mod tests {
             false,
             false,
             HashMap::new(),
+            false,
         )
         .unwrap();
 
@@ -608,10 +1177,7 @@ mod tests {
             )
         };
         let reset = || {
-            {
-                let mut s = GLOBAL_STYLE_SHEET.lock().unwrap();
-                *s = StyleSheet::default();
-            }
+            import_sheet_internal(StyleSheet::default());
             reset_class_map();
             reset_file_map();
             reset_file_routes();
@@ -663,6 +1229,7 @@ mod tests {
                         false,
                         false,
                         HashMap::new(),
+                        false,
                     )
                     .unwrap();
                 }
@@ -713,6 +1280,7 @@ mod tests {
                     false,
                     false,
                     HashMap::new(),
+                    false,
                 )
                 .unwrap();
             }
@@ -779,10 +1347,7 @@ mod tests {
             format!("import {{ Box }} from \"@devup-ui/react\"; const x = <>{body}</>;")
         };
         let reset_engine = || {
-            {
-                let mut s = GLOBAL_STYLE_SHEET.lock().unwrap();
-                *s = StyleSheet::default();
-            }
+            import_sheet_internal(StyleSheet::default());
             reset_class_map();
             reset_file_map();
             reset_file_routes();
@@ -815,6 +1380,7 @@ mod tests {
                     false,
                     false,
                     HashMap::new(),
+                    false,
                 )
                 .unwrap();
             }
@@ -924,7 +1490,7 @@ mod tests {
     #[test]
     #[serial]
     fn test_canonical_map_import_export_roundtrip() {
-        use css::file_map::{get_canonical_map, reset_canonical_map};
+        use css::file_map::{canonical, get_canonical_map, reset_canonical_map};
         reset_canonical_map();
         let mut m = HashMap::new();
         m.insert("src/child.tsx".to_string(), "src/parent.tsx".to_string());
@@ -942,17 +1508,17 @@ mod tests {
     #[test]
     #[serial]
     fn test_code_extract() {
-        {
-            let mut sheet = GLOBAL_STYLE_SHEET.lock().unwrap();
-            *sheet = StyleSheet::default();
-        }
+        import_sheet_internal(StyleSheet::default());
         assert_eq!(
-            get_css(None, false).unwrap().split("*/").nth(1).unwrap(),
+            get_css(None, false, None)
+                .unwrap()
+                .split("*/")
+                .nth(1)
+                .unwrap(),
             ""
         );
 
         {
-            let mut sheet = GLOBAL_STYLE_SHEET.lock().unwrap();
             let mut theme = Theme::default();
             let mut color_theme = ColorTheme::default();
             color_theme.add_color("primary", "#000");
@@ -961,10 +1527,16 @@ mod tests {
             let mut color_theme = ColorTheme::default();
             color_theme.add_color("primary", "#FFF");
             theme.add_color_theme("default", color_theme);
-            sheet.set_theme(theme);
+            register_theme_internal(theme);
         }
 
-        assert_debug_snapshot!(get_css(None, false).unwrap().split("*/").nth(1).unwrap());
+        assert_debug_snapshot!(
+            get_css(None, false, None)
+                .unwrap()
+                .split("*/")
+                .nth(1)
+                .unwrap()
+        );
     }
 
     #[test]
@@ -1008,7 +1580,7 @@ mod tests {
         }"##,
             )
             .unwrap();
-            assert_eq!(theme.breakpoints, vec![0, 480, 768, 992, 1280, 1600]);
+            assert_eq!(theme.breakpoints.values(), [0, 480, 768, 992, 1280, 1600]);
             assert_debug_snapshot!(theme.to_css());
         }
         {
@@ -1156,7 +1728,7 @@ mod tests {
     fn update_breakpoints(#[case] input: Vec<u16>, #[case] expected: Vec<u16>) {
         let mut theme = Theme::default();
         theme.update_breakpoints(input);
-        assert_eq!(theme.breakpoints, expected);
+        assert_eq!(theme.breakpoints.values(), expected);
     }
 
     #[test]
@@ -1170,7 +1742,9 @@ mod tests {
                 "TypographyInterface",
                 "LengthInterface",
                 "ShadowsInterface",
-                "ThemeInterface"
+                "ThemeInterface",
+                "BreakpointsInterface",
+                &TokenInterfaceNames::default(),
             ),
             ""
         );
@@ -1179,7 +1753,7 @@ mod tests {
         let mut color_theme = ColorTheme::default();
         color_theme.add_color("primary", "#000");
         theme.add_color_theme("dark", color_theme);
-        GLOBAL_STYLE_SHEET.lock().unwrap().set_theme(theme);
+        register_theme_internal(theme);
         assert_eq!(
             get_theme_interface(
                 "package",
@@ -1187,9 +1761,16 @@ mod tests {
                 "TypographyInterface",
                 "LengthInterface",
                 "ShadowsInterface",
-                "ThemeInterface"
+                "ThemeInterface",
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
             ),
-            "import \"package\";declare module \"package\"{interface ColorInterface{$primary:null}interface TypographyInterface{}interface LengthInterface{}interface ShadowsInterface{}interface ThemeInterface{dark:null}}"
+            "import \"package\";declare module \"package\"{interface ColorInterface{$primary:null}interface TypographyInterface{}interface LengthInterface{}interface ShadowsInterface{}interface ThemeInterface{dark:null}interface DevupBreakpoints{[`2xl`]:null;lg:null;md:null;sm:null;xl:null;xs:null}}"
         );
 
         // test wrong case
@@ -1209,7 +1790,7 @@ mod tests {
             ))],
         );
         sheet.set_theme(theme);
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = sheet;
+        import_sheet_internal(sheet);
         assert_eq!(
             get_theme_interface(
                 "package",
@@ -1217,10 +1798,41 @@ mod tests {
                 "TypographyInterface",
                 "LengthInterface",
                 "ShadowsInterface",
-                "ThemeInterface"
+                "ThemeInterface",
+                Some("BreakpointsInterface".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
             ),
-            "import \"package\";declare module \"package\"{interface ColorInterface{[`$(primary)`]:null}interface TypographyInterface{[`prim\\`\\`ary`]:null}interface LengthInterface{}interface ShadowsInterface{}interface ThemeInterface{dark:null}}"
+            "import \"package\";declare module \"package\"{interface ColorInterface{[`$(primary)`]:null}interface TypographyInterface{[`prim\\`\\`ary`]:null}interface LengthInterface{}interface ShadowsInterface{}interface ThemeInterface{dark:null}interface BreakpointsInterface{[`2xl`]:null;lg:null;md:null;sm:null;xl:null;xs:null}}"
         );
+
+        register_theme_internal(
+            serde_json::from_str(
+                r#"{"radii":{"default":{"card":8}},"zIndex":{"default":{"modal":1000}}}"#,
+            )
+            .unwrap(),
+        );
+        let interface = get_theme_interface(
+            "package",
+            "ColorInterface",
+            "TypographyInterface",
+            "LengthInterface",
+            "ShadowsInterface",
+            "ThemeInterface",
+            None,
+            Some("Radii".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(interface.contains("interface Radii{$card:null}"));
+        assert!(interface.contains("interface DevupThemeZIndex{$modal:null}"));
     }
 
     #[test]
@@ -1251,7 +1863,7 @@ mod tests {
         theme.add_color_theme("dark", ColorTheme::default());
         let mut sheet = StyleSheet::default();
         sheet.set_theme(theme);
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = sheet;
+        import_sheet_internal(sheet);
         assert_eq!(get_default_theme().unwrap(), Some("light".to_string()));
 
         let mut theme = Theme::default();
@@ -1260,7 +1872,7 @@ mod tests {
 
         let mut sheet = StyleSheet::default();
         sheet.set_theme(theme);
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = sheet;
+        import_sheet_internal(sheet);
         assert_eq!(get_default_theme().unwrap(), Some("default".to_string()));
 
         let mut theme = Theme::default();
@@ -1268,7 +1880,7 @@ mod tests {
 
         let mut sheet = StyleSheet::default();
         sheet.set_theme(theme);
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = sheet;
+        import_sheet_internal(sheet);
         assert_eq!(get_default_theme().unwrap(), Some("dark".to_string()));
     }
 
@@ -1276,38 +1888,30 @@ mod tests {
     #[serial]
     fn test_output_new_and_getters() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
         css::class_map::reset_class_map();
 
-        // Use extract to get real styles
-        let result = extract(
+        let output = code_extract_internal(
             "test.tsx",
             r#"import {Box} from '@devup-ui/core'
 <Box bg="red" />"#,
-            ExtractOption {
-                package: "@devup-ui/core".to_string(),
-                css_dir: "@devup-ui/core".to_string(),
-                single_css: false,
-                import_main_css: false,
-                import_aliases: HashMap::new(),
-            },
-        )
-        .unwrap();
-
-        let output = Output::new(
-            result.code.clone(),
-            result.styles,
-            Some("//# sourceMappingURL=test".to_string()),
+            "@devup-ui/core",
+            "@devup-ui/core".to_string(),
             false,
-            "test.tsx".to_string(),
-            Some("devup-ui-0.css".to_string()),
             false,
-        );
+            false,
+            HashMap::new(),
+            false,
+        )
+        .unwrap();
 
         // Test getters
         assert!(!output.code().is_empty());
-        assert_eq!(output.css_file(), Some("devup-ui-0.css".to_string()));
-        assert_eq!(output.map(), Some("//# sourceMappingURL=test".to_string()));
+        assert_eq!(
+            output.css_file(),
+            Some("@devup-ui/core/devup-ui-0.css".to_string())
+        );
+        assert!(output.map().is_some());
         assert!(output.css().is_some());
     }
 
@@ -1315,20 +1919,22 @@ mod tests {
     #[serial]
     fn test_output_updated_base_style() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
         css::class_map::reset_class_map();
 
         // Create output with empty styles
-        let styles = FxHashSet::default();
-        let output = Output::new(
-            "code".to_string(),
-            styles,
-            None,
+        let output = code_extract_internal(
+            "test.tsx",
+            "const code = 1;",
+            "@devup-ui/core",
+            "@devup-ui/core".to_string(),
             true,
-            "test.tsx".to_string(),
-            None,
             false,
-        );
+            false,
+            HashMap::new(),
+            false,
+        )
+        .unwrap();
 
         // Test updated_base_style getter
         let _ = output.updated_base_style();
@@ -1364,33 +1970,22 @@ mod tests {
     #[serial]
     fn test_output_single_css_mode() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
         css::class_map::reset_class_map();
 
-        // Use extract to get real styles in single_css mode
-        let result = extract(
+        let output = code_extract_internal(
             "test.tsx",
             r#"import {Box} from '@devup-ui/core'
 <Box color="blue" />"#,
-            ExtractOption {
-                package: "@devup-ui/core".to_string(),
-                css_dir: "@devup-ui/core".to_string(),
-                single_css: true,
-                import_main_css: true,
-                import_aliases: HashMap::new(),
-            },
-        )
-        .unwrap();
-
-        let output = Output::new(
-            result.code,
-            result.styles,
-            None,
+            "@devup-ui/core",
+            "@devup-ui/core".to_string(),
             true, // single_css = true
-            "test.tsx".to_string(),
-            Some("devup-ui.css".to_string()),
+            true,
             true, // import_main_css = true
-        );
+            HashMap::new(),
+            false,
+        )
+        .unwrap();
 
         assert!(output.css().is_some());
     }
@@ -1415,20 +2010,22 @@ mod tests {
             None,
         );
 
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = sheet;
+        import_sheet_internal(sheet);
         css::class_map::reset_class_map();
 
         // Now create output which should trigger rm_global_css
-        let styles = FxHashSet::default();
-        let output = Output::new(
-            "new code".to_string(),
-            styles,
-            None,
+        let output = code_extract_internal(
+            "test.tsx",
+            "const code = 1;",
+            "@devup-ui/core",
+            "@devup-ui/core".to_string(),
             false,
-            "test.tsx".to_string(),
-            None,
             false,
-        );
+            false,
+            HashMap::new(),
+            false,
+        )
+        .unwrap();
 
         // The updated_base_style should be true because global CSS was removed
         assert!(output.updated_base_style());
@@ -1440,10 +2037,7 @@ mod tests {
     fn collapse_setup() {
         use css::class_map::reset_class_map;
         use css::file_map::{reset_canonical_map, reset_file_map};
-        {
-            let mut s = GLOBAL_STYLE_SHEET.lock().unwrap();
-            *s = StyleSheet::default();
-        }
+        import_sheet_internal(StyleSheet::default());
         reset_class_map();
         reset_file_map();
         reset_canonical_map();
@@ -1460,6 +2054,7 @@ mod tests {
             false,
             false,
             HashMap::new(),
+            false,
         )
         .unwrap();
     }
@@ -1616,7 +2211,7 @@ mod tests {
     #[serial]
     fn test_import_sheet_internal() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
         css::class_map::reset_class_map();
 
         // Create a custom sheet with a property
@@ -1638,7 +2233,7 @@ mod tests {
     #[serial]
     fn test_export_sheet_internal() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
 
         // Export the sheet
         let result = export_sheet_internal();
@@ -1684,7 +2279,7 @@ mod tests {
     #[serial]
     fn test_code_extract_internal_success() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
         css::class_map::reset_class_map();
 
         // Test successful extraction
@@ -1698,6 +2293,7 @@ mod tests {
             false,
             false,
             HashMap::new(),
+            false,
         );
 
         assert!(result.is_ok());
@@ -1705,11 +2301,220 @@ mod tests {
         assert!(!output.code().is_empty());
     }
 
+    #[test]
+    #[serial]
+    fn test_code_extract_internal_diagnostics() {
+        import_sheet_internal(StyleSheet::default());
+        css::class_map::reset_class_map();
+
+        let output = code_extract_internal(
+            "test.tsx",
+            r"import {Box} from '@devup-ui/react'
+<Box color={color} />",
+            "@devup-ui/react",
+            "@devup-ui/react".to_string(),
+            false,
+            false,
+            false,
+            HashMap::new(),
+            false,
+        )
+        .unwrap();
+
+        let diagnostics = output.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), "warning");
+        assert_eq!(diagnostics[0].code(), "dynamic-style");
+        assert!(diagnostics[0].message().contains("`color`"));
+        assert_eq!(diagnostics[0].start(), 48);
+        assert_eq!(diagnostics[0].end(), 53);
+    }
+
+    #[test]
+    #[serial]
+    fn test_code_extract_internal_components() {
+        import_sheet_internal(StyleSheet::default());
+        css::class_map::reset_class_map();
+        register_components_internal(HashMap::from([(
+            "Section".to_string(),
+            CustomComponent::new("section", &[("display", "flex")]),
+        )]));
+
+        let output = code_extract_internal(
+            "test.tsx",
+            r"import {Section} from '@devup-ui/react'
+<Section />",
+            "@devup-ui/react",
+            "@devup-ui/react".to_string(),
+            false,
+            false,
+            false,
+            HashMap::new(),
+            false,
+        )
+        .unwrap();
+        register_components_internal(HashMap::new());
+
+        assert!(output.code().contains("<section className="));
+        assert!(output.updated_base_style());
+        assert!(with_style_sheet(|sheet| sheet.create_css(None, false)).contains("display:flex"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_code_extract_internal_strict() {
+        import_sheet_internal(StyleSheet::default());
+        css::class_map::reset_class_map();
+
+        let error = code_extract_internal(
+            "test.tsx",
+            r"import {Box} from '@devup-ui/react'
+<Box color={color} />",
+            "@devup-ui/react",
+            "@devup-ui/react".to_string(),
+            false,
+            false,
+            false,
+            HashMap::new(),
+            true,
+        )
+        .err()
+        .unwrap();
+        assert!(error.starts_with("error[dynamic-style]"));
+        assert!(error.ends_with("(48..53)"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_free_functions_share_default_context() {
+        set_prefix(Some("du-".to_string()));
+        assert_eq!(css::get_prefix(), Some("du-".to_string()));
+        css::set_prefix(None);
+        assert_eq!(get_prefix(), None);
+
+        import_canonical_map_internal(HashMap::from([(
+            "src/child.tsx".to_string(),
+            "src/parent.tsx".to_string(),
+        )]));
+        assert_eq!(css::file_map::canonical("src/child.tsx"), "src/parent.tsx");
+        css::file_map::reset_canonical_map();
+        assert_eq!(export_canonical_map_internal().unwrap(), "{}");
+    }
+
+    #[test]
+    #[serial]
+    fn test_devup_context_isolated() {
+        import_sheet_internal(StyleSheet::default());
+        css::class_map::reset_class_map();
+        css::file_map::reset_file_map();
+
+        let extract = |context: &mut DevupContext, prefix: &str, color: &str| {
+            context.set_prefix(Some(prefix.to_string()));
+            let mut theme = Theme::default();
+            theme.add_color_theme("light", make_named_color_theme("primary", color));
+            context.register_theme_internal(theme);
+            context.register_components_internal(HashMap::from([(
+                "Section".to_string(),
+                CustomComponent::new("section", &[("display", "flex")]),
+            )]));
+            context
+                .code_extract_internal(
+                    "src/index.tsx",
+                    r"import {Section} from '@devup-ui/react'
+<Section color='$primary' />",
+                    "@devup-ui/react",
+                    "df".to_string(),
+                    false,
+                    false,
+                    false,
+                    HashMap::new(),
+                    false,
+                )
+                .unwrap()
+                .code()
+        };
+        let mut first = DevupContext::new();
+        let mut second = DevupContext::new();
+        assert_eq!(
+            extract(&mut first, "one-", "red"),
+            extract(&mut second, "two-", "blue").replace("two-", "one-")
+        );
+        assert!(extract(&mut second, "two-", "blue").contains("two-a-a"));
+
+        assert_eq!(first.get_prefix(), Some("one-".to_string()));
+        assert_eq!(get_prefix(), None);
+        assert_eq!(export_class_map_internal().unwrap(), "{}");
+        assert!(
+            first
+                .get_css(Some(0), false, None)
+                .unwrap()
+                .contains("one-a-a")
+        );
+        assert!(
+            first
+                .get_css_source_map(Some(0), false)
+                .contains(r#""sources":["src/index.tsx"]"#)
+        );
+        assert!(
+            first
+                .get_css(None, false, None)
+                .unwrap()
+                .contains("--primary:red")
+        );
+        assert!(
+            second
+                .get_css(None, false, None)
+                .unwrap()
+                .contains("--primary:blue")
+        );
+        first.set_css_format("pretty").unwrap();
+        assert!(
+            first
+                .get_css(None, false, None)
+                .unwrap()
+                .contains("  --primary: red;\n")
+        );
+        assert!(
+            first
+                .get_css(None, false, Some("minified".to_string()))
+                .unwrap()
+                .contains("--primary:red")
+        );
+        assert_eq!(
+            parse_css_format(Some("compact")),
+            Err("unknown css format `compact`, expected `minified` or `pretty`".to_string())
+        );
+        assert_eq!(first.get_default_theme(), Some("light".to_string()));
+        assert!(
+            first
+                .get_theme_interface(
+                    "package", "C", "T", "L", "S", "Th", None, None, None, None, None, None, None
+                )
+                .contains("primary")
+        );
+        assert!(
+            code_extract_internal(
+                "src/index.tsx",
+                "import {Section} from '@devup-ui/react';<Section />",
+                "@devup-ui/react",
+                "df".to_string(),
+                false,
+                false,
+                false,
+                HashMap::new(),
+                false,
+            )
+            .unwrap()
+            .code()
+            .contains("<Section")
+        );
+    }
+
     #[test]
     #[serial]
     fn test_code_extract_internal_error() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
         css::class_map::reset_class_map();
 
         // Test extraction with invalid file extension (should fail)
@@ -1722,6 +2527,7 @@ mod tests {
             false,
             false,
             HashMap::new(),
+            false,
         );
 
         assert!(result.is_err());
@@ -1734,7 +2540,7 @@ mod tests {
     #[serial]
     fn test_register_theme_internal() {
         // Reset global state
-        *GLOBAL_STYLE_SHEET.lock().unwrap() = StyleSheet::default();
+        import_sheet_internal(StyleSheet::default());
 
         // Create and register a theme
         let mut theme = sheet::theme::Theme::default();
@@ -1745,7 +2551,53 @@ mod tests {
         register_theme_internal(theme);
 
         // Verify the theme was registered
-        let default_theme = GLOBAL_STYLE_SHEET.lock().unwrap().theme.get_default_theme();
+        let default_theme = with_style_sheet(|sheet| sheet.theme.get_default_theme());
         assert_eq!(default_theme, Some("default".to_string()));
     }
+
+    #[test]
+    #[serial]
+    fn test_update_theme_internal() {
+        import_sheet_internal(StyleSheet::default());
+        let theme = |json: &str| serde_json::from_str::<Theme>(json).unwrap();
+
+        register_theme_internal(theme(
+            r##"{ "colors": { "light": { "primary": "#000", "secondary": "#111" } } }"##,
+        ));
+        let update = update_theme_internal(theme(
+            r##"{ "colors": { "light": { "primary": "#222", "secondary": "#111" } } }"##,
+        ));
+        assert!(update.css().starts_with("@layer t{:root{"));
+        assert!(update.css().contains("--primary:#222"));
+        assert!(update.css().contains("--secondary:#111"));
+        assert!(!update.interface_changed());
+        assert!(!update.settings_changed());
+        assert_eq!(update.changed(), vec!["colors.light.primary"]);
+        assert!(update.added().is_empty() && update.removed().is_empty());
+
+        let update = update_theme_internal(theme(
+            r##"{ "colors": { "light": { "primary": "#222" } }, "breakpoints": [0, 600] }"##,
+        ));
+        assert!(update.interface_changed());
+        assert!(update.settings_changed());
+        assert_eq!(update.removed(), vec!["colors.light.secondary"]);
+
+        let mut context = DevupContext::new();
+        let update =
+            context.update_theme_internal(theme(r#"{ "length": { "default": { "gap": 2 } } }"#));
+        assert_eq!(update.css(), "@layer t{:root{--gap:8px}}");
+        assert!(update.interface_changed());
+        assert_eq!(update.added(), vec!["length.default.gap"]);
+
+        let update =
+            context.update_theme_internal(theme(r#"{ "length": { "default": { "gap": 4 } } }"#));
+        assert_eq!(update.css(), "@layer t{:root{--gap:16px}}");
+        assert!(!update.interface_changed());
+        assert_eq!(update.changed(), vec!["length.default.gap"]);
+        assert_eq!(
+            with_style_sheet(|sheet| sheet.theme.length.len()),
+            0,
+            "the free functions' theme is left alone"
+        );
+    }
 }
@@ -1,28 +1,23 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::context::{CssContext, with_context};
 
 // Atom-level hoist threshold. 0 = disabled (default). N = a style atom whose
 // content is used by >= N distinct routes is emitted into the shared global
 // devup-ui.css (shipped once) instead of duplicated across per-route chunks.
-static ATOM_HOIST_THRESHOLD: AtomicUsize = AtomicUsize::new(0);
-
 #[inline(always)]
 pub fn set_atom_hoist(threshold: Option<usize>) {
-    ATOM_HOIST_THRESHOLD.store(threshold.unwrap_or(0), Ordering::Relaxed);
+    with_context(|context| context.set_atom_hoist(threshold));
 }
 
 #[inline(always)]
 #[must_use]
 pub fn atom_hoist_threshold() -> Option<usize> {
-    match ATOM_HOIST_THRESHOLD.load(Ordering::Relaxed) {
-        0 => None,
-        v => Some(v),
-    }
+    with_context(CssContext::atom_hoist_threshold)
 }
 
 #[inline(always)]
 #[must_use]
 pub fn is_atom_hoist() -> bool {
-    ATOM_HOIST_THRESHOLD.load(Ordering::Relaxed) != 0
+    atom_hoist_threshold().is_some()
 }
 
 #[cfg(test)]
@@ -0,0 +1,59 @@
+//! Keys of responsive objects such as `p={{ base: 2, md: 4, smOnly: 1 }}`.
+//!
+//! The sheet registers the keys of its theme's breakpoints here, so the
+//! extractor can resolve them without knowing the theme.
+use std::collections::HashMap;
+
+use crate::context::{read, with_context, write};
+use crate::style_selector::AtRuleKind;
+
+/// Where the value under a key of a responsive object applies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponsiveKey {
+    /// A level, like the same index of a responsive array
+    Level(u8),
+    /// An at-rule outside the levels, e.g. below or between two breakpoints
+    At(AtRuleKind, String),
+}
+
+pub fn set_responsive_keys(keys: HashMap<String, ResponsiveKey>) {
+    with_context(|context| write(&context.responsive_keys, |registry| *registry = keys));
+}
+
+#[must_use]
+pub fn get_responsive_key(key: &str) -> Option<ResponsiveKey> {
+    with_context(|context| {
+        read(&context.responsive_keys, |registry| {
+            registry.get(key).cloned()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use super::*;
+
+    #[test]
+    #[serial]
+    fn test_get_responsive_key() {
+        set_responsive_keys(HashMap::from([
+            ("md".to_string(), ResponsiveKey::Level(2)),
+            (
+                "mdDown".to_string(),
+                ResponsiveKey::At(AtRuleKind::Media, "(max-width:767.98px)".to_string()),
+            ),
+        ]));
+        assert_eq!(get_responsive_key("md"), Some(ResponsiveKey::Level(2)));
+        assert_eq!(
+            get_responsive_key("mdDown"),
+            Some(ResponsiveKey::At(
+                AtRuleKind::Media,
+                "(max-width:767.98px)".to_string()
+            ))
+        );
+        assert_eq!(get_responsive_key("lg"), None);
+        set_responsive_keys(HashMap::new());
+    }
+}
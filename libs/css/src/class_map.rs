@@ -1,40 +1,15 @@
 use std::collections::HashMap;
 
-#[cfg(target_arch = "wasm32")]
-use std::cell::RefCell;
+use crate::name_record::with_recording_class_map;
 
-#[cfg(not(target_arch = "wasm32"))]
-use std::sync::Mutex;
-
-#[cfg(not(target_arch = "wasm32"))]
-use std::sync::LazyLock;
-
-#[cfg(target_arch = "wasm32")]
-thread_local! {
-    static GLOBAL_CLASS_MAP: RefCell<HashMap<String, HashMap<String, usize>>> = RefCell::new(HashMap::new());
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-static GLOBAL_CLASS_MAP: LazyLock<Mutex<HashMap<String, HashMap<String, usize>>>> =
-    LazyLock::new(|| Mutex::new(HashMap::new()));
+use crate::context::{with_context, write};
 
 #[inline]
 pub fn with_class_map<F, R>(f: F) -> R
 where
     F: FnOnce(&HashMap<String, HashMap<String, usize>>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_CLASS_MAP.with(|map| f(&map.borrow()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let guard = GLOBAL_CLASS_MAP
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&guard)
-    }
+    with_context(|context| context.with_class_map(f))
 }
 
 #[inline]
@@ -42,18 +17,11 @@ pub fn with_class_map_mut<F, R>(f: F) -> R
 where
     F: FnOnce(&mut HashMap<String, HashMap<String, usize>>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_CLASS_MAP.with(|map| f(&mut map.borrow_mut()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let mut guard = GLOBAL_CLASS_MAP
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&mut guard)
-    }
+    let f = match with_recording_class_map(f) {
+        Ok(result) => return result,
+        Err(f) => f,
+    };
+    with_context(|context| write(&context.class_map, f))
 }
 
 /// for test
@@ -0,0 +1,240 @@
+//! Naming state of one app.
+//!
+//! The class map, file map, canonical map, file routes, prefix, debug flag,
+//! atom-hoist threshold, theme token levels, responsive keys and Tailwind
+//! theme live in a [`CssContext`]. Every free accessor in this crate reads the
+//! context current on the calling thread, which is the process-wide
+//! [`default_context`] unless another one was made current with
+//! [`CssContext::enter`]. This lets several apps with different prefixes and
+//! themes share one process.
+//!
+//! Code holding a context should use its methods ([`CssContext::set_prefix`],
+//! [`CssContext::with_class_map`], ...) rather than entering it to call the
+//! free accessors.
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+
+use bimap::BiHashMap;
+
+use crate::breakpoints::ResponsiveKey;
+use crate::tailwind_theme::TailwindTheme;
+
+#[derive(Default, Debug)]
+pub(crate) struct ThemeTokenRegistry {
+    pub(crate) length: BTreeMap<String, Vec<u8>>,
+    pub(crate) shadow: BTreeMap<String, Vec<u8>>,
+    pub(crate) colors: HashSet<String>,
+    pub(crate) typography: HashSet<String>,
+}
+
+#[derive(Default, Debug)]
+pub struct CssContext {
+    pub(crate) class_map: RwLock<HashMap<String, HashMap<String, usize>>>,
+    pub(crate) file_map: RwLock<BiHashMap<String, usize>>,
+    pub(crate) canonical_map: RwLock<HashMap<String, String>>,
+    pub(crate) file_routes: RwLock<HashMap<String, HashSet<u32>>>,
+    pub(crate) prefix: RwLock<Option<String>>,
+    pub(crate) debug: AtomicBool,
+    pub(crate) atom_hoist: AtomicUsize,
+    pub(crate) token_registry: RwLock<ThemeTokenRegistry>,
+    pub(crate) responsive_keys: RwLock<HashMap<String, ResponsiveKey>>,
+    pub(crate) tailwind_theme: RwLock<TailwindTheme>,
+}
+
+static DEFAULT_CONTEXT: LazyLock<Arc<CssContext>> = LazyLock::new(Arc::default);
+
+thread_local! {
+    static CURRENT_CONTEXT: RefCell<Option<Arc<CssContext>>> = const { RefCell::new(None) };
+}
+
+impl CssContext {
+    /// Make this context current on this thread while `f` runs
+    pub fn enter<R>(self: &Arc<Self>, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<Arc<CssContext>>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_CONTEXT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+        let _restore = Restore(CURRENT_CONTEXT.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+
+    pub fn set_prefix(&self, prefix: Option<String>) {
+        write(&self.prefix, |p| *p = prefix);
+    }
+
+    #[must_use]
+    pub fn prefix(&self) -> Option<String> {
+        read(&self.prefix, Clone::clone)
+    }
+
+    pub fn set_debug(&self, debug: bool) {
+        self.debug.store(debug, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn is_debug(&self) -> bool {
+        self.debug.load(Ordering::Relaxed)
+    }
+
+    /// See [`crate::atom_hoist::set_atom_hoist`]
+    pub fn set_atom_hoist(&self, threshold: Option<usize>) {
+        self.atom_hoist
+            .store(threshold.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn atom_hoist_threshold(&self) -> Option<usize> {
+        match self.atom_hoist.load(Ordering::Relaxed) {
+            0 => None,
+            threshold => Some(threshold),
+        }
+    }
+
+    pub fn with_class_map<R>(
+        &self,
+        f: impl FnOnce(&HashMap<String, HashMap<String, usize>>) -> R,
+    ) -> R {
+        read(&self.class_map, f)
+    }
+
+    pub fn set_class_map(&self, map: HashMap<String, HashMap<String, usize>>) {
+        write(&self.class_map, |class_map| *class_map = map);
+    }
+
+    pub fn with_file_map<R>(&self, f: impl FnOnce(&BiHashMap<String, usize>) -> R) -> R {
+        read(&self.file_map, f)
+    }
+
+    pub fn set_file_map(&self, map: BiHashMap<String, usize>) {
+        write(&self.file_map, |file_map| *file_map = map);
+    }
+
+    pub fn with_canonical_map<R>(&self, f: impl FnOnce(&HashMap<String, String>) -> R) -> R {
+        read(&self.canonical_map, f)
+    }
+
+    pub fn set_canonical_map(&self, map: HashMap<String, String>) {
+        write(&self.canonical_map, |canonical_map| *canonical_map = map);
+    }
+
+    pub fn set_file_routes(&self, map: HashMap<String, HashSet<u32>>) {
+        write(&self.file_routes, |file_routes| *file_routes = map);
+    }
+}
+
+/// The context used when none was entered
+#[must_use]
+pub fn default_context() -> Arc<CssContext> {
+    DEFAULT_CONTEXT.clone()
+}
+
+/// The context current on this thread
+#[must_use]
+pub fn current_context() -> Arc<CssContext> {
+    CURRENT_CONTEXT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(default_context)
+}
+
+#[inline]
+pub(crate) fn with_context<R>(f: impl FnOnce(&CssContext) -> R) -> R {
+    match CURRENT_CONTEXT.with(|current| current.borrow().clone()) {
+        Some(context) => f(&context),
+        None => f(&DEFAULT_CONTEXT),
+    }
+}
+
+#[inline]
+pub(crate) fn read<T, R>(lock: &RwLock<T>, f: impl FnOnce(&T) -> R) -> R {
+    f(&lock.read().unwrap_or_else(PoisonError::into_inner))
+}
+
+#[inline]
+pub(crate) fn write<T, R>(lock: &RwLock<T>, f: impl FnOnce(&mut T) -> R) -> R {
+    f(&mut lock.write().unwrap_or_else(PoisonError::into_inner))
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use super::*;
+    use crate::class_map::get_class_map;
+    use crate::{get_prefix, set_prefix, sheet_to_classname};
+
+    #[test]
+    #[serial]
+    fn test_enter() {
+        set_prefix(None);
+        let context = Arc::new(CssContext::default());
+        let name = context.enter(|| {
+            set_prefix(Some("app-".to_string()));
+            assert!(Arc::ptr_eq(&current_context(), &context));
+            sheet_to_classname("color", 0, Some("red"), None, None, None)
+        });
+        assert_eq!(name, "app-a");
+        assert_eq!(get_prefix(), None);
+        assert!(Arc::ptr_eq(&current_context(), &default_context()));
+        assert!(
+            !get_class_map()
+                .values()
+                .any(|map| map.contains_key("color-0-red--255"))
+        );
+        assert_eq!(context.enter(|| get_class_map()[""]["color-0-red--255"]), 0);
+    }
+
+    #[test]
+    #[serial]
+    fn test_explicit_accessors() {
+        set_prefix(None);
+        let context = CssContext::default();
+        context.set_prefix(Some("app-".to_string()));
+        context.set_debug(true);
+        context.set_atom_hoist(Some(2));
+        context.set_class_map(HashMap::from([(
+            String::new(),
+            HashMap::from([("color-0-red--255".to_string(), 0)]),
+        )]));
+        context.set_file_map(BiHashMap::from_iter([("src/a.tsx".to_string(), 0)]));
+        context.set_canonical_map(HashMap::from([(
+            "src/b.tsx".to_string(),
+            "src/a.tsx".to_string(),
+        )]));
+        context.set_file_routes(HashMap::from([(
+            "src/a.tsx".to_string(),
+            HashSet::from([0]),
+        )]));
+
+        assert_eq!(context.prefix(), Some("app-".to_string()));
+        assert!(context.is_debug());
+        assert_eq!(context.atom_hoist_threshold(), Some(2));
+        assert_eq!(context.with_class_map(|map| map[""].len()), 1);
+        assert_eq!(
+            context.with_file_map(|map| map.get_by_left("src/a.tsx").copied()),
+            Some(0)
+        );
+        assert_eq!(
+            context.with_canonical_map(|map| map.get("src/b.tsx").cloned()),
+            Some("src/a.tsx".to_string())
+        );
+        assert_eq!(read(&context.file_routes, HashMap::len), 1);
+        assert_eq!(get_prefix(), None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_enter_nested() {
+        let outer = Arc::new(CssContext::default());
+        let inner = Arc::new(CssContext::default());
+        outer.enter(|| {
+            set_prefix(Some("outer-".to_string()));
+            inner.enter(|| set_prefix(Some("inner-".to_string())));
+            assert_eq!(get_prefix(), Some("outer-".to_string()));
+        });
+        assert_eq!(inner.enter(get_prefix), Some("inner-".to_string()));
+    }
+}
@@ -1,15 +1,13 @@
-use std::sync::atomic::{AtomicBool, Ordering};
-
-static DEBUG: AtomicBool = AtomicBool::new(false);
+use crate::context::{CssContext, with_context};
 
 #[inline(always)]
 pub fn set_debug(value: bool) {
-    DEBUG.store(value, Ordering::Relaxed);
+    with_context(|context| context.set_debug(value));
 }
 
 #[inline(always)]
 pub fn is_debug() -> bool {
-    DEBUG.load(Ordering::Relaxed)
+    with_context(CssContext::is_debug)
 }
 
 #[cfg(test)]
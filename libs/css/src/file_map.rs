@@ -1,40 +1,15 @@
 use bimap::BiHashMap;
 
-#[cfg(target_arch = "wasm32")]
-use std::cell::RefCell;
+use crate::name_record::{NameRequest, record, with_recording_file_map};
 
-#[cfg(not(target_arch = "wasm32"))]
-use std::sync::Mutex;
-
-#[cfg(not(target_arch = "wasm32"))]
-use std::sync::LazyLock;
-
-#[cfg(target_arch = "wasm32")]
-thread_local! {
-    static GLOBAL_FILE_MAP: RefCell<BiHashMap<String, usize>> = RefCell::new(BiHashMap::new());
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-static GLOBAL_FILE_MAP: LazyLock<Mutex<BiHashMap<String, usize>>> =
-    LazyLock::new(|| Mutex::new(BiHashMap::new()));
+use crate::context::{with_context, write};
 
 #[inline]
 pub fn with_file_map<F, R>(f: F) -> R
 where
     F: FnOnce(&BiHashMap<String, usize>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_FILE_MAP.with(|map| f(&map.borrow()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let guard = GLOBAL_FILE_MAP
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&guard)
-    }
+    with_context(|context| context.with_file_map(f))
 }
 
 #[inline]
@@ -42,18 +17,11 @@ fn with_file_map_mut<F, R>(f: F) -> R
 where
     F: FnOnce(&mut BiHashMap<String, usize>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_FILE_MAP.with(|map| f(&mut map.borrow_mut()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let mut guard = GLOBAL_FILE_MAP
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&mut guard)
-    }
+    let f = match with_recording_file_map(f) {
+        Ok(result) => return result,
+        Err(f) => f,
+    };
+    with_context(|context| write(&context.file_map, f))
 }
 
 /// for test
@@ -72,6 +40,7 @@ pub fn get_file_map() -> BiHashMap<String, usize> {
 #[inline]
 #[must_use]
 pub fn get_file_num_by_filename(filename: &str) -> usize {
+    record(|| NameRequest::File(filename.to_string()));
     with_file_map_mut(|map| {
         if let Some(&file_num) = map.get_by_left(filename) {
             file_num
@@ -96,33 +65,12 @@ pub fn get_filename_by_file_num(file_num: usize) -> String {
 // CANONICAL_MAP: real filename -> bucket-root filename. Populated by a build-time
 // pre-pass (single-importer collapse). When empty, `canonical()` is the identity
 // so existing behavior (and all snapshots) is unchanged — the dedup is opt-in.
-#[cfg(target_arch = "wasm32")]
-thread_local! {
-    static GLOBAL_CANONICAL_MAP: RefCell<std::collections::HashMap<String, String>> =
-        RefCell::new(std::collections::HashMap::new());
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-static GLOBAL_CANONICAL_MAP: LazyLock<Mutex<std::collections::HashMap<String, String>>> =
-    LazyLock::new(|| Mutex::new(std::collections::HashMap::new()));
-
 #[inline]
 pub fn with_canonical_map<F, R>(f: F) -> R
 where
     F: FnOnce(&std::collections::HashMap<String, String>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_CANONICAL_MAP.with(|map| f(&map.borrow()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let guard = GLOBAL_CANONICAL_MAP
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&guard)
-    }
+    with_context(|context| context.with_canonical_map(f))
 }
 
 #[inline]
@@ -130,18 +78,7 @@ fn with_canonical_map_mut<F, R>(f: F) -> R
 where
     F: FnOnce(&mut std::collections::HashMap<String, String>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_CANONICAL_MAP.with(|map| f(&mut map.borrow_mut()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let mut guard = GLOBAL_CANONICAL_MAP
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&mut guard)
-    }
+    with_context(|context| write(&context.canonical_map, f))
 }
 
 /// for test
@@ -150,7 +87,7 @@ pub fn reset_canonical_map() {
 }
 
 pub fn set_canonical_map(new_map: std::collections::HashMap<String, String>) {
-    with_canonical_map_mut(|map| *map = new_map);
+    with_context(|context| context.set_canonical_map(new_map));
 }
 
 #[must_use]
@@ -1,44 +1,16 @@
 use std::collections::{HashMap, HashSet};
 
-#[cfg(target_arch = "wasm32")]
-use std::cell::RefCell;
-
-#[cfg(not(target_arch = "wasm32"))]
-use std::sync::Mutex;
-
-#[cfg(not(target_arch = "wasm32"))]
-use std::sync::LazyLock;
+use crate::context::{read, with_context, write};
 
 // FILE_ROUTES: source filename -> set of leaf-route ids whose render closure
 // includes that file. Populated by the build-time pre-pass. Used to decide,
 // per atom, how many routes use it (for atom-level hoisting).
-#[cfg(target_arch = "wasm32")]
-thread_local! {
-    static GLOBAL_FILE_ROUTES: RefCell<HashMap<String, HashSet<u32>>> =
-        RefCell::new(HashMap::new());
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-static GLOBAL_FILE_ROUTES: LazyLock<Mutex<HashMap<String, HashSet<u32>>>> =
-    LazyLock::new(|| Mutex::new(HashMap::new()));
-
 #[inline]
 pub fn with_file_routes<F, R>(f: F) -> R
 where
     F: FnOnce(&HashMap<String, HashSet<u32>>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_FILE_ROUTES.with(|map| f(&map.borrow()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let guard = GLOBAL_FILE_ROUTES
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&guard)
-    }
+    with_context(|context| read(&context.file_routes, f))
 }
 
 #[inline]
@@ -46,18 +18,7 @@ fn with_file_routes_mut<F, R>(f: F) -> R
 where
     F: FnOnce(&mut HashMap<String, HashSet<u32>>) -> R,
 {
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(not(tarpaulin_include))]
-    {
-        GLOBAL_FILE_ROUTES.with(|map| f(&mut map.borrow_mut()))
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let mut guard = GLOBAL_FILE_ROUTES
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        f(&mut guard)
-    }
+    with_context(|context| write(&context.file_routes, f))
 }
 
 /// for test
@@ -66,7 +27,7 @@ pub fn reset_file_routes() {
 }
 
 pub fn set_file_routes(new_map: HashMap<String, HashSet<u32>>) {
-    with_file_routes_mut(|map| *map = new_map);
+    with_context(|context| context.set_file_routes(new_map));
 }
 
 #[must_use]
@@ -1,16 +1,20 @@
 pub mod atom_hoist;
+pub mod breakpoints;
 pub mod class_map;
 mod constant;
+pub mod context;
 pub mod debug;
 pub mod file_map;
 pub mod file_routes;
 pub mod is_special_property;
+pub mod name_record;
 mod num_to_nm_base;
 pub mod optimize_multi_css_value;
 pub mod optimize_value;
 pub mod rm_css_comment;
 mod selector_separator;
 pub mod style_selector;
+pub mod tailwind_theme;
 pub mod theme_tokens;
 pub mod utils;
 
@@ -19,47 +23,24 @@ use std::collections::BTreeMap;
 use crate::constant::{
     COLOR_HASH, F_SPACE_RE, GLOBAL_ENUM_STYLE_PROPERTY, GLOBAL_STYLE_PROPERTY, ZERO_RE,
 };
+use crate::context::{CssContext, with_context};
 use crate::debug::is_debug;
 use crate::file_map::get_file_num_by_filename;
+use crate::name_record::{NameRequest, record};
 use crate::num_to_nm_base::num_to_nm_base;
 use crate::optimize_value::optimize_value;
 use crate::style_selector::StyleSelector;
 use crate::utils::to_kebab_case;
 
-#[cfg(target_arch = "wasm32")]
-mod prefix_state {
-    use std::cell::RefCell;
-    thread_local! {
-        static GLOBAL_PREFIX: RefCell<Option<String>> = const { RefCell::new(None) };
-    }
-    pub fn set_prefix(prefix: Option<String>) {
-        GLOBAL_PREFIX.with(|p| *p.borrow_mut() = prefix);
-    }
-    pub fn get_prefix() -> Option<String> {
-        GLOBAL_PREFIX.with(|p| p.borrow().clone())
-    }
+pub fn set_prefix(prefix: Option<String>) {
+    with_context(|context| context.set_prefix(prefix));
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-mod prefix_state {
-    use std::sync::LazyLock;
-    use std::sync::Mutex;
-    static GLOBAL_PREFIX: LazyLock<Mutex<Option<String>>> = LazyLock::new(|| Mutex::new(None));
-    pub fn set_prefix(prefix: Option<String>) {
-        *GLOBAL_PREFIX
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner) = prefix;
-    }
-    pub fn get_prefix() -> Option<String> {
-        GLOBAL_PREFIX
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner)
-            .clone()
-    }
+#[must_use]
+pub fn get_prefix() -> Option<String> {
+    with_context(CssContext::prefix)
 }
 
-pub use prefix_state::{get_prefix, set_prefix};
-
 #[must_use]
 pub fn merge_selector(class_name: &str, selector: Option<&StyleSelector>) -> String {
     if let Some(selector) = selector {
@@ -183,6 +164,10 @@ pub fn get_enum_property_map(property: &str) -> Option<BTreeMap<&str, BTreeMap<&
 
 #[must_use]
 pub fn keyframes_to_keyframes_name(keyframes: &str, filename: Option<&str>) -> String {
+    record(|| NameRequest::Keyframes {
+        keyframes: keyframes.to_string(),
+        filename: filename.map(str::to_string),
+    });
     let prefix = get_prefix().unwrap_or_default();
     if is_debug() {
         let mut result = String::with_capacity(prefix.len() + 2 + keyframes.len());
@@ -289,6 +274,14 @@ pub fn sheet_to_classname(
     style_order: Option<u8>,
     filename: Option<&str>,
 ) -> String {
+    record(|| NameRequest::Class {
+        property: property.to_string(),
+        level,
+        value: value.map(str::to_string),
+        selector: selector.map(str::to_string),
+        style_order,
+        filename: filename.map(str::to_string),
+    });
     let prefix = get_prefix().unwrap_or_default();
     // base style
     let filename = if style_order == Some(0) {
@@ -393,6 +386,11 @@ fn write_u8(s: &mut String, v: u8) {
 
 #[must_use]
 pub fn sheet_to_variable_name(property: &str, level: u8, selector: Option<&str>) -> String {
+    record(|| NameRequest::Variable {
+        property: property.to_string(),
+        level,
+        selector: selector.map(str::to_string),
+    });
     let prefix = get_prefix().unwrap_or_default();
     if is_debug() {
         let selector = selector.unwrap_or_default().trim();
@@ -0,0 +1,209 @@
+//! Record class and file names instead of allocating them.
+//!
+//! Class and file numbers are allocated in first-use order, so extracting files
+//! concurrently would make the numbering depend on thread scheduling. A batch
+//! extraction first runs every file under [`record_names`], which allocates
+//! provisional names in thread-local maps and records each naming request, then
+//! replays the records with [`commit_names`] in a fixed file order. Replaying
+//! the requests allocates exactly what a sequential extraction in that order
+//! would, so the real pass only looks names up.
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use bimap::BiHashMap;
+
+use crate::file_map::get_file_num_by_filename;
+use crate::{keyframes_to_keyframes_name, sheet_to_classname, sheet_to_variable_name};
+
+/// A call that may allocate a class or file number
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NameRequest {
+    File(String),
+    Class {
+        property: String,
+        level: u8,
+        value: Option<String>,
+        selector: Option<String>,
+        style_order: Option<u8>,
+        filename: Option<String>,
+    },
+    Keyframes {
+        keyframes: String,
+        filename: Option<String>,
+    },
+    Variable {
+        property: String,
+        level: u8,
+        selector: Option<String>,
+    },
+}
+
+/// Naming requests of one extraction, in first-use order
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NameRecord {
+    pub requests: Vec<NameRequest>,
+}
+
+#[derive(Default)]
+struct Recorder {
+    record: NameRecord,
+    seen: HashSet<NameRequest>,
+    class_map: HashMap<String, HashMap<String, usize>>,
+    file_map: BiHashMap<String, usize>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Run `f` on this thread while recording the names it requests
+///
+/// Names returned inside `f` are provisional and must not be kept.
+pub fn record_names<R>(f: impl FnOnce() -> R) -> (R, NameRecord) {
+    let previous = RECORDER.with(|recorder| recorder.replace(Some(Recorder::default())));
+    let result = f();
+    let recorder = RECORDER.with(|recorder| recorder.replace(previous));
+    (result, recorder.map(|r| r.record).unwrap_or_default())
+}
+
+/// Allocate the recorded names in the global maps, record by record
+pub fn commit_names<'a>(records: impl IntoIterator<Item = &'a NameRecord>) {
+    for request in records.into_iter().flat_map(|record| &record.requests) {
+        match request {
+            NameRequest::File(filename) => {
+                let _ = get_file_num_by_filename(filename);
+            }
+            NameRequest::Class {
+                property,
+                level,
+                value,
+                selector,
+                style_order,
+                filename,
+            } => {
+                let _ = sheet_to_classname(
+                    property,
+                    *level,
+                    value.as_deref(),
+                    selector.as_deref(),
+                    *style_order,
+                    filename.as_deref(),
+                );
+            }
+            NameRequest::Keyframes {
+                keyframes,
+                filename,
+            } => {
+                let _ = keyframes_to_keyframes_name(keyframes, filename.as_deref());
+            }
+            NameRequest::Variable {
+                property,
+                level,
+                selector,
+            } => {
+                let _ = sheet_to_variable_name(property, *level, selector.as_deref());
+            }
+        }
+    }
+}
+
+/// Record `request` if this thread is recording
+pub(crate) fn record(request: impl FnOnce() -> NameRequest) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            let request = request();
+            if recorder.seen.insert(request.clone()) {
+                recorder.record.requests.push(request);
+            }
+        }
+    });
+}
+
+/// Run `f` on the provisional class map, or give it back when not recording
+pub(crate) fn with_recording_class_map<F, R>(f: F) -> Result<R, F>
+where
+    F: FnOnce(&mut HashMap<String, HashMap<String, usize>>) -> R,
+{
+    RECORDER.with(|recorder| match recorder.borrow_mut().as_mut() {
+        Some(recorder) => Ok(f(&mut recorder.class_map)),
+        None => Err(f),
+    })
+}
+
+/// Run `f` on the provisional file map, or give it back when not recording
+pub(crate) fn with_recording_file_map<F, R>(f: F) -> Result<R, F>
+where
+    F: FnOnce(&mut BiHashMap<String, usize>) -> R,
+{
+    RECORDER.with(|recorder| match recorder.borrow_mut().as_mut() {
+        Some(recorder) => Ok(f(&mut recorder.file_map)),
+        None => Err(f),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use super::*;
+    use crate::class_map::{get_class_map, reset_class_map};
+    use crate::file_map::{get_file_map, reset_file_map};
+
+    #[test]
+    #[serial]
+    fn test_record_names() {
+        reset_class_map();
+        reset_file_map();
+        let (names, record) = record_names(|| {
+            [
+                sheet_to_classname("color", 0, Some("red"), None, None, Some("b.tsx")),
+                sheet_to_classname("color", 0, Some("blue"), None, None, Some("b.tsx")),
+                sheet_to_classname("color", 0, Some("red"), None, None, Some("b.tsx")),
+                sheet_to_variable_name("width", 0, None),
+                keyframes_to_keyframes_name("spin", Some("b.tsx")),
+            ]
+        });
+        assert_eq!(names, ["a-a", "a-b", "a-a", "--a", "a-c"]);
+        assert_eq!(record.requests.len(), 5);
+        // nothing was allocated while recording
+        assert!(get_class_map().is_empty());
+        assert!(get_file_map().is_empty());
+
+        let (_, first) =
+            record_names(|| sheet_to_classname("color", 0, Some("red"), None, None, Some("a.tsx")));
+        assert_eq!(
+            first.requests,
+            [
+                NameRequest::Class {
+                    property: "color".to_string(),
+                    level: 0,
+                    value: Some("red".to_string()),
+                    selector: None,
+                    style_order: None,
+                    filename: Some("a.tsx".to_string()),
+                },
+                NameRequest::File("a.tsx".to_string()),
+            ]
+        );
+        commit_names([&first, &record]);
+        assert_eq!(get_file_map().get_by_left("a.tsx"), Some(&0));
+        assert_eq!(get_file_map().get_by_left("b.tsx"), Some(&1));
+        assert_eq!(
+            sheet_to_classname("color", 0, Some("blue"), None, None, Some("b.tsx")),
+            "b-b"
+        );
+        assert_eq!(keyframes_to_keyframes_name("spin", Some("b.tsx")), "b-c");
+        assert_eq!(sheet_to_variable_name("width", 0, None), "--a");
+    }
+
+    #[test]
+    #[serial]
+    fn test_record_names_nested() {
+        let (inner, outer) = record_names(|| {
+            record(|| NameRequest::File("a.tsx".to_string()));
+            record_names(|| record(|| NameRequest::File("b.tsx".to_string()))).1
+        });
+        assert_eq!(inner.requests, [NameRequest::File("b.tsx".to_string())]);
+        assert_eq!(outer.requests, [NameRequest::File("a.tsx".to_string())]);
+    }
+}
@@ -21,6 +21,7 @@ pub enum AtRuleKind {
     Supports,
     Container,
     Layer,
+    Scope,
 }
 
 impl Display for AtRuleKind {
@@ -30,6 +31,7 @@ impl Display for AtRuleKind {
             AtRuleKind::Supports => write!(f, "supports"),
             AtRuleKind::Container => write!(f, "container"),
             AtRuleKind::Layer => write!(f, "layer"),
+            AtRuleKind::Scope => write!(f, "scope"),
         }
     }
 }
@@ -41,6 +43,7 @@ impl From<&str> for AtRuleKind {
             "supports" => AtRuleKind::Supports,
             "container" => AtRuleKind::Container,
             "layer" => AtRuleKind::Layer,
+            "scope" => AtRuleKind::Scope,
             _ => unreachable!(),
         }
     }
@@ -175,6 +178,16 @@ impl From<&str> for StyleSelector {
         } else if let Some(s) = value.strip_prefix("theme-") {
             // first character should lower case
             StyleSelector::Selector(format!(":root[data-theme={}] &", to_camel_case(s)))
+        } else if let Some(s) = value.strip_prefix("scoped-theme-") {
+            // the nearest themed ancestor wins, nested themes stop the scope
+            let theme = to_camel_case(s);
+            StyleSelector::At {
+                kind: AtRuleKind::Scope,
+                query: format!(
+                    "([data-theme={theme}]) to ([data-theme]:not([data-theme={theme}]))"
+                ),
+                selector: Some("&:where(:scope,:scope *)".to_string()),
+            }
         } else if matches!(value.as_str(), "print" | "screen" | "speech" | "all") {
             StyleSelector::At {
                 kind: AtRuleKind::Media,
@@ -210,7 +223,20 @@ impl From<[&str; 2]> for StyleSelector {
 }
 impl From<(&StyleSelector, &str)> for StyleSelector {
     fn from(value: (&StyleSelector, &str)) -> Self {
-        if let StyleSelector::Global(_, file) = value.0 {
+        if let StyleSelector::At {
+            kind: kind @ AtRuleKind::Scope,
+            query,
+            selector,
+        } = value.0
+        {
+            StyleSelector::At {
+                kind: *kind,
+                query: query.clone(),
+                selector: Some(
+                    StyleSelector::from([selector.as_deref().unwrap_or("&"), value.1]).to_string(),
+                ),
+            }
+        } else if let StyleSelector::Global(_, file) = value.0 {
             let post = to_kebab_case(value.1);
             StyleSelector::Global(
                 format!(
@@ -291,6 +317,16 @@ mod tests {
     #[case("group-1", StyleSelector::Selector(":is([role=group],[data-group]):1 &".to_string()))]
     #[case(["theme-dark", "placeholder"], StyleSelector::Selector(":root[data-theme=dark] &::placeholder".to_string()))]
     #[case("theme-light", StyleSelector::Selector(":root[data-theme=light] &".to_string()))]
+    #[case("scoped-theme-high-contrast", StyleSelector::At {
+        kind: AtRuleKind::Scope,
+        query: "([data-theme=highContrast]) to ([data-theme]:not([data-theme=highContrast]))".to_string(),
+        selector: Some("&:where(:scope,:scope *)".to_string()),
+    })]
+    #[case((&StyleSelector::from("scoped-theme-dark"), "hover"), StyleSelector::At {
+        kind: AtRuleKind::Scope,
+        query: "([data-theme=dark]) to ([data-theme]:not([data-theme=dark]))".to_string(),
+        selector: Some("&:where(:scope,:scope *):hover".to_string()),
+    })]
     #[case("*[aria=disabled='true'] &:hover", StyleSelector::Selector("*[aria=disabled='true'] &:hover".to_string()))]
     fn test_style_selector(
         #[case] input: impl Into<StyleSelector>,
@@ -0,0 +1,234 @@
+//! Scales and screens of a customized Tailwind theme.
+//!
+//! The sheet registers the Tailwind theme of its devup theme here, so the
+//! extractor can resolve Tailwind classes against it instead of the default
+//! Tailwind scales.
+use std::collections::HashMap;
+
+use crate::breakpoints::ResponsiveKey;
+use crate::context::{read, with_context, write};
+use crate::style_selector::AtRuleKind;
+
+/// Scale of the default Tailwind theme a class value is looked up in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TailwindScale {
+    Colors,
+    Spacing,
+    FontSize,
+    FontWeight,
+    BorderRadius,
+    BorderWidth,
+    BoxShadow,
+    Opacity,
+    ZIndex,
+    Duration,
+    Ease,
+}
+
+/// Values extending a default scale, or replacing it when `replace` is set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TailwindValues<T> {
+    pub values: HashMap<String, T>,
+    pub replace: bool,
+}
+
+impl<T> Default for TailwindValues<T> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            replace: false,
+        }
+    }
+}
+
+impl<T: Clone> TailwindValues<T> {
+    fn get(&self, key: &str, default: impl FnOnce() -> Option<T>) -> Option<T> {
+        self.values
+            .get(key)
+            .cloned()
+            .or_else(|| if self.replace { None } else { default() })
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TailwindTheme {
+    pub scales: HashMap<TailwindScale, TailwindValues<String>>,
+    /// Responsive prefixes like `tablet:`
+    pub screens: TailwindValues<ResponsiveKey>,
+    /// At-rules below every responsive level, for `max-md:` and the like
+    pub below: HashMap<u8, (AtRuleKind, String)>,
+}
+
+pub fn set_tailwind_theme(theme: TailwindTheme) {
+    with_context(|context| write(&context.tailwind_theme, |registry| *registry = theme));
+}
+
+/// The value of `key` in `scale`, `default` being the default Tailwind one
+#[must_use]
+pub fn get_tailwind_value(
+    scale: TailwindScale,
+    key: &str,
+    default: Option<&str>,
+) -> Option<String> {
+    with_context(|context| {
+        read(&context.tailwind_theme, |theme| {
+            match theme.scales.get(&scale) {
+                Some(values) => values.get(key, || default.map(ToString::to_string)),
+                None => default.map(ToString::to_string),
+            }
+        })
+    })
+}
+
+/// Whether any customized scale defines `key`
+#[must_use]
+pub fn has_tailwind_value(key: &str) -> bool {
+    with_context(|context| {
+        read(&context.tailwind_theme, |theme| {
+            theme
+                .scales
+                .values()
+                .any(|values| values.values.contains_key(key))
+        })
+    })
+}
+
+/// Where a responsive prefix applies, `default` being the default Tailwind
+/// screen level
+#[must_use]
+pub fn get_tailwind_screen(prefix: &str, default: Option<u8>) -> Option<ResponsiveKey> {
+    with_context(|context| {
+        read(&context.tailwind_theme, |theme| {
+            theme
+                .screens
+                .get(prefix, || default.map(ResponsiveKey::Level))
+        })
+    })
+}
+
+/// Where a `max-*` prefix applies: below `screen`
+///
+/// Levels are looked up in the registered at-rules below them, at-rules are
+/// negated as `(not …)` so they can be joined with other conditions.
+#[must_use]
+pub fn get_tailwind_screen_below(screen: ResponsiveKey) -> Option<(AtRuleKind, String)> {
+    match screen {
+        ResponsiveKey::Level(level) => with_context(|context| {
+            read(&context.tailwind_theme, |theme| {
+                theme.below.get(&level).cloned()
+            })
+        }),
+        ResponsiveKey::At(kind, query) => {
+            // named containers keep their name in front of the condition
+            let (name, condition) = query
+                .find('(')
+                .map_or(("", query.as_str()), |start| query.split_at(start));
+            Some((
+                kind,
+                if condition.contains("and") {
+                    format!("{name}(not ({condition}))")
+                } else {
+                    format!("{name}(not {condition})")
+                },
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use super::*;
+
+    #[test]
+    #[serial]
+    fn test_tailwind_theme() {
+        set_tailwind_theme(TailwindTheme {
+            scales: HashMap::from([
+                (
+                    TailwindScale::Colors,
+                    TailwindValues {
+                        values: HashMap::from([("brand".to_string(), "#123456".to_string())]),
+                        replace: false,
+                    },
+                ),
+                (
+                    TailwindScale::Spacing,
+                    TailwindValues {
+                        values: HashMap::from([("1".to_string(), "2px".to_string())]),
+                        replace: true,
+                    },
+                ),
+            ]),
+            screens: TailwindValues {
+                values: HashMap::from([(
+                    "tablet".to_string(),
+                    ResponsiveKey::At(AtRuleKind::Media, "(min-width:700px)".to_string()),
+                )]),
+                replace: true,
+            },
+            below: HashMap::from([(2, (AtRuleKind::Media, "(max-width:767.98px)".to_string()))]),
+        });
+
+        assert_eq!(
+            get_tailwind_value(TailwindScale::Colors, "brand", None),
+            Some("#123456".to_string())
+        );
+        assert_eq!(
+            get_tailwind_value(TailwindScale::Colors, "black", Some("#000")),
+            Some("#000".to_string())
+        );
+        assert_eq!(
+            get_tailwind_value(TailwindScale::Spacing, "1", Some("0.25rem")),
+            Some("2px".to_string())
+        );
+        assert_eq!(
+            get_tailwind_value(TailwindScale::Spacing, "2", Some("0.5rem")),
+            None
+        );
+        assert_eq!(
+            get_tailwind_value(TailwindScale::Opacity, "50", Some("0.5")),
+            Some("0.5".to_string())
+        );
+        assert!(has_tailwind_value("brand"));
+        assert!(!has_tailwind_value("black"));
+        assert_eq!(get_tailwind_screen("md", Some(2)), None);
+        assert_eq!(
+            get_tailwind_screen("tablet", None),
+            Some(ResponsiveKey::At(
+                AtRuleKind::Media,
+                "(min-width:700px)".to_string()
+            ))
+        );
+
+        assert_eq!(
+            get_tailwind_screen_below(ResponsiveKey::Level(2)),
+            Some((AtRuleKind::Media, "(max-width:767.98px)".to_string()))
+        );
+        assert_eq!(get_tailwind_screen_below(ResponsiveKey::Level(3)), None);
+        assert_eq!(
+            get_tailwind_screen_below(ResponsiveKey::At(
+                AtRuleKind::Media,
+                "(min-width:700px)".to_string()
+            )),
+            Some((AtRuleKind::Media, "(not (min-width:700px))".to_string()))
+        );
+        assert_eq!(
+            get_tailwind_screen_below(ResponsiveKey::At(
+                AtRuleKind::Container,
+                "sidebar (min-width:700px)".to_string()
+            )),
+            Some((
+                AtRuleKind::Container,
+                "sidebar (not (min-width:700px))".to_string()
+            ))
+        );
+
+        set_tailwind_theme(TailwindTheme::default());
+        assert_eq!(
+            get_tailwind_screen("md", Some(2)),
+            Some(ResponsiveKey::Level(2))
+        );
+    }
+}
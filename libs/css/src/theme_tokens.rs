@@ -1,23 +1,46 @@
-use std::collections::BTreeMap;
-use std::sync::{LazyLock, RwLock};
+use std::collections::{BTreeMap, HashSet};
 
-#[derive(Default, Debug)]
-struct ThemeTokenRegistry {
-    length: BTreeMap<String, Vec<u8>>,
-    shadow: BTreeMap<String, Vec<u8>>,
-}
-
-static TOKEN_REGISTRY: LazyLock<RwLock<ThemeTokenRegistry>> =
-    LazyLock::new(|| RwLock::new(ThemeTokenRegistry::default()));
+use crate::context::{read, with_context, write};
 
 pub fn set_theme_token_levels(
     length: BTreeMap<String, Vec<u8>>,
     shadow: BTreeMap<String, Vec<u8>>,
 ) {
-    if let Ok(mut registry) = TOKEN_REGISTRY.write() {
-        registry.length = length;
-        registry.shadow = shadow;
-    }
+    with_context(|context| {
+        write(&context.token_registry, |registry| {
+            registry.length = length;
+            registry.shadow = shadow;
+        });
+    });
+}
+
+/// Register the color tokens, by CSS key (`gray-100`), and the typography
+/// names of the theme
+pub fn set_theme_token_names(colors: HashSet<String>, typography: HashSet<String>) {
+    with_context(|context| {
+        write(&context.token_registry, |registry| {
+            registry.colors = colors;
+            registry.typography = typography;
+        });
+    });
+}
+
+#[must_use]
+pub fn is_color_token(name: &str) -> bool {
+    with_context(|context| {
+        read(&context.token_registry, |registry| {
+            registry.colors.contains(name)
+        })
+    })
+}
+
+#[must_use]
+pub fn is_typography_token(name: &str) -> bool {
+    with_context(|context| {
+        read(&context.token_registry, |registry| {
+            registry.typography.contains(name)
+        })
+    })
 }
 
 /// Look up a `$token` in the length and shadow registries.
@@ -25,14 +48,16 @@ pub fn set_theme_token_levels(
 /// with more than one level, regardless of which CSS property it's used on.
 pub fn get_responsive_theme_token(value: &str) -> Option<Vec<u8>> {
     let token = value.strip_prefix('$')?;
-    let registry = TOKEN_REGISTRY.read().ok()?;
-
-    registry
-        .length
-        .get(token)
-        .or_else(|| registry.shadow.get(token))
-        .filter(|levels| levels.len() > 1)
-        .cloned()
+    with_context(|context| {
+        read(&context.token_registry, |registry| {
+            registry
+                .length
+                .get(token)
+                .or_else(|| registry.shadow.get(token))
+                .filter(|levels| levels.len() > 1)
+                .cloned()
+        })
+    })
 }
 
 #[cfg(test)]
@@ -52,4 +77,18 @@ mod tests {
         assert_eq!(get_responsive_theme_token("$unknown"), None);
         assert_eq!(get_responsive_theme_token("noprefix"), None);
     }
+
+    #[test]
+    fn test_theme_token_names() {
+        set_theme_token_names(
+            HashSet::from(["primary".to_string(), "gray-100".to_string()]),
+            HashSet::from(["heading".to_string()]),
+        );
+
+        assert!(is_color_token("gray-100"));
+        assert!(!is_color_token("heading"));
+        assert!(is_typography_token("heading"));
+        assert!(!is_typography_token("primary"));
+        set_theme_token_names(HashSet::new(), HashSet::new());
+    }
 }
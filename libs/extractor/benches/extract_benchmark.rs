@@ -17,6 +17,8 @@ fn make_option() -> ExtractOption {
         single_css: true,
         import_main_css: false,
         import_aliases: HashMap::new(),
+        strict: false,
+        components: HashMap::new(),
     }
 }
 
@@ -27,6 +29,8 @@ fn make_vanilla_option() -> ExtractOption {
         single_css: true,
         import_main_css: false,
         import_aliases: HashMap::new(),
+        strict: false,
+        components: HashMap::new(),
     }
 }
 
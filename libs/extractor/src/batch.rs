@@ -0,0 +1,173 @@
+use std::error::Error;
+
+use css::context::current_context;
+use css::name_record::{commit_names, record_names};
+use rayon::prelude::*;
+
+use crate::{ExtractOption, ExtractOutput, diagnostic::Diagnostic, extract};
+
+/// A file of an [`extract_batch`] call
+#[derive(Debug)]
+pub struct ExtractInput<'a> {
+    pub filename: &'a str,
+    pub code: &'a str,
+    pub option: ExtractOption,
+}
+
+pub type BatchError = Box<dyn Error + Send + Sync>;
+
+/// Extract many files in parallel
+///
+/// Class and file numbers are allocated as if the files were extracted one by
+/// one sorted by filename, so the output does not depend on the input order or
+/// the number of threads. Every file is extracted twice: once to record the
+/// names it requests, and once with those names allocated.
+///
+/// Results are returned in input order.
+pub fn extract_batch(inputs: &[ExtractInput]) -> Vec<Result<ExtractOutput, BatchError>> {
+    // Worker threads use the caller's context
+    let context = current_context();
+    let records = inputs
+        .par_iter()
+        .map(|input| {
+            context.enter(|| {
+                record_names(|| extract(input.filename, input.code, input.option.clone())).1
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut order = (0..inputs.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| inputs[index].filename);
+    commit_names(order.into_iter().map(|index| &records[index]));
+
+    inputs
+        .par_iter()
+        .map(|input| {
+            context.enter(|| {
+                extract(input.filename, input.code, input.option.clone()).map_err(into_batch_error)
+            })
+        })
+        .collect()
+}
+
+/// Keep strict-mode diagnostics downcastable across threads
+fn into_batch_error(error: Box<dyn Error>) -> BatchError {
+    match error.downcast::<Diagnostic>() {
+        Ok(diagnostic) => diagnostic,
+        Err(error) => error.to_string().into(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use css::class_map::{get_class_map, reset_class_map};
+    use css::file_map::{get_file_map, reset_file_map};
+    use serial_test::serial;
+
+    const FILES: [(&str, &str); 4] = [
+        (
+            "src/c.tsx",
+            "import {Box,keyframes} from '@devup-ui/react';const a=keyframes({from:{opacity:0}});<Box color='red' animationName={a} w={x}/>",
+        ),
+        (
+            "src/a.tsx",
+            "import {Box} from '@devup-ui/react';<Box color='blue' _hover={{color:'red'}}/>",
+        ),
+        ("src/plain.ts", "export const a = 1"),
+        (
+            "src/b.tsx",
+            "import {Box,css} from '@devup-ui/react';<Box className={css({color:'red'})} bg='blue' w={y}/>",
+        ),
+    ];
+
+    fn inputs(files: &[(&'static str, &'static str)]) -> Vec<ExtractInput<'static>> {
+        files
+            .iter()
+            .map(|&(filename, code)| ExtractInput {
+                filename,
+                code,
+                option: ExtractOption::default(),
+            })
+            .collect()
+    }
+
+    fn codes(outputs: Vec<Result<ExtractOutput, BatchError>>) -> Vec<String> {
+        outputs
+            .into_iter()
+            .map(|output| output.unwrap().code)
+            .collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_extract_batch_matches_sorted_sequential() {
+        reset_class_map();
+        reset_file_map();
+        let mut sorted = FILES;
+        sorted.sort_by_key(|(filename, _)| *filename);
+        let sequential = sorted
+            .iter()
+            .map(|(filename, code)| {
+                (
+                    *filename,
+                    extract(filename, code, ExtractOption::default())
+                        .unwrap()
+                        .code,
+                )
+            })
+            .collect::<Vec<_>>();
+        let class_map = get_class_map();
+        let file_map = get_file_map();
+
+        for files in [FILES, sorted, [FILES[3], FILES[2], FILES[1], FILES[0]]] {
+            reset_class_map();
+            reset_file_map();
+            let batch = codes(extract_batch(&inputs(&files)));
+            for ((filename, _), code) in files.iter().zip(batch) {
+                let expected = &sequential.iter().find(|(f, _)| f == filename).unwrap().1;
+                assert_eq!(&code, expected, "{filename}");
+            }
+            assert_eq!(get_class_map(), class_map);
+            assert_eq!(get_file_map(), file_map);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_extract_batch_error() {
+        reset_class_map();
+        reset_file_map();
+        let outputs = extract_batch(&[
+            ExtractInput {
+                filename: "src/a.tsx",
+                code: "import {Box} from '@devup-ui/react';<Box w={x}/>",
+                option: ExtractOption {
+                    strict: true,
+                    ..ExtractOption::default()
+                },
+            },
+            ExtractInput {
+                filename: "src/b.unknown",
+                code: "import {Box} from '@devup-ui/react';<Box/>",
+                option: ExtractOption::default(),
+            },
+        ]);
+        assert!(outputs[0].as_ref().unwrap_err().is::<Diagnostic>());
+        assert!(!outputs[1].as_ref().unwrap_err().is::<Diagnostic>());
+    }
+
+    #[test]
+    #[serial]
+    fn test_extract_batch_in_context() {
+        reset_class_map();
+        let context = std::sync::Arc::new(css::context::CssContext::default());
+        let outputs = context.enter(|| {
+            css::set_prefix(Some("app-".to_string()));
+            codes(extract_batch(&inputs(&FILES)))
+        });
+        assert!(outputs[1].contains("\"app-a-a app-a-b\""), "{}", outputs[1]);
+        assert!(get_class_map().is_empty());
+    }
+}
@@ -1,3 +1,9 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use css::disassemble_property;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter};
 
@@ -5,6 +11,79 @@ use crate::extract_style::{
     extract_static_style::ExtractStaticStyle, extract_style_value::ExtractStyleValue,
 };
 
+/// User-defined primitive (e.g. `HStack`), extracted exactly like the built-in components
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CustomComponent {
+    /// Rendered tag
+    pub tag: String,
+    /// Default style props, applied before the element's own props
+    pub styles: Vec<(String, String)>,
+}
+
+impl CustomComponent {
+    pub fn new(tag: &str, styles: &[(&str, &str)]) -> Self {
+        Self {
+            tag: tag.to_string(),
+            styles: styles
+                .iter()
+                .map(|(property, value)| ((*property).to_string(), (*value).to_string()))
+                .collect(),
+        }
+    }
+}
+
+/// Parse the `components` section of `devup.json`
+///
+/// ```json
+/// { "HStack": { "tag": "div", "styles": { "display": "flex", "gap": 2 } } }
+/// ```
+/// `tag` defaults to `div`.
+pub fn parse_custom_components(value: &Value) -> Result<HashMap<String, CustomComponent>, String> {
+    let Value::Object(components) = value else {
+        return Err("components must be an object".to_string());
+    };
+    let mut result = HashMap::with_capacity(components.len());
+    for (name, component) in components {
+        let Value::Object(component) = component else {
+            return Err(format!("component `{name}` must be an object"));
+        };
+        let tag = match component.get("tag") {
+            None => "div",
+            Some(Value::String(tag)) => tag.as_str(),
+            Some(_) => return Err(format!("tag of component `{name}` must be a string")),
+        };
+        let mut styles = vec![];
+        match component.get("styles") {
+            None => {}
+            Some(Value::Object(props)) => {
+                for (property, value) in props {
+                    styles.push((
+                        property.clone(),
+                        match value {
+                            Value::String(value) => value.clone(),
+                            Value::Number(value) => value.to_string(),
+                            _ => {
+                                return Err(format!(
+                                    "style `{property}` of component `{name}` must be a string or a number"
+                                ));
+                            }
+                        },
+                    ));
+                }
+            }
+            Some(_) => return Err(format!("styles of component `{name}` must be an object")),
+        }
+        result.insert(
+            name.clone(),
+            CustomComponent {
+                tag: tag.to_string(),
+                styles,
+            },
+        );
+    }
+    Ok(result)
+}
+
 /// devup-ui export variable kind
 #[derive(Debug, PartialEq, Eq, Clone, EnumIter, Display)]
 pub enum ExportVariableKind {
@@ -17,11 +96,17 @@ pub enum ExportVariableKind {
     Center,
     Image,
     Grid,
+    /// Registered through `ExtractOption::components`
+    #[strum(to_string = "{name}")]
+    Custom {
+        name: String,
+        component: Rc<CustomComponent>,
+    },
 }
 
 impl ExportVariableKind {
     /// Convert the kind to a tag
-    pub const fn to_tag(&self) -> &str {
+    pub fn to_tag(&self) -> &str {
         match self {
             ExportVariableKind::Center
             | ExportVariableKind::VStack
@@ -32,8 +117,40 @@ impl ExportVariableKind {
             ExportVariableKind::Image => "img",
             ExportVariableKind::Button => "button",
             ExportVariableKind::Input => "input",
+            ExportVariableKind::Custom { component, .. } => &component.tag,
         }
     }
+
+    /// Built-in kinds followed by the custom ones
+    pub fn all(
+        components: &FxHashMap<String, Rc<CustomComponent>>,
+    ) -> impl Iterator<Item = ExportVariableKind> + '_ {
+        ExportVariableKind::iter()
+            .filter(|kind| !matches!(kind, ExportVariableKind::Custom { .. }))
+            .chain(
+                components
+                    .iter()
+                    .map(|(name, component)| ExportVariableKind::Custom {
+                        name: name.clone(),
+                        component: component.clone(),
+                    }),
+            )
+    }
+
+    /// Resolve an export name to a built-in kind, falling back to the custom components
+    pub fn resolve(
+        value: &str,
+        components: &FxHashMap<String, Rc<CustomComponent>>,
+    ) -> Option<ExportVariableKind> {
+        ExportVariableKind::from_str(value).ok().or_else(|| {
+            components
+                .get(value)
+                .map(|component| ExportVariableKind::Custom {
+                    name: value.to_string(),
+                    component: component.clone(),
+                })
+        })
+    }
 }
 
 impl ExportVariableKind {
@@ -86,6 +203,17 @@ impl ExportVariableKind {
                     "display", "grid", 0, None,
                 ))]
             }
+            ExportVariableKind::Custom { component, .. } => component
+                .styles
+                .iter()
+                .flat_map(|(property, value)| {
+                    disassemble_property(property).into_iter().map(|property| {
+                        ExtractStyleValue::Static(ExtractStaticStyle::new_basic(
+                            &property, value, 0, None,
+                        ))
+                    })
+                })
+                .collect(),
         }
     }
 }
@@ -101,7 +229,7 @@ impl TryFrom<String> for ExportVariableKind {
 impl ExportVariableKind {
     pub fn from_str(value: &str) -> Result<Self, ()> {
         for kind in ExportVariableKind::iter() {
-            if kind.to_string() == value {
+            if !matches!(kind, ExportVariableKind::Custom { .. }) && kind.to_string() == value {
                 return Ok(kind);
             }
         }
@@ -110,6 +238,7 @@ impl ExportVariableKind {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -222,4 +351,62 @@ mod tests {
             ))]
         );
     }
+
+    #[test]
+    fn test_custom_component() {
+        let components = FxHashMap::from_iter([(
+            "HStack".to_string(),
+            Rc::new(CustomComponent::new("section", &[("px", "2")])),
+        )]);
+        let kind = ExportVariableKind::resolve("HStack", &components).unwrap();
+        assert_eq!(kind.to_string(), "HStack");
+        assert_eq!(kind.to_tag(), "section");
+        assert_eq!(
+            kind.extract(),
+            vec![
+                ExtractStyleValue::Static(ExtractStaticStyle::new_basic(
+                    "padding-left",
+                    "2",
+                    0,
+                    None
+                )),
+                ExtractStyleValue::Static(ExtractStaticStyle::new_basic(
+                    "padding-right",
+                    "2",
+                    0,
+                    None
+                )),
+            ]
+        );
+        assert_eq!(
+            ExportVariableKind::resolve("Box", &components),
+            Some(ExportVariableKind::Box)
+        );
+        assert_eq!(ExportVariableKind::resolve("Stack", &components), None);
+        assert_eq!(ExportVariableKind::all(&components).count(), 10);
+        assert!(ExportVariableKind::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_parse_custom_components() {
+        let components = parse_custom_components(&serde_json::json!({
+            "HStack": { "styles": { "display": "flex", "gap": 2 } },
+            "Link": { "tag": "a" }
+        }))
+        .unwrap();
+        assert_eq!(
+            components["HStack"],
+            CustomComponent::new("div", &[("display", "flex"), ("gap", "2")])
+        );
+        assert_eq!(components["Link"], CustomComponent::new("a", &[]));
+
+        assert!(parse_custom_components(&serde_json::json!([])).is_err());
+        assert!(parse_custom_components(&serde_json::json!({ "A": 1 })).is_err());
+        assert!(parse_custom_components(&serde_json::json!({ "A": { "tag": 1 } })).is_err());
+        assert!(parse_custom_components(&serde_json::json!({ "A": { "styles": [] } })).is_err());
+        assert!(
+            parse_custom_components(&serde_json::json!({ "A": { "styles": { "a": null } } }))
+                .is_err()
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 
+use crate::diagnostic::{DiagnosticCode, warn};
+use crate::tailwind::{join_queries, parse_single_class};
 use crate::utils::{get_string_by_literal_expression, wrap_direct_call};
 use css::{
     optimize_multi_css_value::{check_multi_css_optimize, optimize_mutli_css_value},
@@ -7,7 +9,7 @@ use css::{
     style_selector::{AtRuleKind, StyleSelector},
 };
 use oxc_allocator::Allocator;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN, Span};
 
 use crate::utils::expression_to_code;
 use oxc_ast::ast::TemplateLiteral;
@@ -47,7 +49,7 @@ pub fn css_to_style_literal(
     if css.expressions.is_empty() {
         for quasi in &css.quasis {
             styles.extend(
-                css_to_style(&quasi.value.raw, level, selector)
+                css_to_style(&quasi.value.raw, level, selector, css.span)
                     .into_iter()
                     .map(CssToStyleResult::Static),
             );
@@ -80,7 +82,7 @@ pub fn css_to_style_literal(
     let combined_css = css_parts.join("");
 
     // Parse CSS to extract static styles
-    let static_styles = css_to_style(&combined_css, level, selector);
+    let static_styles = css_to_style(&combined_css, level, selector, css.span);
 
     // Shared allocator for AST builder used in dynamic expression processing
     let shared_allocator = Allocator::default();
@@ -172,6 +174,14 @@ pub fn css_to_style_literal(
                     };
                     let identifier = rm_last_semi_colon(&identifier);
 
+                    warn(
+                        DiagnosticCode::DynamicStyle,
+                        format!(
+                            "`{}` is not static (`{identifier}`), falling back to a CSS variable",
+                            style.property()
+                        ),
+                        expr.span(),
+                    );
                     styles.push(CssToStyleResult::Dynamic(ExtractDynamicStyle::new(
                         style.property(),
                         style.level(),
@@ -226,6 +236,14 @@ pub fn css_to_style_literal(
                     // Wrap in template literal backticks
                     let final_identifier = format!("`{template_literal}`");
 
+                    warn(
+                        DiagnosticCode::DynamicStyle,
+                        format!(
+                            "`{}` is not static ({final_identifier}), falling back to a CSS variable",
+                            style.property()
+                        ),
+                        css.span,
+                    );
                     styles.push(CssToStyleResult::Dynamic(ExtractDynamicStyle::new(
                         style.property(),
                         style.level(),
@@ -244,6 +262,7 @@ pub fn css_to_style(
     css: &str,
     level: u8,
     selector: &Option<StyleSelector>,
+    span: Span,
 ) -> Vec<ExtractStaticStyle> {
     let mut styles = vec![];
     let mut input = css;
@@ -264,7 +283,7 @@ pub fn css_to_style(
                 .collect::<Vec<_>>();
             if at_inputs.len() > 1 {
                 for at_input in at_inputs {
-                    styles.extend(css_to_style(&at_input, level, selector));
+                    styles.extend(css_to_style(&at_input, level, selector, span));
                 }
                 return styles;
             }
@@ -300,7 +319,7 @@ pub fn css_to_style(
 
             // Process plain properties if any
             if !plain_props.is_empty() {
-                styles.extend(css_to_style_block(&plain_props, level, selector));
+                styles.extend(css_to_style_block(&plain_props, level, selector, span));
             }
 
             let rest = &input[start + 1..];
@@ -368,9 +387,9 @@ pub fn css_to_style(
                 }
             };
             let block = if block.contains('{') {
-                css_to_style(block, level, sel)
+                css_to_style(block, level, sel, span)
             } else {
-                css_to_style_block(block, level, sel)
+                css_to_style_block(block, level, sel, span)
             };
 
             // Find the matching closing brace
@@ -393,7 +412,7 @@ pub fn css_to_style(
                     input = remaining;
                 } else {
                     // If it doesn't contain '{', process it as a block and break
-                    styles.extend(css_to_style_block(remaining, level, selector));
+                    styles.extend(css_to_style_block(remaining, level, selector, span));
                     break;
                 }
             } else {
@@ -401,7 +420,7 @@ pub fn css_to_style(
             }
         }
     } else {
-        styles.extend(css_to_style_block(input, level, selector));
+        styles.extend(css_to_style_block(input, level, selector, span));
     }
 
     styles.sort_by_key(|a| a.property().to_string());
@@ -412,15 +431,23 @@ fn css_to_style_block(
     css: &str,
     level: u8,
     selector: &Option<StyleSelector>,
+    span: Span,
 ) -> Vec<ExtractStaticStyle> {
     rm_css_comment(css)
         .split(';')
-        .filter_map(|s| {
+        .flat_map(|s| {
             let s = s.trim();
             if s.is_empty() {
-                None
+                vec![]
+            } else if let Some(classes) = s
+                .strip_prefix("@apply")
+                .filter(|classes| classes.starts_with(char::is_whitespace))
+            {
+                apply_to_style(classes, level, selector, span)
             } else {
-                let (property, value) = s.split_once(':')?;
+                let Some((property, value)) = s.split_once(':') else {
+                    return vec![];
+                };
                 let property = property.trim();
                 let value = value.trim();
                 let value = if check_multi_css_optimize(property) {
@@ -428,18 +455,132 @@ fn css_to_style_block(
                 } else {
                     value.to_string()
                 };
-                Some(ExtractStaticStyle::new(
+                vec![ExtractStaticStyle::new(
                     property,
                     &value,
                     level,
                     selector.clone(),
-                ))
+                )]
             }
         })
         .collect()
 }
 
-pub fn keyframes_to_keyframes_style(keyframes: &str) -> BTreeMap<String, Vec<ExtractStaticStyle>> {
+/// Styles of the Tailwind classes of an `@apply` rule, their variants nested
+/// in the block they are applied in
+///
+/// Unknown classes, typography classes and at-rule variants inside global
+/// selectors cannot be applied, so they are dropped with a warning reported
+/// at `span`, the template the rule is written in.
+fn apply_to_style(
+    classes: &str,
+    level: u8,
+    selector: &Option<StyleSelector>,
+    span: Span,
+) -> Vec<ExtractStaticStyle> {
+    classes
+        .split_whitespace()
+        .filter_map(|class| {
+            let Some(parsed) = parse_single_class(class) else {
+                warn(
+                    DiagnosticCode::UnknownTailwindClass,
+                    format!("`{class}` in `@apply` is not a known Tailwind utility and was dropped"),
+                    span,
+                );
+                return None;
+            };
+            let ExtractStyleValue::Static(mut style) = parsed.to_style_value() else {
+                warn(
+                    DiagnosticCode::UnknownTailwindClass,
+                    format!("`{class}` in `@apply` is a typography class and was dropped"),
+                    span,
+                );
+                return None;
+            };
+            if matches!(selector, Some(StyleSelector::Global(_, _)))
+                && matches!(style.selector, Some(StyleSelector::At { .. }))
+            {
+                warn(
+                    DiagnosticCode::UnknownTailwindClass,
+                    format!(
+                        "`{class}` in `@apply` needs an at-rule inside a global selector and was dropped"
+                    ),
+                    span,
+                );
+                return None;
+            }
+            style.selector = nest_selector(selector.as_ref(), style.selector.take());
+            if style.level == 0 {
+                style.level = level;
+            }
+            Some(style)
+        })
+        .collect()
+}
+
+/// `inner` applied inside `outer`, `&` of `inner` being `outer`
+///
+/// At-rules of the same kind are joined with `and`, otherwise the inner one
+/// wins. At-rules of `inner` are dropped inside global selectors.
+fn nest_selector(
+    outer: Option<&StyleSelector>,
+    inner: Option<StyleSelector>,
+) -> Option<StyleSelector> {
+    let Some(inner) = inner else {
+        return outer.cloned();
+    };
+    let (at_rule, inner) = match inner {
+        StyleSelector::At {
+            kind,
+            query,
+            selector,
+        } => (Some((kind, query)), selector),
+        StyleSelector::Selector(selector) => (None, Some(selector)),
+        // Tailwind classes never produce global selectors
+        StyleSelector::Global(_, _) => return outer.cloned(),
+    };
+    let nest = |outer: &str| {
+        inner
+            .as_ref()
+            .map_or_else(|| outer.to_string(), |inner| inner.replace('&', outer))
+    };
+    let (at_rule, selector) = match outer {
+        None => (at_rule, inner.clone()),
+        Some(StyleSelector::Selector(outer)) => (at_rule, Some(nest(outer))),
+        Some(StyleSelector::Global(outer, file)) => {
+            return Some(StyleSelector::Global(nest(outer), file.clone()));
+        }
+        Some(StyleSelector::At {
+            kind,
+            query,
+            selector,
+        }) => (
+            Some(match at_rule {
+                Some((inner_kind, inner_query)) if inner_kind == *kind => {
+                    (*kind, join_queries(query, &inner_query))
+                }
+                Some(at_rule) => at_rule,
+                None => (*kind, query.clone()),
+            }),
+            selector
+                .as_deref()
+                .map_or_else(|| inner.clone(), |outer| Some(nest(outer))),
+        ),
+    };
+    Some(match at_rule {
+        Some((kind, query)) => StyleSelector::At {
+            kind,
+            query,
+            selector,
+        },
+        None => StyleSelector::Selector(selector.unwrap_or_else(|| "&".to_string())),
+    })
+}
+
+pub fn keyframes_to_keyframes_style(
+    keyframes: &str,
+    span: Span,
+) -> BTreeMap<String, Vec<ExtractStaticStyle>> {
     let mut map = BTreeMap::new();
     let mut input = keyframes;
 
@@ -448,7 +589,7 @@ pub fn keyframes_to_keyframes_style(keyframes: &str) -> BTreeMap<String, Vec<Ext
         let rest = &input[start + 1..];
         if let Some(end) = rest.find('}') {
             let block = &rest[..end];
-            let mut styles = css_to_style(block, 0, &None);
+            let mut styles = css_to_style(block, 0, &None, span);
 
             styles.sort_by_key(|a| a.property().to_string());
             map.insert(key, styles);
@@ -557,12 +698,17 @@ fn remove_semicolon_before_closing_brace(value: &mut String) {
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::diagnostic::take_diagnostics;
 
+    use css::context::CssContext;
+    use css::theme_tokens::set_theme_token_names;
     use oxc_allocator::Allocator;
     use oxc_ast::ast::{Expression, Statement};
     use oxc_parser::Parser;
     use oxc_span::SourceType;
     use rstest::rstest;
+    use std::collections::HashSet;
+    use std::sync::Arc;
 
     #[rstest]
     #[case("`background-color: red;`", vec![("background-color", "red", None)])]
@@ -1349,7 +1495,7 @@ mod tests {
         #[case] input: &str,
         #[case] expected: Vec<(&str, &str, Option<StyleSelector>)>,
     ) {
-        let styles = css_to_style(input, 0, &None);
+        let styles = css_to_style(input, 0, &None, SPAN);
         let mut result: Vec<(&str, &str, Option<StyleSelector>)> = styles
             .iter()
             .map(|prop| (prop.property(), prop.value(), prop.selector().cloned()))
@@ -1360,6 +1506,143 @@ mod tests {
         assert_eq!(result, expected_sorted);
     }
 
+    #[rstest]
+    #[case("@apply flex p-4;", vec![
+        ("display", "flex", 0, None),
+        ("padding", "1rem", 0, None),
+    ])]
+    #[case("color: red; @apply hover:text-white md:p-2", vec![
+        ("color", "#FFF", 0, Some(StyleSelector::Selector("&:hover".to_string()))),
+        ("color", "red", 0, None),
+        ("padding", ".5rem", 2, None),
+    ])]
+    #[case("&:focus { @apply group-hover:flex [&>svg]:w-4; }", vec![
+        ("display", "flex", 0, Some(StyleSelector::Selector(":is([role=group],[data-group]):hover &:focus".to_string()))),
+        ("width", "1rem", 0, Some(StyleSelector::Selector("&:focus>svg".to_string()))),
+    ])]
+    #[case("@media (min-width: 768px) { @apply hover:flex print:grid; }", vec![
+        ("display", "flex", 0, Some(StyleSelector::At {
+            kind: AtRuleKind::Media,
+            query: "(min-width:768px)".to_string(),
+            selector: Some("&:hover".to_string()),
+        })),
+        ("display", "grid", 0, Some(StyleSelector::At {
+            kind: AtRuleKind::Media,
+            query: "print and (min-width:768px)".to_string(),
+            selector: None,
+        })),
+    ])]
+    fn test_css_to_style_apply(
+        #[case] input: &str,
+        #[case] expected: Vec<(&str, &str, u8, Option<StyleSelector>)>,
+    ) {
+        let styles = css_to_style(input, 0, &None, SPAN);
+        let mut result: Vec<(&str, &str, u8, Option<StyleSelector>)> = styles
+            .iter()
+            .map(|prop| {
+                (
+                    prop.property(),
+                    prop.value(),
+                    prop.level(),
+                    prop.selector().cloned(),
+                )
+            })
+            .collect();
+        result.sort();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_apply_in_global_selector() {
+        take_diagnostics();
+        let global = Some(StyleSelector::Global(
+            "body".to_string(),
+            "file.tsx".to_string(),
+        ));
+        let styles = css_to_style("@apply p-4 hover:flex md:flex print:grid", 1, &global, SPAN);
+        let result = styles
+            .iter()
+            .map(|prop| {
+                (
+                    prop.property(),
+                    prop.value(),
+                    prop.level(),
+                    prop.selector().cloned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            vec![
+                (
+                    "display",
+                    "flex",
+                    1,
+                    Some(StyleSelector::Global(
+                        "body:hover".to_string(),
+                        "file.tsx".to_string()
+                    ))
+                ),
+                ("display", "flex", 2, global.clone()),
+                ("padding", "1rem", 1, global),
+            ]
+        );
+        assert_eq!(
+            messages(),
+            vec![
+                "`print:grid` in `@apply` needs an at-rule inside a global selector and was dropped"
+            ]
+        );
+    }
+
+    fn messages() -> Vec<String> {
+        take_diagnostics()
+            .into_iter()
+            .map(|diagnostic| {
+                assert_eq!(diagnostic.code, DiagnosticCode::UnknownTailwindClass);
+                diagnostic.message
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_unknown_classes() {
+        take_diagnostics();
+        Arc::new(CssContext::default()).enter(|| {
+            set_theme_token_names(HashSet::new(), HashSet::from(["heading".to_string()]));
+            let styles = css_to_style(
+                "@apply flex unknown:flex text-heading bogus;",
+                0,
+                &None,
+                Span::new(10, 56),
+            );
+            assert_eq!(
+                styles
+                    .iter()
+                    .map(|style| (style.property(), style.value()))
+                    .collect::<Vec<_>>(),
+                vec![("display", "flex")]
+            );
+        });
+        let diagnostics = take_diagnostics();
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.span == Span::new(10, 56))
+        );
+        assert_eq!(
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect::<Vec<_>>(),
+            vec![
+                "`unknown:flex` in `@apply` is not a known Tailwind utility and was dropped",
+                "`text-heading` in `@apply` is a typography class and was dropped",
+                "`bogus` in `@apply` is not a known Tailwind utility and was dropped",
+            ]
+        );
+    }
+
     #[rstest]
     #[case(
         "to {\nbackground-color:red;\n}\nfrom {\nbackground-color:blue;\n}",
@@ -1415,7 +1698,7 @@ mod tests {
         #[case] input: &str,
         #[case] expected: Vec<(&str, Vec<(&str, &str)>)>,
     ) {
-        let styles = keyframes_to_keyframes_style(input);
+        let styles = keyframes_to_keyframes_style(input, SPAN);
         assert!(
             styles.len() == expected.len(),
             "styles.len() != expected.len()"
@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use oxc_span::Span;
+use strum_macros::Display;
+
+/// How serious a diagnostic is for the build
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    /// The code still works, but part of it is left to the runtime
+    Warning,
+    /// The code cannot be extracted as written
+    Error,
+}
+
+/// Stable identifier of a diagnostic, rendered in kebab-case (e.g. `dynamic-style`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DiagnosticCode {
+    /// A style value is not static and falls back to a CSS variable set through inline `style`
+    DynamicStyle,
+    /// A spread could not be resolved statically, so its styles are left to the runtime
+    UnresolvedSpread,
+    /// A style value could not be extracted at all and was dropped
+    UnsupportedValue,
+    /// A vanilla-extract file failed to execute and was processed as a regular source file
+    VanillaExtractExecution,
+    /// A class in a Tailwind class string is not a known utility and was dropped
+    UnknownTailwindClass,
+}
+
+/// A prop or expression that could not be statically extracted
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    pub code: DiagnosticCode,
+    pub message: String,
+    /// Byte range in the (import-alias transformed) source, `0..0` when unknown
+    pub span: Span,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] {} ({}..{})",
+            self.severity, self.code, self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl Error for Diagnostic {}
+
+thread_local! {
+    static DIAGNOSTICS: RefCell<Vec<Diagnostic>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Record a diagnostic for the file currently being extracted.
+/// Identical diagnostics (e.g. from the vanilla-extract two-pass extraction) are kept once.
+pub(crate) fn report(
+    severity: DiagnosticSeverity,
+    code: DiagnosticCode,
+    message: impl Into<String>,
+    span: Span,
+) {
+    let diagnostic = Diagnostic {
+        severity,
+        code,
+        message: message.into(),
+        span,
+    };
+    DIAGNOSTICS.with(|diagnostics| {
+        let mut diagnostics = diagnostics.borrow_mut();
+        if !diagnostics.contains(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
+    });
+}
+
+#[inline]
+pub(crate) fn warn(code: DiagnosticCode, message: impl Into<String>, span: Span) {
+    report(DiagnosticSeverity::Warning, code, message, span);
+}
+
+/// Number of diagnostics recorded on this thread so far
+pub(crate) fn diagnostic_count() -> usize {
+    DIAGNOSTICS.with(|diagnostics| diagnostics.borrow().len())
+}
+
+/// Rewrite the diagnostics recorded on this thread after the first `count`
+pub(crate) fn rewrite_diagnostics_since(count: usize, f: impl FnMut(&mut Diagnostic)) {
+    DIAGNOSTICS.with(|diagnostics| diagnostics.borrow_mut().iter_mut().skip(count).for_each(f));
+}
+
+/// Drain every diagnostic recorded on this thread, sorted by source position
+pub(crate) fn take_diagnostics() -> Vec<Diagnostic> {
+    let mut diagnostics = DIAGNOSTICS.with(RefCell::take);
+    diagnostics.sort_by_key(|d| (d.span.start, d.span.end));
+    diagnostics
+}
+
+/// Drain the diagnostics of the finished extraction.
+/// In strict mode every dynamic style is an error and the first one fails the extraction.
+pub(crate) fn finish_diagnostics(strict: bool) -> Result<Vec<Diagnostic>, Diagnostic> {
+    let mut diagnostics = take_diagnostics();
+    if strict {
+        for diagnostic in &mut diagnostics {
+            if diagnostic.code == DiagnosticCode::DynamicStyle {
+                diagnostic.severity = DiagnosticSeverity::Error;
+            }
+        }
+        if let Some(diagnostic) = diagnostics
+            .iter()
+            .find(|d| d.code == DiagnosticCode::DynamicStyle)
+        {
+            return Err(diagnostic.clone());
+        }
+    }
+    Ok(diagnostics)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used, clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_and_take() {
+        take_diagnostics();
+        warn(DiagnosticCode::DynamicStyle, "b", Span::new(10, 12));
+        warn(DiagnosticCode::DynamicStyle, "b", Span::new(10, 12));
+        report(
+            DiagnosticSeverity::Error,
+            DiagnosticCode::UnsupportedValue,
+            "a",
+            Span::new(1, 2),
+        );
+        let diagnostics = take_diagnostics();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, "a");
+        assert_eq!(diagnostics[1].code, DiagnosticCode::DynamicStyle);
+        assert!(take_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_finish_diagnostics() {
+        take_diagnostics();
+        warn(DiagnosticCode::UnresolvedSpread, "spread", Span::new(1, 2));
+        warn(DiagnosticCode::DynamicStyle, "dynamic", Span::new(3, 4));
+        let diagnostics = finish_diagnostics(false).unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|d| d.severity == DiagnosticSeverity::Warning)
+        );
+
+        warn(DiagnosticCode::UnresolvedSpread, "spread", Span::new(1, 2));
+        assert_eq!(finish_diagnostics(true).unwrap().len(), 1);
+
+        warn(DiagnosticCode::UnresolvedSpread, "spread", Span::new(1, 2));
+        warn(DiagnosticCode::DynamicStyle, "dynamic", Span::new(3, 4));
+        let error = finish_diagnostics(true).unwrap_err();
+        assert_eq!(error.severity, DiagnosticSeverity::Error);
+        assert_eq!(error.span, Span::new(3, 4));
+        assert!(take_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(DiagnosticSeverity::Warning.to_string(), "warning");
+        assert_eq!(DiagnosticSeverity::Error.to_string(), "error");
+        assert_eq!(DiagnosticCode::DynamicStyle.to_string(), "dynamic-style");
+        assert_eq!(
+            DiagnosticCode::UnknownTailwindClass.to_string(),
+            "unknown-tailwind-class"
+        );
+        assert_eq!(
+            DiagnosticCode::VanillaExtractExecution.to_string(),
+            "vanilla-extract-execution"
+        );
+        assert_eq!(
+            Diagnostic {
+                severity: DiagnosticSeverity::Error,
+                code: DiagnosticCode::DynamicStyle,
+                message: "`width` is not static".to_string(),
+                span: Span::new(3, 8),
+            }
+            .to_string(),
+            "error[dynamic-style] `width` is not static (3..8)"
+        );
+    }
+}
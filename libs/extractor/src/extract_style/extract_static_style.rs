@@ -1,4 +1,6 @@
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 
 use css::{
     optimize_multi_css_value::{check_multi_css_optimize, optimize_mutli_css_value},
@@ -21,7 +23,19 @@ pub enum ThemeTokenResolution {
     FirstValue,
 }
 
-#[derive(PartialEq, Clone, Eq, Hash, Ord, PartialOrd)]
+/// Where a style was written in the source file
+///
+/// `start` and `end` are byte offsets like oxc spans; `line` and `column` are
+/// 0-based, the column counted in UTF-16 code units as source maps expect.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Ord, PartialOrd, Default)]
+pub struct SourceSpan {
+    pub start: u32,
+    pub end: u32,
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Clone)]
 pub struct ExtractStaticStyle {
     /// property
     pub property: String,
@@ -37,6 +51,59 @@ pub struct ExtractStaticStyle {
     pub layer: Option<String>,
     /// How theme tokens should be resolved when converting to CSS.
     pub theme_token_resolution: ThemeTokenResolution,
+    /// The JSX prop, styled template or `css()` call this style came from.
+    /// Not part of the style identity.
+    pub span: Option<SourceSpan>,
+}
+
+type StyleKey<'a> = (
+    &'a str,
+    &'a str,
+    u8,
+    Option<&'a StyleSelector>,
+    Option<u8>,
+    Option<&'a str>,
+    ThemeTokenResolution,
+);
+
+impl ExtractStaticStyle {
+    fn key(&self) -> StyleKey<'_> {
+        (
+            &self.property,
+            &self.value,
+            self.level,
+            self.selector.as_ref(),
+            self.style_order,
+            self.layer.as_deref(),
+            self.theme_token_resolution,
+        )
+    }
+}
+
+impl PartialEq for ExtractStaticStyle {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for ExtractStaticStyle {}
+
+impl Hash for ExtractStaticStyle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for ExtractStaticStyle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ExtractStaticStyle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
 }
 
 impl Debug for ExtractStaticStyle {
@@ -78,6 +145,7 @@ impl ExtractStaticStyle {
             style_order: None,
             layer: None,
             theme_token_resolution: ThemeTokenResolution::CssVariable,
+            span: None,
         }
     }
 
@@ -114,6 +182,7 @@ impl ExtractStaticStyle {
             style_order: Some(0),
             layer: None,
             theme_token_resolution: ThemeTokenResolution::CssVariable,
+            span: None,
         }
     }
 
@@ -123,6 +192,11 @@ impl ExtractStaticStyle {
         self
     }
 
+    #[must_use]
+    pub const fn span(&self) -> Option<SourceSpan> {
+        self.span
+    }
+
     /// Get the layer name
     #[must_use]
     pub fn layer(&self) -> Option<&str> {
@@ -1,7 +1,11 @@
 use crate::extract_style::{
-    ExtractStyleProperty, extract_css::ExtractCss, extract_dynamic_style::ExtractDynamicStyle,
-    extract_font_face::ExtractFontFace, extract_import::ExtractImport,
-    extract_keyframes::ExtractKeyframes, extract_static_style::ExtractStaticStyle,
+    ExtractStyleProperty,
+    extract_css::ExtractCss,
+    extract_dynamic_style::ExtractDynamicStyle,
+    extract_font_face::ExtractFontFace,
+    extract_import::ExtractImport,
+    extract_keyframes::ExtractKeyframes,
+    extract_static_style::{ExtractStaticStyle, SourceSpan},
     style_property::StyleProperty,
 };
 
@@ -45,6 +49,15 @@ impl ExtractStyleValue {
             _ => {}
         }
     }
+
+    /// Record where a static style came from, keeping a more precise span
+    pub const fn set_span(&mut self, span: SourceSpan) {
+        if let ExtractStyleValue::Static(style) = self
+            && style.span.is_none()
+        {
+            style.span = Some(span);
+        }
+    }
 }
 
 #[cfg(test)]
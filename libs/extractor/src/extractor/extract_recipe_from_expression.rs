@@ -0,0 +1,309 @@
+use crate::{
+    ExtractStyleProp,
+    diagnostic::{DiagnosticCode, diagnostic_count, rewrite_diagnostics_since, warn},
+    extract_style::extract_style_value::ExtractStyleValue,
+    extractor::{
+        ExtractResult, RecipeExtractResult,
+        extract_style_from_expression::{LiteralHandling, extract_style_from_expression},
+    },
+    gen_class_name::gen_class_names,
+    utils::{expression_to_code, get_string_by_property_key},
+};
+use oxc_ast::{
+    AstBuilder,
+    ast::{Expression, ObjectPropertyKind},
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SPAN, SourceType};
+
+/// `base` is overridden by `variants`, which are overridden by `compoundVariants`
+const BASE_STYLE_ORDER: u8 = 1;
+const VARIANT_STYLE_ORDER: u8 = 2;
+const COMPOUND_VARIANT_STYLE_ORDER: u8 = 3;
+
+/// Extract `recipe({ base, variants, compoundVariants, defaultVariants })`
+/// and build the class name lookup function replacing it
+///
+/// ```js
+/// (v) => ["base", { sm: "a", lg: "b" }[v?.size ?? "sm"], (v?.size ?? "sm") === "sm" && v?.tone === "primary" && "c"]
+///     .filter(Boolean).join(" ")
+/// ```
+pub fn extract_recipe_from_expression<'a>(
+    ast_builder: &AstBuilder<'a>,
+    expression: &mut Expression<'a>,
+    filename: Option<&str>,
+) -> RecipeExtractResult<'a> {
+    let mut styles = vec![];
+    let mut base = None;
+    let mut variants: Vec<(String, Vec<(String, String)>)> = vec![];
+    let mut compound_variants: Vec<(Vec<(String, String)>, String)> = vec![];
+    let mut default_variants: Vec<(String, String)> = vec![];
+
+    if let Expression::ObjectExpression(obj) = expression {
+        for prop in &mut obj.properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+                warn(
+                    DiagnosticCode::UnsupportedValue,
+                    "spread is not supported in recipe() and was dropped",
+                    prop.span(),
+                );
+                continue;
+            };
+            match get_string_by_property_key(&prop.key).as_deref() {
+                Some("base") => {
+                    base = extract_class_name(
+                        ast_builder,
+                        &mut prop.value,
+                        BASE_STYLE_ORDER,
+                        filename,
+                        &mut styles,
+                    );
+                }
+                Some("variants") => {
+                    let Expression::ObjectExpression(groups) = &mut prop.value else {
+                        continue;
+                    };
+                    for group in &mut groups.properties {
+                        if let ObjectPropertyKind::ObjectProperty(group) = group
+                            && let Some(name) = get_string_by_property_key(&group.key)
+                            && let Expression::ObjectExpression(options) = &mut group.value
+                        {
+                            let mut option_class_names = vec![];
+                            for option in &mut options.properties {
+                                if let ObjectPropertyKind::ObjectProperty(option) = option
+                                    && let Some(key) = get_string_by_property_key(&option.key)
+                                    && let Some(class_name) = extract_class_name(
+                                        ast_builder,
+                                        &mut option.value,
+                                        VARIANT_STYLE_ORDER,
+                                        filename,
+                                        &mut styles,
+                                    )
+                                {
+                                    option_class_names.push((key, class_name));
+                                }
+                            }
+                            variants.push((name, option_class_names));
+                        }
+                    }
+                }
+                Some("compoundVariants") => {
+                    let Expression::ArrayExpression(array) = &mut prop.value else {
+                        continue;
+                    };
+                    for element in &mut array.elements {
+                        let Some(Expression::ObjectExpression(compound)) =
+                            element.as_expression_mut()
+                        else {
+                            continue;
+                        };
+                        let mut conditions = vec![];
+                        let mut class_name = None;
+                        for p in &mut compound.properties {
+                            if let ObjectPropertyKind::ObjectProperty(p) = p
+                                && let Some(key) = get_string_by_property_key(&p.key)
+                            {
+                                if key == "css" {
+                                    class_name = extract_class_name(
+                                        ast_builder,
+                                        &mut p.value,
+                                        COMPOUND_VARIANT_STYLE_ORDER,
+                                        filename,
+                                        &mut styles,
+                                    );
+                                } else if let Some(value) = get_variant_value(&p.value) {
+                                    conditions.push((key, value));
+                                } else {
+                                    warn(
+                                        DiagnosticCode::UnsupportedValue,
+                                        format!(
+                                            "compound variant condition `{key}` must be a literal and was dropped"
+                                        ),
+                                        p.value.span(),
+                                    );
+                                }
+                            }
+                        }
+                        if let Some(class_name) = class_name {
+                            compound_variants.push((conditions, class_name));
+                        }
+                    }
+                }
+                Some("defaultVariants") => {
+                    let Expression::ObjectExpression(defaults) = &prop.value else {
+                        continue;
+                    };
+                    for p in &defaults.properties {
+                        if let ObjectPropertyKind::ObjectProperty(p) = p
+                            && let Some(key) = get_string_by_property_key(&p.key)
+                            && let Some(value) = get_variant_value(&p.value)
+                        {
+                            default_variants.push((key, value));
+                        }
+                    }
+                }
+                Some("styleOrder") => warn(
+                    DiagnosticCode::UnsupportedValue,
+                    "`styleOrder` of recipe() was ignored, set it in `base`, a variant or a compound variant `css` instead",
+                    prop.span,
+                ),
+                Some(key) => warn(
+                    DiagnosticCode::UnsupportedValue,
+                    format!("`{key}` is not a recipe() option and was ignored"),
+                    prop.span,
+                ),
+                None => warn(
+                    DiagnosticCode::UnsupportedValue,
+                    "computed recipe() option was ignored",
+                    prop.span,
+                ),
+            }
+        }
+    }
+
+    let variant_access = |name: &str| {
+        let access = if is_identifier(name) {
+            format!("v?.{name}")
+        } else {
+            format!("v?.[{}]", to_js_string(name))
+        };
+        default_variants
+            .iter()
+            .find(|(key, _)| key == name)
+            .map_or_else(
+                || access.clone(),
+                |(_, value)| format!("({access}??{value})"),
+            )
+    };
+
+    let mut parts = vec![];
+    if let Some(base) = base {
+        parts.push(base);
+    }
+    for (name, options) in &variants {
+        if options.is_empty() {
+            continue;
+        }
+        parts.push(format!(
+            "{{{}}}[{}]",
+            options
+                .iter()
+                .map(|(key, class_name)| format!("{}:{class_name}", to_js_string(key)))
+                .collect::<Vec<_>>()
+                .join(","),
+            variant_access(name)
+        ));
+    }
+    for (conditions, class_name) in &compound_variants {
+        parts.push(
+            conditions
+                .iter()
+                .map(|(name, value)| format!("{}==={value}&&", variant_access(name)))
+                .chain(std::iter::once(class_name.clone()))
+                .collect::<String>(),
+        );
+    }
+
+    let source = ast_builder.str(&format!(
+        "(v)=>[{}].filter(Boolean).join(\" \")",
+        parts.join(",")
+    ));
+    RecipeExtractResult {
+        styles,
+        expression: Parser::new(ast_builder.allocator, source.as_str(), SourceType::mjs())
+            .parse_expression()
+            .unwrap_or_else(|_| ast_builder.expression_string_literal(SPAN, "", None)),
+    }
+}
+
+/// Extract a style object and return its class name expression as code
+fn extract_class_name<'a>(
+    ast_builder: &AstBuilder<'a>,
+    expression: &mut Expression<'a>,
+    default_style_order: u8,
+    filename: Option<&str>,
+    styles: &mut Vec<ExtractStyleValue>,
+) -> Option<String> {
+    let recorded = diagnostic_count();
+    let ExtractResult {
+        styles: mut props_styles,
+        style_order,
+        ..
+    } = extract_style_from_expression(
+        ast_builder,
+        None,
+        expression,
+        0,
+        &None,
+        LiteralHandling::ExpandResponsiveThemeToken,
+    );
+    // The lookup function only returns class names, so nothing would set the
+    // CSS variable of a dynamic value
+    rewrite_diagnostics_since(recorded, |diagnostic| {
+        if diagnostic.code == DiagnosticCode::DynamicStyle {
+            diagnostic.code = DiagnosticCode::UnsupportedValue;
+            diagnostic.message = diagnostic
+                .message
+                .replace("falling back to a CSS variable", "dropped from recipe()");
+        }
+    });
+    retain_static(&mut props_styles);
+    let class_name = gen_class_names(
+        ast_builder,
+        &mut props_styles,
+        Some(style_order.unwrap_or(default_style_order)),
+        filename,
+    )?;
+    styles.extend(props_styles.into_iter().flat_map(|ex| ex.extract()));
+    Some(if let Expression::StringLiteral(literal) = &class_name {
+        to_js_string(&literal.value)
+    } else {
+        expression_to_code(&class_name)
+            .trim_end()
+            .trim_end_matches(';')
+            .to_string()
+    })
+}
+
+/// Drop the dynamic styles, whose CSS variable the lookup function cannot set
+fn retain_static(props: &mut Vec<ExtractStyleProp>) {
+    props.retain_mut(|prop| match prop {
+        ExtractStyleProp::Static(ExtractStyleValue::Dynamic(_)) => false,
+        ExtractStyleProp::StaticArray(props) => {
+            retain_static(props);
+            true
+        }
+        ExtractStyleProp::Enum { map, .. } => {
+            map.values_mut().for_each(retain_static);
+            true
+        }
+        ExtractStyleProp::Expression { styles, .. } => {
+            styles.retain(|style| !matches!(style, ExtractStyleValue::Dynamic(_)));
+            true
+        }
+        _ => true,
+    });
+}
+
+/// Variant values are string, number or boolean literals, returned as JS code
+fn get_variant_value(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::BooleanLiteral(boolean) => Some(boolean.value.to_string()),
+        Expression::NumericLiteral(number) => Some(number.value.to_string()),
+        Expression::StringLiteral(string) => Some(to_js_string(&string.value)),
+        _ => None,
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn to_js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
@@ -1,6 +1,7 @@
 use crate::{
     ExtractStyleProp,
     css_utils::{css_to_style, css_to_style_literal},
+    diagnostic::{DiagnosticCode, warn},
     extract_style::{
         extract_dynamic_style::ExtractDynamicStyle,
         extract_static_style::{ExtractStaticStyle, ThemeTokenResolution},
@@ -15,19 +16,23 @@ use crate::{
     },
 };
 use css::{
-    add_selector_params, disassemble_property, get_enum_property_map, get_enum_property_value,
-    is_special_property::is_special_property, style_selector::StyleSelector,
-    theme_tokens::get_responsive_theme_token, utils::to_kebab_case,
+    add_selector_params,
+    breakpoints::{ResponsiveKey, get_responsive_key},
+    disassemble_property, get_enum_property_map, get_enum_property_value,
+    is_special_property::is_special_property,
+    style_selector::StyleSelector,
+    theme_tokens::get_responsive_theme_token,
+    utils::to_kebab_case,
 };
 use oxc_allocator::CloneIn;
 use oxc_ast::{
     AstBuilder,
     ast::{
-        BinaryOperator, Expression, LogicalOperator, ObjectPropertyKind, TemplateElementValue,
-        UnaryOperator,
+        BinaryOperator, Expression, LogicalOperator, ObjectExpression, ObjectPropertyKind,
+        TemplateElementValue, UnaryOperator,
     },
 };
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
 
 const IGNORED_IDENTIFIERS: [&str; 3] = ["undefined", "NaN", "Infinity"];
 
@@ -388,7 +393,7 @@ pub fn extract_style_from_expression<'a>(
             }
         } else {
             ExtractResult {
-                styles: css_to_style(&value, level, selector)
+                styles: css_to_style(&value, level, selector, expression.span())
                     .into_iter()
                     .map(|ex| ExtractStyleProp::Static(ExtractStyleValue::Static(ex)))
                     .collect(),
@@ -422,14 +427,15 @@ pub fn extract_style_from_expression<'a>(
             // Each variant is kept on its own line so per-line coverage
             // tools (tarpaulin on CI) can attribute the hit to the exact
             // pattern being exercised. The body is flattened to a single
-            // `Option::map().unwrap_or_default()` chain to avoid an extra
+            // `Option::map_or_else()` chain to avoid an extra
             // if/else branch region — `name == None` happens only under
             // `_xxx={...}` pseudo-selector recursion, where no dynamic_style
             // can be emitted because the selector has no CSS property slot.
             Expression::BinaryExpression(_)
             | Expression::StaticMemberExpression(_)
-            | Expression::CallExpression(_) => name
-                .map(|name| ExtractResult {
+            | Expression::CallExpression(_) => name.map_or_else(
+                || dropped_in_selector(expression),
+                |name| ExtractResult {
                     styles: vec![dynamic_style(
                         ast_builder,
                         name,
@@ -438,8 +444,8 @@ pub fn extract_style_from_expression<'a>(
                         selector,
                     )],
                     ..ExtractResult::default()
-                })
-                .unwrap_or_default(),
+                },
+            ),
             Expression::TSAsExpression(exp) => extract_style_from_expression(
                 ast_builder,
                 name,
@@ -562,7 +568,7 @@ pub fn extract_style_from_expression<'a>(
                         }
                     }
                 } else {
-                    ExtractResult::default()
+                    dropped_in_selector(expression)
                 }
             }
             Expression::LogicalExpression(logical) => {
@@ -705,6 +711,42 @@ pub fn extract_style_from_expression<'a>(
                 }
             }
             Expression::ObjectExpression(obj) => {
+                if name.is_some()
+                    && let Some(keys) = get_responsive_keys(obj)
+                {
+                    let mut props = vec![];
+                    for (property, key) in obj.properties.iter_mut().zip(keys) {
+                        let ObjectPropertyKind::ObjectProperty(o) = property else {
+                            continue;
+                        };
+                        let (level, selector) = match key {
+                            ResponsiveKey::Level(level) => (level, selector.clone()),
+                            ResponsiveKey::At(kind, query) => (
+                                level,
+                                Some(StyleSelector::At {
+                                    kind,
+                                    query,
+                                    selector: selector.as_ref().map(ToString::to_string),
+                                }),
+                            ),
+                        };
+                        props.extend(
+                            extract_style_from_expression(
+                                ast_builder,
+                                name,
+                                &mut o.value,
+                                level,
+                                &selector,
+                                LiteralHandling::KeepSingleClass,
+                            )
+                            .styles,
+                        );
+                    }
+                    return ExtractResult {
+                        styles: vec![ExtractStyleProp::StaticArray(props)],
+                        ..ExtractResult::default()
+                    };
+                }
                 let mut props = vec![];
                 let params = obj.properties.iter().find_map(|p| {
                     if let ObjectPropertyKind::ObjectProperty(o) = p
@@ -767,6 +809,37 @@ pub fn extract_style_from_expression<'a>(
     }
 }
 
+/// Keys of a responsive object (`{ base: 1, md: 2 }`), if every key is one
+fn get_responsive_keys(obj: &ObjectExpression) -> Option<Vec<ResponsiveKey>> {
+    if obj.properties.is_empty() {
+        return None;
+    }
+    obj.properties
+        .iter()
+        .map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(o) => {
+                get_string_by_property_key(&o.key).and_then(|key| get_responsive_key(&key))
+            }
+            ObjectPropertyKind::SpreadProperty(_) => None,
+        })
+        .collect()
+}
+
+/// A non-static value under a pseudo-selector has no CSS property to bind a variable to
+fn dropped_in_selector<'a>(expression: &Expression<'a>) -> ExtractResult<'a> {
+    warn(
+        DiagnosticCode::UnsupportedValue,
+        format!(
+            "`{}` cannot be resolved statically inside a selector and was dropped",
+            expression_to_code(expression)
+                .trim_end()
+                .trim_end_matches(';')
+        ),
+        expression.span(),
+    );
+    ExtractResult::default()
+}
+
 pub fn dynamic_style<'a>(
     ast_builder: &AstBuilder<'a>,
     name: &str,
@@ -794,10 +867,19 @@ pub fn dynamic_style<'a>(
                 .collect(),
         }
     } else {
+        let code = expression_to_code(expression);
+        warn(
+            DiagnosticCode::DynamicStyle,
+            format!(
+                "`{name}` is not static (`{}`), falling back to a CSS variable",
+                code.trim_end().trim_end_matches(';')
+            ),
+            expression.span(),
+        );
         ExtractStyleProp::Static(ExtractStyleValue::Dynamic(ExtractDynamicStyle::new(
             name,
             level,
-            &expression_to_code(expression),
+            &code,
             selector.clone(),
         )))
     }
@@ -1,4 +1,5 @@
 use crate::ExtractStyleProp;
+use crate::diagnostic::{DiagnosticCode, DiagnosticSeverity, report, warn};
 use crate::extract_style::extract_dynamic_style::ExtractDynamicStyle;
 use crate::extract_style::extract_static_style::ExtractStaticStyle;
 use crate::extract_style::extract_style_value::ExtractStyleValue;
@@ -11,6 +12,7 @@ use css::optimize_value::optimize_value;
 use css::sheet_to_variable_name;
 use oxc_ast::AstBuilder;
 use oxc_ast::ast::{BindingPattern, Expression, ObjectPropertyKind, Statement};
+use oxc_span::GetSpan;
 use rustc_hash::FxHashMap;
 
 use crate::utils::get_string_by_property_key;
@@ -63,8 +65,11 @@ pub fn extract_stylex_namespace_styles<'a>(
         let ObjectPropertyKind::ObjectProperty(prop) = prop else {
             // Phase 4c: Spread not supported at namespace level
             if matches!(prop, ObjectPropertyKind::SpreadProperty(_)) {
-                eprintln!(
-                    "[stylex] ERROR: Object spread is not allowed at the namespace level of stylex.create()."
+                report(
+                    DiagnosticSeverity::Error,
+                    DiagnosticCode::UnsupportedValue,
+                    "[stylex] Object spread is not allowed at the namespace level of stylex.create().",
+                    prop.span(),
                 );
             }
             continue;
@@ -73,8 +78,11 @@ pub fn extract_stylex_namespace_styles<'a>(
         let Some(ns_name) = get_string_by_property_key(&prop.key) else {
             // Phase 4c: Computed namespace keys not supported
             if prop.computed {
-                eprintln!(
-                    "[stylex] ERROR: Computed namespace keys are not allowed in stylex.create()."
+                report(
+                    DiagnosticSeverity::Error,
+                    DiagnosticCode::UnsupportedValue,
+                    "[stylex] Computed namespace keys are not allowed in stylex.create().",
+                    prop.key.span(),
                 );
             }
             continue;
@@ -120,8 +128,11 @@ pub fn extract_stylex_namespace_styles<'a>(
                         });
                     }
                 } else if matches!(style_prop, ObjectPropertyKind::SpreadProperty(_)) {
-                    eprintln!(
-                        "[stylex] ERROR: Object spread is not allowed in stylex.create() namespaces. Define all properties explicitly."
+                    report(
+                        DiagnosticSeverity::Error,
+                        DiagnosticCode::UnsupportedValue,
+                        "[stylex] Object spread is not allowed in stylex.create() namespaces. Define all properties explicitly.",
+                        style_prop.span(),
                     );
                 }
                 continue;
@@ -130,8 +141,11 @@ pub fn extract_stylex_namespace_styles<'a>(
             let Some(prop_name) = get_string_by_property_key(&style_prop.key) else {
                 // Phase 4c: Computed property keys not supported
                 if style_prop.computed {
-                    eprintln!(
-                        "[stylex] ERROR: Computed property keys are not allowed in stylex.create(). Use static string keys instead."
+                    report(
+                        DiagnosticSeverity::Error,
+                        DiagnosticCode::UnsupportedValue,
+                        "[stylex] Computed property keys are not allowed in stylex.create(). Use static string keys instead.",
+                        style_prop.key.span(),
                     );
                 }
                 continue;
@@ -166,6 +180,7 @@ pub fn extract_stylex_namespace_styles<'a>(
                                     style_order: None,
                                     layer: None,
                                     theme_token_resolution: Default::default(),
+                                    span: None,
                                 },
                             )));
                         }
@@ -195,6 +210,7 @@ pub fn extract_stylex_namespace_styles<'a>(
                         style_order: None,
                         layer: None,
                         theme_token_resolution: Default::default(),
+                        span: None,
                     },
                 )));
                 continue;
@@ -217,6 +233,7 @@ pub fn extract_stylex_namespace_styles<'a>(
                                 style_order: None,
                                 layer: None,
                                 theme_token_resolution: Default::default(),
+                                span: None,
                             },
                         )));
                     }
@@ -239,6 +256,7 @@ pub fn extract_stylex_namespace_styles<'a>(
                                 style_order: None,
                                 layer: None,
                                 theme_token_resolution: Default::default(),
+                                span: None,
                             },
                         )));
                     }
@@ -264,14 +282,20 @@ pub fn extract_stylex_namespace_styles<'a>(
                         style_order: None,
                         layer: None,
                         theme_token_resolution: Default::default(),
+                        span: None,
                     },
                 )));
                 continue;
             } else {
                 // Phase 4c: Non-static values in create() are not supported
                 if !matches!(&style_prop.value, Expression::NullLiteral(_)) {
-                    eprintln!(
-                        "[stylex] ERROR: Non-static value for property '{prop_name}' in stylex.create(). Only string literals, numbers, null, objects (conditions), firstThatWorks(), types.*(), and arrow functions are allowed."
+                    report(
+                        DiagnosticSeverity::Error,
+                        DiagnosticCode::UnsupportedValue,
+                        format!(
+                            "[stylex] Non-static value for property '{prop_name}' in stylex.create(). Only string literals, numbers, null, objects (conditions), firstThatWorks(), types.*(), and arrow functions are allowed."
+                        ),
+                        style_prop.value.span(),
                     );
                 }
                 continue;
@@ -288,6 +312,7 @@ pub fn extract_stylex_namespace_styles<'a>(
                     style_order: None,
                     layer: None,
                     theme_token_resolution: Default::default(),
+                    span: None,
                 },
             )));
         }
@@ -374,6 +399,13 @@ fn extract_stylex_dynamic_namespace<'a>(
             let var_name = sheet_to_variable_name(&css_property, 0, None);
             css_vars.push((param_idx, var_name));
             let param_name = &param_names[param_idx];
+            warn(
+                DiagnosticCode::DynamicStyle,
+                format!(
+                    "`{css_property}` is not static (`{param_name}`), falling back to a CSS variable"
+                ),
+                prop.value.span(),
+            );
             styles.push(ExtractStyleProp::Static(ExtractStyleValue::Dynamic(
                 ExtractDynamicStyle::new(&css_property, 0, param_name, None),
             )));
@@ -391,6 +423,7 @@ fn extract_stylex_dynamic_namespace<'a>(
                         style_order: None,
                         layer: None,
                         theme_token_resolution: Default::default(),
+                        span: None,
                     },
                 )));
                 continue;
@@ -409,6 +442,7 @@ fn extract_stylex_dynamic_namespace<'a>(
                     style_order: None,
                     layer: None,
                     theme_token_resolution: Default::default(),
+                    span: None,
                 },
             )));
         }
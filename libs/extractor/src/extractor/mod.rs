@@ -1,9 +1,13 @@
 use oxc_ast::ast::Expression;
 
-use crate::{ExtractStyleProp, extract_style::extract_keyframes::ExtractKeyframes};
+use crate::{
+    ExtractStyleProp,
+    extract_style::{extract_keyframes::ExtractKeyframes, extract_style_value::ExtractStyleValue},
+};
 
 pub(super) mod extract_global_style_from_expression;
 pub(super) mod extract_keyframes_from_expression;
+pub(super) mod extract_recipe_from_expression;
 pub(super) mod extract_style_from_expression;
 pub(super) mod extract_style_from_jsx;
 pub(super) mod extract_style_from_member_expression;
@@ -37,3 +41,10 @@ pub struct GlobalExtractResult<'a> {
 pub struct KeyframesExtractResult {
     pub keyframes: ExtractKeyframes,
 }
+
+#[derive(Debug)]
+pub struct RecipeExtractResult<'a> {
+    pub styles: Vec<ExtractStyleValue>,
+    /// class name lookup function
+    pub expression: Expression<'a>,
+}
@@ -1,6 +1,9 @@
 mod as_visit;
+#[cfg(not(target_arch = "wasm32"))]
+mod batch;
 mod component;
 mod css_utils;
+pub mod diagnostic;
 pub mod extract_style;
 mod extractor;
 mod gen_class_name;
@@ -13,15 +16,20 @@ mod util_type;
 mod utils;
 mod vanilla_extract;
 mod visit;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, finish_diagnostics, take_diagnostics, warn};
+use crate::extract_style::extract_static_style::SourceSpan;
 use crate::extract_style::extract_style_value::ExtractStyleValue;
 use crate::visit::DevupVisitor;
+#[cfg(not(target_arch = "wasm32"))]
+pub use batch::{BatchError, ExtractInput, extract_batch};
+pub use component::{CustomComponent, parse_custom_components};
 use css::file_map::{canonical, get_file_num_by_filename, is_global};
 use oxc_allocator::{Allocator, CloneIn};
 use oxc_ast::ast::Expression;
 use oxc_ast_visit::VisitMut;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
+use oxc_span::{SPAN, SourceType};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
@@ -98,6 +106,36 @@ impl<'a> ExtractStyleProp<'a> {
         }
     }
 
+    /// Record where the static styles of this prop came from
+    pub fn set_span(&mut self, span: SourceSpan) {
+        match self {
+            ExtractStyleProp::Static(style) => style.set_span(span),
+            ExtractStyleProp::StaticArray(array) => {
+                array.iter_mut().for_each(|style| style.set_span(span));
+            }
+            ExtractStyleProp::Conditional {
+                consequent,
+                alternate,
+                ..
+            } => {
+                consequent
+                    .iter_mut()
+                    .chain(alternate.iter_mut())
+                    .for_each(|style| style.set_span(span));
+            }
+            ExtractStyleProp::Enum { map, .. } => map
+                .values_mut()
+                .flatten()
+                .for_each(|style| style.set_span(span)),
+            ExtractStyleProp::Expression { styles, .. } => {
+                styles.iter_mut().for_each(|style| style.set_span(span));
+            }
+            ExtractStyleProp::MemberExpression { map, .. } => {
+                map.values_mut().for_each(|style| style.set_span(span));
+            }
+        }
+    }
+
     pub fn extract(&self) -> Vec<ExtractStyleValue> {
         match self {
             ExtractStyleProp::Static(style) => vec![style.clone()],
@@ -140,8 +178,12 @@ pub struct ExtractOutput {
 
     pub map: Option<String>,
     pub css_file: Option<String>,
+
+    /// props and expressions that could not be statically extracted
+    pub diagnostics: Vec<Diagnostic>,
 }
 
+#[derive(Debug, Clone)]
 pub struct ExtractOption {
     pub package: String,
     pub css_dir: String,
@@ -149,6 +191,10 @@ pub struct ExtractOption {
     pub import_main_css: bool,
     /// Import aliases for redirecting imports from other CSS-in-JS libraries to the target package
     pub import_aliases: HashMap<String, ImportAlias>,
+    /// Fail the extraction instead of falling back to runtime CSS variables for dynamic values
+    pub strict: bool,
+    /// User-defined components, keyed by export name
+    pub components: HashMap<String, CustomComponent>,
 }
 
 impl Default for ExtractOption {
@@ -159,6 +205,8 @@ impl Default for ExtractOption {
             single_css: false,
             import_main_css: false,
             import_aliases: HashMap::new(),
+            strict: false,
+            components: HashMap::new(),
         }
     }
 }
@@ -178,6 +226,9 @@ pub fn extract(
         &option.import_aliases,
     );
 
+    // Diagnostics are collected per thread; drop leftovers from an aborted extraction
+    take_diagnostics();
+
     // Step 2: Check if code contains the target package (after transformation)
     let has_relevant_import = transformed_code.contains(option.package.as_str())
         || transformed_code.contains("@stylexjs/stylex");
@@ -189,6 +240,7 @@ pub fn extract(
             code: code.to_string(),
             map: None,
             css_file: None,
+            diagnostics: vec![],
         });
     }
 
@@ -231,8 +283,15 @@ pub fn extract(
                     (generated, true)
                 }
             }
-            Err(_) => {
+            Err(error) => {
                 // Fall back to treating as regular file if execution fails
+                warn(
+                    DiagnosticCode::VanillaExtractExecution,
+                    format!(
+                        "vanilla-extract execution failed, extracting as a regular file: {error}"
+                    ),
+                    SPAN,
+                );
                 (transformed_code.clone(), false)
             }
         }
@@ -247,6 +306,7 @@ pub fn extract(
             code: code.to_string(),
             map: None,
             css_file: None,
+            diagnostics: finish_diagnostics(option.strict)?,
         });
     }
 
@@ -292,6 +352,8 @@ pub fn extract(
         &option.package,
         css_files,
         if global { None } else { Some(bucket) },
+        &option.components,
+        (!is_vanilla_extract).then_some(code_to_parse.as_str()),
     );
     visitor.visit_program(&mut program);
     let result = Codegen::new()
@@ -306,6 +368,7 @@ pub fn extract(
         code: result.code,
         map: result.map.map(|m| m.to_json_string()),
         css_file: Some(css_file),
+        diagnostics: finish_diagnostics(option.strict)?,
     })
 }
 
@@ -346,6 +409,8 @@ fn extract_class_map_from_code(
             &option.package,
             css_files,
             if global { None } else { Some(bucket) },
+            &option.components,
+            None,
         );
         visitor.visit_program(&mut program);
 
@@ -421,8 +486,11 @@ mod tests {
     use std::collections::BTreeSet;
 
     use super::*;
+    use crate::diagnostic::DiagnosticSeverity;
+    use css::breakpoints::{ResponsiveKey, set_responsive_keys};
     use css::class_map::reset_class_map;
     use css::file_map::reset_file_map;
+    use css::style_selector::AtRuleKind;
     use insta::assert_debug_snapshot;
     use rstest::rstest;
     use serial_test::serial;
@@ -479,6 +547,8 @@ mod tests {
             single_css: false,
             import_main_css: false,
             import_aliases: HashMap::new(),
+            strict: false,
+            components: HashMap::new(),
         };
         let src = r#"import { Box } from "@devup-ui/react"; const a = <Box bg="red" />;"#;
         let parent = extract("parent.tsx", src, opt()).unwrap();
@@ -512,6 +582,8 @@ mod tests {
             single_css: false,
             import_main_css: false,
             import_aliases: HashMap::new(),
+            strict: false,
+            components: HashMap::new(),
         };
         let src = r#"import { Box } from "@devup-ui/react"; const a = <Box bg="red" />;"#;
         let global_out = extract("shared.tsx", src, opt()).unwrap();
@@ -550,7 +622,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -567,7 +641,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -584,7 +660,7 @@ mod tests {
                 r#"import {Box} from '@devup-ui/core'
         <Box padding={1} ref={ref} data-test={1} role={2} children={[]} onClick={()=>{}} aria-valuenow={24} key={2} tabIndex={1} id="id" />
         "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -602,7 +678,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -625,7 +703,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -644,7 +724,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -663,7 +745,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -682,7 +766,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -701,7 +787,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -720,7 +808,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -739,7 +829,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -758,7 +850,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -777,7 +871,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -796,7 +892,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -815,7 +913,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -834,7 +934,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -853,7 +955,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -872,7 +976,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -886,7 +992,7 @@ mod tests {
                 r#"import {Box} from '@devup-ui/core'
         <Box as={{A: "section", B: "div", C: Variable, D, [key]: "section", ...rest}[key]} w="100%" h="100%" />
         "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -899,7 +1005,7 @@ mod tests {
                 r#"import {Box} from '@devup-ui/core'
         <Box as={{A: "section", B: "div", C: Variable, D, ["key"]: "section", ...rest}["key"]} w="100%" h="100%" />
         "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -917,7 +1023,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -936,7 +1044,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -955,7 +1065,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -975,7 +1087,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -995,7 +1109,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1017,7 +1133,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1035,7 +1153,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1053,7 +1173,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1072,7 +1194,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1091,7 +1215,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1110,7 +1236,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1135,7 +1263,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1161,7 +1291,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1186,7 +1318,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1209,7 +1343,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1228,7 +1364,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1247,7 +1385,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1266,7 +1406,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1284,7 +1426,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1309,7 +1453,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1328,7 +1474,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1351,7 +1499,9 @@ mod tests {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1388,7 +1538,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1411,7 +1563,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1433,7 +1587,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1449,7 +1605,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1472,7 +1630,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1491,7 +1651,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1510,7 +1672,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1529,7 +1693,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1548,7 +1714,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1571,7 +1739,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1590,7 +1760,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1609,7 +1781,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1632,7 +1806,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1651,7 +1827,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1670,7 +1848,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1689,7 +1869,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1708,7 +1890,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1731,7 +1915,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1754,13 +1940,49 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
         ));
     }
 
+    #[test]
+    #[serial]
+    fn extract_responsive_object_style_props() {
+        reset_class_map();
+        reset_file_map();
+        set_responsive_keys(HashMap::from([
+            ("base".to_string(), ResponsiveKey::Level(0)),
+            ("md".to_string(), ResponsiveKey::Level(2)),
+            (
+                "mdDown".to_string(),
+                ResponsiveKey::At(AtRuleKind::Media, "(max-width:767.98px)".to_string()),
+            ),
+            (
+                "mdOnly".to_string(),
+                ResponsiveKey::At(
+                    AtRuleKind::Media,
+                    "(min-width:768px)and (max-width:991.98px)".to_string(),
+                ),
+            ),
+        ]));
+        let output = extract(
+            "test.tsx",
+            r#"import { Box } from "@devup-ui/core";
+<Box p={{ base: 2, md: 4 }} bg={{ mdDown: "red", mdOnly: "blue" }} _hover={{ color: { base: "red", md: color } }} />;
+"#,
+            ExtractOption {
+                package: "@devup-ui/core".to_string(),
+                ..ExtractOption::default()
+            },
+        );
+        set_responsive_keys(HashMap::new());
+        assert_debug_snapshot!(ToBTreeSet::from(output.unwrap()));
+    }
+
     #[test]
     #[serial]
     fn extract_wrong_responsive_style_props() {
@@ -1777,7 +1999,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1796,7 +2020,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1819,7 +2045,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1838,7 +2066,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1861,7 +2091,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1880,7 +2112,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1899,7 +2133,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1918,7 +2154,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1937,7 +2175,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1956,7 +2196,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1975,7 +2217,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -1994,7 +2238,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2013,7 +2259,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2032,7 +2280,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2051,7 +2301,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2074,7 +2326,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2093,7 +2347,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2112,7 +2368,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2131,7 +2389,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2150,7 +2410,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2169,7 +2431,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2192,7 +2456,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2211,7 +2477,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2234,7 +2502,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2253,7 +2523,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2272,7 +2544,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2291,7 +2565,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2310,7 +2586,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2329,7 +2607,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2348,7 +2628,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2367,7 +2649,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2390,7 +2674,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2409,7 +2695,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2428,7 +2716,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2447,7 +2737,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2470,7 +2762,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2489,7 +2783,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2508,7 +2804,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2527,7 +2825,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2549,7 +2849,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2568,7 +2870,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2593,7 +2897,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2631,7 +2937,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2654,7 +2962,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2677,7 +2987,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2700,7 +3012,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2723,7 +3037,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2746,7 +3062,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2767,7 +3085,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2795,7 +3115,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2818,7 +3140,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2841,7 +3165,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2868,7 +3194,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2891,7 +3219,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2914,7 +3244,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2944,7 +3276,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2974,7 +3308,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -2997,7 +3333,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3016,7 +3354,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3035,7 +3375,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3054,7 +3396,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3073,7 +3417,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3092,7 +3438,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3111,7 +3459,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3136,7 +3486,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3159,7 +3511,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3185,7 +3539,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3210,7 +3566,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3238,7 +3596,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3265,7 +3625,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3291,7 +3653,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3323,7 +3687,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3350,7 +3716,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3379,7 +3747,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3408,7 +3778,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3437,7 +3809,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3466,7 +3840,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3491,7 +3867,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3512,7 +3890,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3531,7 +3911,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3553,7 +3935,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3578,7 +3962,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3601,7 +3987,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3626,7 +4014,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3651,7 +4041,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3672,7 +4064,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3694,7 +4088,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3715,7 +4111,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3739,7 +4137,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3758,7 +4158,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3777,7 +4179,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3796,7 +4200,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3820,7 +4226,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3850,7 +4258,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3869,7 +4279,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3888,7 +4300,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3908,7 +4322,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3927,7 +4343,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3946,7 +4364,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3965,7 +4385,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -3994,7 +4416,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4022,7 +4446,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4045,7 +4471,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4068,7 +4496,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4087,7 +4517,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4106,7 +4538,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4129,7 +4563,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4148,7 +4584,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4167,7 +4605,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4189,7 +4629,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4208,7 +4650,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4227,7 +4671,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4251,7 +4697,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4279,7 +4727,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4308,7 +4758,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4329,7 +4781,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap_err()
@@ -4348,7 +4802,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap_err()
@@ -4372,7 +4828,9 @@ import clsx from 'clsx'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4391,7 +4849,9 @@ useTheme();
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4433,7 +4893,9 @@ export {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4471,7 +4933,9 @@ export {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4491,7 +4955,9 @@ e(o, { className: "a", bg: "red" })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4511,7 +4977,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" } })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4531,7 +4999,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4547,7 +5017,7 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
         import { Box as o } from "@devup-ui/core";
         e(o, { as: b ? "div" : "section", className: "a", bg: variable, style: { color: "blue" }, props: { animate: { duration: 1 } } })
         "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -4560,7 +5030,7 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
         import { Box as o } from "@devup-ui/core";
         e(o, { as: Variable, className: "a", bg: variable, style: { color: "blue" }, props: { animate: { duration: 1 } } })
         "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -4574,7 +5044,7 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
         import { Box as o } from "@devup-ui/core";
         e(o, { as: b ? null : undefined, className: "a", bg: variable, style: { color: "blue" }, props: { animate: { duration: 1 } } })
         "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -4585,31 +5055,31 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
     fn support_transpile_cjs() {
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.cjs", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(r.Text,{typography:"header",children:"typo"}),e.jsx(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.cjs", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(r.Text,{typography:"header",children:"typo"}),e.jsx(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
 
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.cjs", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const {jsx:e1, jsxs:e2}=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e2("div",{children:[e1(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e1(r.Text,{typography:"header",children:"typo"}),e1(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.cjs", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const {jsx:e1, jsxs:e2}=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e2("div",{children:[e1(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e1(r.Text,{typography:"header",children:"typo"}),e1(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
 
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(r.Text,{typography:"header",children:"typo"}),e.jsx(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(r.Text,{typography:"header",children:"typo"}),e.jsx(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
 
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(r.Text,{typography:`header`,children:"typo"}),e.jsx(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),r=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(r.Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(r.Text,{typography:`header`,children:"typo"}),e.jsx(r.Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
 
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),{Box,Text,Flex}=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(Text,{typography:`header`,children:"typo"}),e.jsx(Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),{Box,Text,Flex}=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(Box,{_hover:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(Text,{typography:`header`,children:"typo"}),e.jsx(Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
 
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),{Box,Text,Flex}=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(Box,{["_hover"]:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(Text,{typography:`header`,children:"typo"}),e.jsx(Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),{Box,Text,Flex}=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(Box,{["_hover"]:{bg:"blue"},bg:"$text",color:"red",children:"hello"}),e.jsx(Text,{typography:`header`,children:"typo"}),e.jsx(Flex,{as:"section",mt:2,children:"section"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
 
         reset_class_map();
         reset_file_map();
-        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),{Box,Text,Flex}=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(Box,{["_hover"]:{bg:"blue"},bg:"$text",[variable]:"red",children:"hello"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }).unwrap()));
+        assert_debug_snapshot!(ToBTreeSet::from(extract("test.js", r#""use strict";Object.defineProperty(exports,Symbol.toStringTag,{value:"Module"});const e=require("react/jsx-runtime"),{Box,Text,Flex}=require("@devup-ui/react");function t(){return e.jsxs("div",{children:[e.jsx(Box,{["_hover"]:{bg:"blue"},bg:"$text",[variable]:"red",children:"hello"})]})}exports.Lib=t;"#, ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }).unwrap()));
     }
 
     #[test]
@@ -4623,7 +5093,7 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                 r"import {Flex} from '@devup-ui/core'
         <Flex opacity={1} zIndex={2} fontWeight={900} scale={2} flex={1} lineHeight={1} tabSize={4} MozTabSize={4} WebkitLineClamp={4} />
         ",
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -4645,7 +5115,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4668,7 +5140,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4687,7 +5161,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4706,7 +5182,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4729,7 +5207,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4748,7 +5228,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4767,7 +5249,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4790,7 +5274,9 @@ e(o, { className: "a", bg: variable, style: { color: "blue" }, ...props })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4828,7 +5314,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4851,7 +5339,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4870,7 +5360,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4889,7 +5381,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4908,7 +5402,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4927,7 +5423,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4947,7 +5445,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4967,7 +5467,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -4987,7 +5489,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5009,7 +5513,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5028,7 +5534,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5047,7 +5555,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5066,7 +5576,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5085,7 +5597,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5104,7 +5618,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5123,7 +5639,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5146,7 +5664,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5165,7 +5685,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5184,7 +5706,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5203,7 +5727,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5223,7 +5749,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5250,7 +5778,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5269,7 +5799,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5292,7 +5824,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5311,7 +5845,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5330,7 +5866,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5349,7 +5887,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5371,7 +5911,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5390,7 +5932,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5409,7 +5953,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5452,7 +5998,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5475,7 +6023,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5494,7 +6044,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5513,7 +6065,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5532,7 +6086,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5555,7 +6111,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5573,7 +6131,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5596,7 +6156,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5615,7 +6177,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5637,7 +6201,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5660,7 +6226,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5679,7 +6247,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5701,7 +6271,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5720,7 +6292,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5743,7 +6317,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5766,7 +6342,9 @@ export default function Card({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5793,7 +6371,9 @@ PROCESS_DATA.map(({ id, title, content }, idx) => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5816,7 +6396,9 @@ PROCESS_DATA.map(({ id, title, content }, idx) => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5835,7 +6417,9 @@ PROCESS_DATA.map(({ id, title, content }, idx) => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5858,7 +6442,9 @@ PROCESS_DATA.map(({ id, title, content }, idx) => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5881,7 +6467,9 @@ PROCESS_DATA.map(({ id, title, content }, idx) => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5906,7 +6494,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5932,7 +6522,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5954,7 +6546,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -5977,7 +6571,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6012,7 +6608,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6035,7 +6633,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6054,7 +6654,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6073,7 +6675,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6092,7 +6696,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6111,7 +6717,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6134,7 +6742,34 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
+                }
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn scoped_theme_selector() {
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.jsx",
+                r#"import {Box} from '@devup-ui/core'
+    <Box _scopedThemeDark={{ bg: "black", _hover:{bg:"gray"} }} />
+            "#,
+                ExtractOption {
+                    package: "@devup-ui/core".to_string(),
+                    css_dir: "@devup-ui/core".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6157,7 +6792,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6175,7 +6812,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6201,7 +6840,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6228,7 +6869,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6251,7 +6894,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6274,7 +6919,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6295,7 +6942,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6322,7 +6971,9 @@ import {Button} from '@devup/ui'
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6361,7 +7012,9 @@ export {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6380,7 +7033,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6399,7 +7054,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6418,7 +7075,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6444,7 +7103,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6471,7 +7132,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6490,7 +7153,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6516,7 +7181,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6536,7 +7203,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6556,7 +7225,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6577,7 +7248,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6597,7 +7270,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6622,7 +7297,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6642,7 +7319,9 @@ export {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6665,7 +7344,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6689,7 +7370,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6709,7 +7392,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6730,7 +7415,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6750,7 +7437,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6773,7 +7462,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6797,7 +7488,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6820,7 +7513,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6847,7 +7542,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6870,7 +7567,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6893,7 +7592,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6923,7 +7624,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6949,7 +7652,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -6975,7 +7680,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7000,7 +7707,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7021,7 +7730,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7043,7 +7754,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7064,7 +7777,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7085,7 +7800,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7106,7 +7823,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7127,7 +7846,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7148,7 +7869,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7172,7 +7895,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7191,7 +7916,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7210,7 +7937,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7233,7 +7962,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7257,7 +7988,9 @@ e(o, { styleVars: { c: "yellow" } })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7284,7 +8017,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7307,7 +8042,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7330,7 +8067,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7353,7 +8092,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7376,7 +8117,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7399,7 +8142,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7422,7 +8167,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7441,7 +8188,9 @@ globalCss(...{})
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7460,7 +8209,9 @@ globalCss(...{div: {bg: "red"}})
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7480,7 +8231,9 @@ globalCss(...{div: {bg: "red"}, ...{span: {bg: "blue"}}})
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7507,7 +8260,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7526,7 +8281,9 @@ globalCss()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7545,7 +8302,9 @@ globalCss(1)
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7577,7 +8336,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7613,7 +8374,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7649,7 +8412,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7693,7 +8458,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7720,7 +8487,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7741,7 +8510,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7762,7 +8533,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7784,7 +8557,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7808,7 +8583,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7827,7 +8604,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7846,7 +8625,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7866,7 +8647,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7885,7 +8668,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7910,7 +8695,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7931,7 +8718,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7952,7 +8741,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -7988,7 +8779,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8015,7 +8808,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8041,7 +8836,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8066,7 +8863,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8093,7 +8892,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8120,7 +8921,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8162,7 +8965,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8183,7 +8988,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8204,7 +9011,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8225,7 +9034,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8250,7 +9061,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8271,7 +9084,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8296,7 +9111,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8318,7 +9135,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8339,7 +9158,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8361,7 +9182,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8380,7 +9203,9 @@ globalCss({})
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8399,7 +9224,9 @@ globalCss()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8425,7 +9252,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8448,7 +9277,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8471,7 +9302,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8494,7 +9327,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8517,7 +9352,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8540,7 +9377,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8563,7 +9402,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8586,7 +9427,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8625,7 +9468,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8648,7 +9493,9 @@ keyframes(...{
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8674,7 +9521,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8721,7 +9570,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8770,7 +9621,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8793,7 +9646,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8809,7 +9664,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8826,7 +9683,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8842,7 +9701,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8865,7 +9726,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: true,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8888,7 +9751,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8911,7 +9776,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8931,7 +9798,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8950,7 +9819,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8969,7 +9840,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -8988,7 +9861,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9007,7 +9882,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9033,7 +9910,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9055,7 +9934,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9077,7 +9958,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9101,7 +9984,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9125,7 +10010,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9149,7 +10036,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9176,7 +10065,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9200,7 +10091,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9228,7 +10121,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9252,7 +10147,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9273,7 +10170,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9294,7 +10193,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9315,7 +10216,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9338,7 +10241,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9358,7 +10263,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9381,7 +10288,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9401,7 +10310,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9424,7 +10335,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9444,7 +10357,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9469,7 +10384,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9490,7 +10407,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9514,7 +10433,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9535,7 +10456,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9559,7 +10482,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9588,7 +10513,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9614,7 +10541,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9636,7 +10565,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9658,7 +10589,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9682,7 +10615,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9702,7 +10637,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9722,7 +10659,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9750,7 +10689,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9775,7 +10716,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9802,7 +10745,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9827,7 +10772,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9854,7 +10801,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9877,7 +10826,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9897,7 +10848,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9917,7 +10870,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9937,7 +10892,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9968,7 +10925,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -9992,7 +10951,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10012,7 +10973,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10032,7 +10995,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10052,7 +11017,9 @@ keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10077,7 +11044,9 @@ const StyledDiv = styled.div({ ...baseStyles })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10101,7 +11070,9 @@ const className = css()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10125,7 +11096,9 @@ const className = css({})
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10152,7 +11125,9 @@ const spin = keyframes({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10178,7 +11153,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10205,7 +11182,9 @@ const Component = () => {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10230,7 +11209,9 @@ const className = myCss({ bg: "red" })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10256,7 +11237,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10288,7 +11271,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10314,7 +11299,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10338,7 +11325,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10364,7 +11353,9 @@ const key = "primary";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10390,7 +11381,9 @@ const Component = ({ className }) => {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10414,7 +11407,9 @@ const Component = ({ className }) => {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10439,7 +11434,9 @@ const className = css({ fontSize: `${size}px` })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10464,7 +11461,9 @@ const isActive = true;
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10490,7 +11489,9 @@ const Component = (props) => {
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10516,7 +11517,9 @@ const b = false;
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10541,7 +11544,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10565,7 +11570,9 @@ const spin = keyframes()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10589,7 +11596,9 @@ globalCss()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10613,7 +11622,9 @@ globalCss()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10637,7 +11648,9 @@ globalCss()
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10661,7 +11674,9 @@ const className = DevUI.css({ bg: "red" })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10687,7 +11702,9 @@ const colors = { primary: "blue", secondary: "red" };
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10712,7 +11729,9 @@ const dynamicColor = "blue";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10737,7 +11756,9 @@ const extraProps = { onClick: () => {} };
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10761,7 +11782,9 @@ const extraProps = { onClick: () => {} };
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10788,7 +11811,9 @@ const margin = 5;
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10814,7 +11839,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10838,7 +11865,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10858,7 +11887,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10878,7 +11909,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10898,7 +11931,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10922,7 +11957,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10946,7 +11983,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10966,7 +12005,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -10986,7 +12027,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11006,7 +12049,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11030,7 +12075,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11050,7 +12097,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11070,7 +12119,9 @@ const color = "red";
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11100,7 +12151,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11126,7 +12179,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11152,7 +12207,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11185,7 +12242,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11215,7 +12274,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11244,7 +12305,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: false,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11274,7 +12337,9 @@ export const container: string = style({ background: "red", padding: 16 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11298,7 +12363,9 @@ export const wrapper = style({ backgroundColor: "white", margin: 8 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11318,7 +12385,9 @@ export const link = style({ color: "blue", textDecoration: "underline" })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11357,7 +12426,9 @@ export const text = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases
+                    import_aliases,
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11383,7 +12454,9 @@ export const button = style({ background: primaryColor, padding: spacing })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11408,7 +12481,9 @@ export const box = style({ padding: base * 2, margin: base / 2 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11433,7 +12508,9 @@ export const extended = style({ ...baseStyle, background: "red" })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11457,7 +12534,9 @@ export const hoverButton = style({ background: "gray", _hover: { background: "bl
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11481,7 +12560,9 @@ export const responsiveBox = style({ padding: [8, 16, 32] })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11506,7 +12587,9 @@ export const animated = style({ animation: "fadeIn 1s ease-in" })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11526,7 +12609,9 @@ globalStyle("body", { margin: 0, padding: 0 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11556,7 +12641,9 @@ export const box = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11579,7 +12666,9 @@ export const box = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11607,7 +12696,9 @@ export const background = styleVariants({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11631,7 +12722,9 @@ export const button = styleVariants({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11661,7 +12754,9 @@ export const text = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11688,7 +12783,9 @@ export const body = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11727,7 +12824,9 @@ export const box = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11765,7 +12864,9 @@ export const darkTheme = createTheme(vars, {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11797,7 +12898,9 @@ globalStyle('*', {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11832,7 +12935,9 @@ export const responsive = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11865,7 +12970,9 @@ export const vars = createGlobalTheme(':root', {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11900,7 +13007,9 @@ export const button = style([base, interactive, {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11941,7 +13050,9 @@ export const button = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -11973,7 +13084,9 @@ export const child = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12011,7 +13124,9 @@ export const responsive = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12042,7 +13157,9 @@ export const grid = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12179,7 +13296,9 @@ export const grid = style({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12205,7 +13324,9 @@ export const grid = style({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12232,6 +13353,8 @@ export const broken = style((() => { throw new Error("fail"); })())
                 single_css: true,
                 import_main_css: false,
                 import_aliases: HashMap::new(),
+                strict: false,
+                components: HashMap::new(),
             },
         );
         // Should not panic, may return error or empty styles
@@ -12258,6 +13381,8 @@ const unused = 1;
                 single_css: true,
                 import_main_css: false,
                 import_aliases: HashMap::new(),
+                strict: false,
+                components: HashMap::new(),
             },
         );
         assert!(result.is_ok());
@@ -12283,7 +13408,9 @@ export const box = style({ padding: SPACING })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12317,7 +13444,9 @@ export const [lightTheme, vars] = createTheme({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12350,7 +13479,9 @@ export const themed = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12376,7 +13507,9 @@ export const withEmpty = style([{}])
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12409,7 +13542,9 @@ export const sizes = styleVariants({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12440,7 +13575,9 @@ export const containerStyle = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12477,7 +13614,9 @@ export const box = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12513,7 +13652,9 @@ export const darkTheme = createTheme(contract, {
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12544,7 +13685,9 @@ export const text = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12574,7 +13717,9 @@ export const box = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12600,7 +13745,9 @@ export const box = style({ padding: 8 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12625,7 +13772,7 @@ const internalContainer = createContainer()
 const internalLayer = layer('internal')
 export const publicStyle = style({ margin: 8 })
 ",
-                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -12663,7 +13810,9 @@ export const sibling = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12710,7 +13859,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12744,7 +13895,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12777,7 +13930,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12804,7 +13959,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12831,7 +13988,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12856,7 +14015,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12881,6 +14042,8 @@ globalCss({
                 single_css: true,
                 import_main_css: false,
                 import_aliases: HashMap::new(),
+                strict: false,
+                components: HashMap::new(),
             },
         );
         assert!(result.is_ok());
@@ -12907,6 +14070,8 @@ const x = style({ padding: [[[}}} // invalid syntax
                 single_css: true,
                 import_main_css: false,
                 import_aliases: HashMap::new(),
+                strict: false,
+                components: HashMap::new(),
             },
         );
         // Should not panic - falls back to regular processing
@@ -12938,7 +14103,9 @@ const buttonStyle = css({ bg: 'red', p: 4 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -12971,7 +14138,9 @@ export const spinner = style({ animation: spin })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -12998,7 +14167,9 @@ export const myTheme = createTheme({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13028,7 +14199,9 @@ export const buttons = styleVariants({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13063,7 +14236,9 @@ export const box = style({ padding: 8 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13089,7 +14264,9 @@ export const text = style({ fontFamily: minimalFont })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13117,7 +14294,9 @@ export const animated = style({ animation: fadeIn })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13151,7 +14330,9 @@ export const box = style({ padding: 8 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13177,7 +14358,9 @@ export const complex = style([base, { margin: 4 }, { color: 'blue' }])
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13207,7 +14390,9 @@ export const child = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13240,7 +14425,7 @@ export const buttons = styleVariants({
 })
 export const box = style({ fontFamily: myFont })
 ",
-                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -13268,7 +14453,9 @@ export const themed = style({ color: themeVars.colors.primary })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13302,7 +14489,9 @@ export const heading = style({ fontFamily: secondFont })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13333,7 +14522,9 @@ export const box = style({ padding: 8 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13364,7 +14555,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13394,7 +14587,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13426,7 +14621,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13457,7 +14654,7 @@ export const child = style({
 })
 globalStyle('body', { margin: 0 })
 ",
-                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -13487,7 +14684,9 @@ export const box = style({ padding: 8 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13520,7 +14719,9 @@ globalCss({
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13554,7 +14755,9 @@ export const child = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13587,7 +14790,9 @@ export const card = style({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13610,6 +14815,8 @@ export const card = style({
                 single_css: true,
                 import_main_css: false,
                 import_aliases: HashMap::new(),
+                strict: false,
+                components: HashMap::new(),
             },
             &style_names,
         )
@@ -13644,7 +14851,9 @@ const Button = styled.button({ bg: 'red', p: 4 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -13674,7 +14883,9 @@ const Card = styled("div")({ bg: 'blue', m: 2 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -13703,7 +14914,9 @@ const element = <div>Hello</div>",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -13733,7 +14946,9 @@ const Button = myStyled.button({ bg: 'green', p: 2 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -13764,7 +14979,9 @@ const Button = emotionStyled.button({ bg: 'purple', p: 3 })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -13803,7 +15020,9 @@ const Button = styled.button({ bg: 'red' })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: aliases
+                    import_aliases: aliases,
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -13827,7 +15046,9 @@ const Button = styled.button({ bg: 'red' })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13851,7 +15072,9 @@ const Button = styled.button({ bg: 'red' })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13875,7 +15098,9 @@ const Button = styled.button({ bg: 'red' })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13899,7 +15124,9 @@ const Button = styled.button({ bg: 'red' })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13923,7 +15150,9 @@ const Button = styled.button({ bg: 'red' })
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -13962,7 +15191,7 @@ export const Card = () => (
   </Box>
 )
 "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -13980,7 +15209,7 @@ export const Card = () => (
                 r#"import {Box} from '@devup-ui/core'
 <Box className="p-2 sm:p-4 md:p-6 lg:p-8 xl:p-10 2xl:p-12 hover:bg-blue-500 focus:ring-2 active:scale-95 disabled:opacity-50 dark:bg-gray-900 dark:hover:bg-gray-800" />
 "#,
-                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() }
+                ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() }
             )
             .unwrap()
         ));
@@ -14008,7 +15237,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14032,7 +15263,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14058,7 +15291,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14084,7 +15319,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14110,7 +15347,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14136,7 +15375,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14162,7 +15403,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14188,7 +15431,9 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14215,7 +15460,71 @@ export const Card = () => (
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
+                }
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_tailwind_apply() {
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r"import {css, styled, globalCss} from '@devup-ui/core'
+const card = css`
+  @apply flex items-center p-4 md:p-6;
+  &:hover {
+    @apply bg-gray-100 data-[state=open]:bg-white;
+  }
+`
+const Button = styled.button`
+  color: red;
+  @apply rounded-md focus:ring-2;
+`
+globalCss({
+  body: `@apply m-0 hover:text-black`
+})
+",
+                ExtractOption {
+                    package: "@devup-ui/core".to_string(),
+                    css_dir: "@devup-ui/core".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
+                }
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_tailwind_merge_conflicts() {
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r#"import {Box} from '@devup-ui/core'
+<Box className="px-2 p-4 pt-1 md:p-2 md:p-6 hover:bg-red-500 hover:bg-blue-500 text-red-500 text-lg" />
+"#,
+                ExtractOption {
+                    package: "@devup-ui/core".to_string(),
+                    css_dir: "@devup-ui/core".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14243,7 +15552,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14265,7 +15576,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14291,7 +15604,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14313,7 +15628,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14340,7 +15657,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14363,7 +15682,9 @@ export { c as Lib };"#,
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -14431,7 +15752,9 @@ const styles = stylex.create({ base: { color: 'red' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14453,7 +15776,9 @@ const styles = stylex.create({ base: { color: 'blue' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14475,7 +15800,9 @@ const styles = create({ base: { color: 'green' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14503,7 +15830,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14533,7 +15862,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14564,7 +15895,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14586,7 +15919,9 @@ const styles = stylex.create({});",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14608,7 +15943,9 @@ const styles = stylex.create({ base: {} });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14634,7 +15971,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14660,7 +15999,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14686,7 +16027,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14712,7 +16055,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14738,7 +16083,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14764,7 +16111,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14790,7 +16139,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14816,7 +16167,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14842,7 +16195,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14869,7 +16224,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14896,7 +16253,9 @@ const el = <div {...stylex.props(styles.base)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14922,7 +16281,9 @@ const el = <div {...stylex.props(styles.base, styles.active)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14948,7 +16309,9 @@ const el = <div {...stylex.props(styles.base, isActive && styles.active)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14974,7 +16337,9 @@ const el = <div {...stylex.props(isPrimary ? styles.primary : styles.secondary)}
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -14996,7 +16361,9 @@ const el = <div {...stylex.props()} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15021,7 +16388,9 @@ const el = <div {...stylex.props(styles.base)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15046,7 +16415,9 @@ const el = <div {...props(styles.base)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15071,7 +16442,9 @@ const el = <div {...stylex.props(false, null, styles.base)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15100,7 +16473,9 @@ const fadeIn = stylex.keyframes({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15128,7 +16503,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15152,7 +16529,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15176,7 +16555,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15200,7 +16581,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15224,7 +16607,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15252,7 +16637,9 @@ const styles = create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15279,7 +16666,9 @@ const result = stylex.props(styles.bar(h));",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15307,7 +16696,9 @@ const result = stylex.props(styles.bar(h));",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15335,7 +16726,9 @@ const result = stylex.props(styles.bar(myH, myW));",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15366,7 +16759,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15394,7 +16789,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15421,7 +16818,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15448,7 +16847,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15474,7 +16875,9 @@ const { base } = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15503,7 +16906,9 @@ const el = <div>
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15533,7 +16938,9 @@ const el = <div>
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15562,7 +16969,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15591,7 +17000,9 @@ const styles = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15618,7 +17029,9 @@ const composed = stylex.create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15645,7 +17058,9 @@ const composed = create({
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15673,7 +17088,9 @@ const el = <div {...stylex.props(composed.fancy)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15699,7 +17116,9 @@ const styles = create({ base: { color: firstThatWorks('red', 'blue') } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15721,7 +17140,9 @@ const styles = stylex.create({ base: { fontSize: 16, lineHeight: 1.5 } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15743,7 +17164,9 @@ const styles = stylex.create({ base: { zIndex: stylex.firstThatWorks(10, 20) } }
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15765,7 +17188,9 @@ const styles = stylex.create({ base: { fontSize: stylex.types.length(16) } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15787,7 +17212,9 @@ const styles = stylex.create({ base: { fontSize: stylex.types.length(someVar) }
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15809,7 +17236,9 @@ const styles = stylex.create({ base: { ':hover': 'invalid' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15831,7 +17260,9 @@ const styles = stylex.create({ base: 'not-an-object', active: { color: 'blue' }
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15854,7 +17285,9 @@ const styles = stylex.create({ ...other, base: { color: 'red' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15877,7 +17310,9 @@ const styles = stylex.create({ [key]: { color: 'red' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15899,7 +17334,9 @@ const styles = stylex.create({ base: () => ({ color: 'red' }) });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15921,7 +17358,9 @@ const styles = stylex.create({ base: (x) => { return { color: x }; } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15943,7 +17382,9 @@ const styles = stylex.create({ base: (x) => ({ fontSize: 16, height: x }) });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -15961,7 +17402,7 @@ const styles = stylex.create({ base: (x) => ({ fontSize: 16, height: x }) });",
                 r"import stylex from '@stylexjs/stylex';
 const fadeIn = stylex.keyframes({ from: { opacity: '0' }, to: { opacity: '1' } });
 const styles = stylex.create({ base: (dur) => ({ animationName: fadeIn, animationDuration: dur }) });",
-                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() },
+                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() },
             )
             .unwrap()
         ));
@@ -15983,7 +17424,9 @@ const el = <div {...stylex.props(isActive ? styles.active : unknownRef)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16006,7 +17449,9 @@ const el = <div {...stylex.props(isActive ? unknownRef : styles.fallback)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16029,7 +17474,9 @@ const el = <div {...stylex.props(isActive ? unknownA : unknownB)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16052,7 +17499,9 @@ const el = <div {...stylex.props(styles.base, null, 0, false, '')} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16075,7 +17524,9 @@ const el = <div {...stylex.props(styles.base, someFunction())} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16098,7 +17549,9 @@ const el = <div {...stylex.props(styles.base)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16116,7 +17569,7 @@ const el = <div {...stylex.props(styles.base)} />;",
                 r"import stylex from '@stylexjs/stylex';
 const base = stylex.create({ dynamic: (x) => ({ color: x, fontSize: '14px' }) });
 const composed = stylex.create({ fancy: { ...stylex.include(base.dynamic), backgroundColor: 'blue' } });",
-                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() },
+                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() },
             )
             .unwrap()
         ));
@@ -16137,7 +17590,9 @@ const styles = create({ base: { color: 'red' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16164,7 +17619,9 @@ const styles = stylex.create("not-object");"#,
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16183,7 +17640,7 @@ const styles = stylex.create("not-object");"#,
                 r"import stylex from '@stylexjs/stylex';
 const other = { fontSize: '14px' };
 const styles = stylex.create({ base: { ':hover': { ...other, [Symbol()]: 'val', color: 'red' } } });",
-                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new() },
+                ExtractOption { package: "@devup-ui/react".to_string(), css_dir: "@devup-ui/react".to_string(), single_css: true, import_main_css: false, import_aliases: HashMap::new(), strict: false, components: HashMap::new() },
             )
             .unwrap()
         ));
@@ -16206,7 +17663,9 @@ const styles = stylex.create({ base: (x) => ({ ...other, [Symbol()]: x, color: '
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16230,7 +17689,9 @@ const styles = stylex.create({ base: (x) => ({ color: x, fontSize: someVar }) })
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16258,7 +17719,9 @@ const styles = stylex.create({ base: { ...notInclude(), color: 'red' } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16281,7 +17744,9 @@ const styles = create({ base: { width: types.length('100px') } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16305,7 +17770,9 @@ const styles = stylex.create({ base: { color: someFunc() } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16329,7 +17796,9 @@ const styles = stylex.create({ base: { fontSize: { default: stylex.types.length(
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16352,7 +17821,9 @@ const styles = stylex.create({ base: { opacity: { default: [1, 2] } } });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16376,7 +17847,9 @@ const styles = stylex.create({ base: { color: { ...spreadObj, default: 'red' } }
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16400,7 +17873,9 @@ const styles = stylex.create({ base: { color: { [key]: 'blue', default: 'red' }
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16428,7 +17903,9 @@ const el = <div {...stylex.props(styles.empty)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16452,7 +17929,9 @@ const el = <div {...stylex.props(styles.missing)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16476,7 +17955,9 @@ const el = <div {...stylex.props(isActive && unknownRef)} />;",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16500,7 +17981,9 @@ const composed = stylex.create({ test: { ...stylex.include(base.empty), color: '
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16524,7 +18007,9 @@ const styles = stylex.create({ base: (x) => x });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16548,7 +18033,9 @@ const styles = stylex.create({ base: (x) => (x) });",
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16573,7 +18060,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -16597,7 +18086,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16617,7 +18108,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16637,7 +18130,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16661,7 +18156,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16681,7 +18178,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16711,7 +18210,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16731,7 +18232,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16751,7 +18254,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16771,7 +18276,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16801,7 +18308,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16821,7 +18330,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16841,7 +18352,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16861,7 +18374,9 @@ const composed = stylex.create({ combined: { ...stylex.include(base.root) } });"
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -16953,6 +18468,8 @@ export const A = () => <Box _hover={arr[0]} />;
                         single_css: true,
                         import_main_css: false,
                         import_aliases: HashMap::new(),
+                        strict: false,
+                        components: HashMap::new(),
                     },
                 )
             });
@@ -16999,7 +18516,9 @@ export const A = () => <Box _hover={hoverStyle} bg="red" />;
                     css_dir: "@devup-ui/react".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 },
             )
             .unwrap()
@@ -17036,7 +18555,9 @@ export const A = () => <Box _hover={hoverStyle} bg="red" />;
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -17056,7 +18577,9 @@ export const A = () => <Box _hover={hoverStyle} bg="red" />;
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -17076,7 +18599,9 @@ export const A = () => <Box _hover={hoverStyle} bg="red" />;
                     css_dir: "@devup-ui/core".to_string(),
                     single_css: true,
                     import_main_css: false,
-                    import_aliases: HashMap::new()
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new()
                 }
             )
             .unwrap()
@@ -17117,6 +18642,8 @@ export const A = () => <Box _hover={fn()} />;
                     single_css: true,
                     import_main_css: false,
                     import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
                 },
             )
             .expect("graceful extract for pseudo-selector with non-literal value");
@@ -17127,4 +18654,499 @@ export const A = () => <Box _hover={fn()} />;
             );
         }
     }
+
+    #[test]
+    #[serial]
+    fn extract_source_spans() {
+        reset_class_map();
+        reset_file_map();
+        let output = extract(
+            "test.tsx",
+            r#"import {Box,css,globalCss,styled} from '@devup-ui/react'
+const a = css({ color: "red" })
+const B = styled.div`
+  width: 1px;
+`
+globalCss({ body: { margin: 0 } });
+<Box
+  bg="blue" _hover={{ p: 1 }} className={a}
+  {...{ m: 2 }}
+/>"#,
+            ExtractOption::default(),
+        )
+        .unwrap();
+        let mut spans = output
+            .styles
+            .iter()
+            .filter_map(|style| match style {
+                ExtractStyleValue::Static(style) => Some((style.property(), style.span())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        spans.sort();
+        assert_debug_snapshot!(spans);
+    }
+
+    #[test]
+    #[serial]
+    fn extract_diagnostics() {
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(
+            extract(
+                "test.tsx",
+                r"import {Box} from '@devup-ui/react'
+export const A = ({ width, rest }) => <Box w={width} bg={getBg()} _hover={rest.hover} {...rest} />;
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                },
+            )
+            .unwrap()
+            .diagnostics
+        );
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(
+            extract(
+                "test.tsx",
+                r#"import {Box} from '@devup-ui/react'
+export const A = () => <Box className="flex p-4 not-a-utility" />;
+"#,
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                },
+            )
+            .unwrap()
+            .diagnostics
+        );
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(
+            extract(
+                "test.tsx",
+                r"import {css} from '@devup-ui/react'
+const size = 10;
+export const a = css`width: ${size}px; color: ${color};`;
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                },
+            )
+            .unwrap()
+            .diagnostics
+        );
+
+        // fully static code has no diagnostics
+        reset_class_map();
+        reset_file_map();
+        assert!(
+            extract(
+                "test.tsx",
+                r"import {Box} from '@devup-ui/react'
+export const A = () => <Box w={10} _hover={{ bg: 'red' }} />;
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                },
+            )
+            .unwrap()
+            .diagnostics
+            .is_empty()
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn extract_strict() {
+        reset_class_map();
+        reset_file_map();
+        let error = extract(
+            "test.tsx",
+            r"import {Box} from '@devup-ui/react'
+export const A = ({ width }) => <Box w={width} bg='red' />;
+",
+            ExtractOption {
+                package: "@devup-ui/react".to_string(),
+                css_dir: "@devup-ui/react".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: HashMap::new(),
+                strict: true,
+                components: HashMap::new(),
+            },
+        )
+        .unwrap_err();
+        let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+        assert_eq!(diagnostic.severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostic.code, DiagnosticCode::DynamicStyle);
+        assert_eq!(diagnostic.span.start, 76);
+        assert_eq!(diagnostic.span.end, 81);
+
+        reset_class_map();
+        reset_file_map();
+        let error = extract(
+            "test.tsx",
+            r"import {styled} from '@devup-ui/react'
+export const A = styled.div`width: ${(p) => p.width};`;
+",
+            ExtractOption {
+                package: "@devup-ui/react".to_string(),
+                css_dir: "@devup-ui/react".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: HashMap::new(),
+                strict: true,
+                components: HashMap::new(),
+            },
+        )
+        .unwrap_err();
+        assert_debug_snapshot!(error.to_string());
+
+        reset_class_map();
+        reset_file_map();
+        let error = extract(
+            "test.tsx",
+            r"import * as stylex from '@stylexjs/stylex'
+const styles = stylex.create({ size: (h) => ({ height: h }) });
+export const A = () => <div {...stylex.props(styles.size(10))} />;
+",
+            ExtractOption {
+                package: "@devup-ui/react".to_string(),
+                css_dir: "@devup-ui/react".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: HashMap::new(),
+                strict: true,
+                components: HashMap::new(),
+            },
+        )
+        .unwrap_err();
+        assert_debug_snapshot!(error.to_string());
+
+        // other diagnostics stay warnings and static code still extracts
+        reset_class_map();
+        reset_file_map();
+        let output = extract(
+            "test.tsx",
+            r"import {Box} from '@devup-ui/react'
+export const A = (props) => <Box w={10} {...props} />;
+",
+            ExtractOption {
+                package: "@devup-ui/react".to_string(),
+                css_dir: "@devup-ui/react".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: HashMap::new(),
+                strict: true,
+                components: HashMap::new(),
+            },
+        )
+        .unwrap();
+        assert_eq!(output.styles.len(), 1);
+        assert_eq!(output.diagnostics.len(), 1);
+        assert_eq!(output.diagnostics[0].severity, DiagnosticSeverity::Warning);
+    }
+
+    #[test]
+    #[serial]
+    fn extract_custom_components() {
+        let components = HashMap::from([
+            (
+                "HStack".to_string(),
+                CustomComponent::new("div", &[("display", "flex"), ("gap", "2")]),
+            ),
+            (
+                "Link".to_string(),
+                CustomComponent::new("a", &[("px", "1")]),
+            ),
+        ]);
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r#"import {HStack, Link, Box} from '@devup-ui/react'
+<HStack gap={4}><Link href="/">home</Link><Box /></HStack>;
+"#,
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: components.clone(),
+                }
+            )
+            .unwrap()
+        ));
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r#"import * as D from '@devup-ui/react'
+<D.HStack as="section" />;
+"#,
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: components.clone(),
+                }
+            )
+            .unwrap()
+        ));
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.js",
+                r#"import { jsx as _jsx } from "react/jsx-runtime";
+import { HStack } from "@devup-ui/react";
+_jsx(HStack, { bg: "red" });
+"#,
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: components.clone(),
+                }
+            )
+            .unwrap()
+        ));
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r"import {styled, Link} from '@devup-ui/react'
+const Nav = styled(Link)({ color: 'red' });
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components,
+                }
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn extract_custom_component_default_overridden_by_prop() {
+        reset_class_map();
+        reset_file_map();
+        let output = extract(
+            "test.tsx",
+            r"import {Stack} from '@devup-ui/react'
+<Stack gap={4} />;
+",
+            ExtractOption {
+                package: "@devup-ui/react".to_string(),
+                css_dir: "@devup-ui/react".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: HashMap::new(),
+                strict: false,
+                components: HashMap::from([(
+                    "Stack".to_string(),
+                    CustomComponent::new("div", &[("gap", "2")]),
+                )]),
+            },
+        )
+        .unwrap();
+        let mut gaps = output
+            .styles
+            .iter()
+            .filter_map(|style| match style {
+                ExtractStyleValue::Static(style) if style.property() == "gap" => {
+                    Some((style.value().to_string(), style.style_order()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        gaps.sort();
+        // the registered default sits in the base order so the prop always wins
+        assert_eq!(
+            gaps,
+            vec![("16px".to_string(), None), ("8px".to_string(), Some(0))]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn extract_recipe() {
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r"import {recipe} from '@devup-ui/react'
+const button = recipe({
+  base: { px: 2, color: 'white' },
+  variants: {
+    size: { sm: { h: 8 }, lg: { h: 12, fontSize: 18 } },
+    tone: { primary: { bg: '$primary' }, danger: { bg: 'red' } },
+    disabled: { true: { opacity: 0.5 } },
+  },
+  compoundVariants: [{ size: 'lg', tone: 'danger', css: { fontWeight: 'bold' } }],
+  defaultVariants: { size: 'sm', tone: 'primary' },
+});
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                }
+            )
+            .unwrap()
+        ));
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.tsx",
+                r"import {recipe} from '@devup-ui/react'
+const empty = recipe();
+const baseOnly = recipe({ base: { _hover: { color: 'red' } } });
+const quoted = recipe({ variants: { 'data-size': { 1: { w: 1 } } }, defaultVariants: { 'data-size': 1 } });
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                }
+            )
+            .unwrap()
+        ));
+
+        reset_class_map();
+        reset_file_map();
+        assert_debug_snapshot!(ToBTreeSet::from(
+            extract(
+                "test.ts",
+                r"import {recipe} from '@devup-ui/react'
+const r = recipe({ base: { m: 1 }, styleOrder: 10 });
+const s = recipe({ base: { m: 1, styleOrder: 10 }, ...rest });
+",
+                ExtractOption {
+                    package: "@devup-ui/react".to_string(),
+                    css_dir: "@devup-ui/react".to_string(),
+                    single_css: true,
+                    import_main_css: false,
+                    import_aliases: HashMap::new(),
+                    strict: false,
+                    components: HashMap::new(),
+                }
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn extract_recipe_diagnostics() {
+        reset_class_map();
+        reset_file_map();
+        let output = extract(
+            "test.tsx",
+            r"import {recipe} from '@devup-ui/react'
+const r = recipe({ base: { m: 1, color: tone }, variants: { size: { sm: { w: width } } }, styleOrder: 10, slots: {} });
+",
+            ExtractOption {
+                package: "@devup-ui/react".to_string(),
+                css_dir: "@devup-ui/react".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: HashMap::new(),
+                strict: true,
+                components: HashMap::new(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            output
+                .diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.code, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    DiagnosticCode::UnsupportedValue,
+                    "`color` is not static (`tone`), dropped from recipe()"
+                ),
+                (
+                    DiagnosticCode::UnsupportedValue,
+                    "`width` is not static (`width`), dropped from recipe()"
+                ),
+                (
+                    DiagnosticCode::UnsupportedValue,
+                    "`styleOrder` of recipe() was ignored, set it in `base`, a variant or a compound variant `css` instead"
+                ),
+                (
+                    DiagnosticCode::UnsupportedValue,
+                    "`slots` is not a recipe() option and was ignored"
+                ),
+            ]
+        );
+        assert!(!output.code.contains("--"));
+        assert!(
+            output
+                .styles
+                .iter()
+                .all(|style| !matches!(style, ExtractStyleValue::Dynamic(_)))
+        );
+    }
 }
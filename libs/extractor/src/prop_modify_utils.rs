@@ -1,8 +1,10 @@
-use crate::extract_style::ExtractStyleProperty;
+use crate::diagnostic::{DiagnosticCode, warn};
 use crate::extract_style::style_property::StyleProperty;
 use crate::gen_class_name::gen_class_names;
 use crate::gen_style::gen_styles;
-use crate::tailwind::{has_tailwind_classes, parse_single_class, parse_tailwind_to_styles};
+use crate::tailwind::{
+    has_tailwind_classes, merge_tailwind_to_styles, parse_single_class, parse_tailwind_to_styles,
+};
 use crate::utils::{get_string_by_property_key, merge_object_expressions};
 use crate::{ExtractStyleProp, ExtractStyleValue};
 use oxc_allocator::CloneIn;
@@ -196,8 +198,11 @@ pub fn modify_props<'a>(
                             .as_expression()
                             .map(|expression| expression.clone_in(ast_builder.allocator));
                     } else if let JSXAttributeValue::StringLiteral(literal) = &value {
-                        res =
-                            Some(ast_builder.expression_string_literal(SPAN, literal.value, None));
+                        res = Some(ast_builder.expression_string_literal(
+                            literal.span,
+                            literal.value,
+                            None,
+                        ));
                     }
                     let name = ident.name.as_str();
                     if name == "className" {
@@ -363,8 +368,20 @@ fn extract_tailwind_from_class_name<'a>(
     if let Some(Expression::StringLiteral(literal)) = class_name_prop {
         let class_str = literal.value.as_str();
         if has_tailwind_classes(class_str) {
-            let mut tailwind_styles = parse_tailwind_to_styles(class_str, filename);
+            // Conflicting classes are resolved here, the last one winning
+            let mut tailwind_styles = merge_tailwind_to_styles(class_str);
             if !tailwind_styles.is_empty() {
+                // The literal is replaced by generated class names, so unknown classes are lost
+                for class in class_str
+                    .split_whitespace()
+                    .filter(|class| parse_single_class(class).is_none())
+                {
+                    warn(
+                        DiagnosticCode::UnknownTailwindClass,
+                        format!("`{class}` is not a known Tailwind utility and was dropped"),
+                        literal.span,
+                    );
+                }
                 // Apply style_order to all extracted Tailwind styles
                 apply_style_order_to_styles(&mut tailwind_styles, style_order);
 
@@ -423,12 +440,12 @@ fn build_tailwind_class_mapping(
 
     for class in class_str.split_whitespace() {
         if let Some(parsed) = parse_single_class(class) {
-            let mut static_style = parsed.to_static_style();
+            let mut style = parsed.to_style_value();
             if let Some(order) = style_order {
-                static_style.style_order = Some(order);
+                style.set_style_order(order);
             }
             // Extract to get the generated class name
-            if let StyleProperty::ClassName(generated) = static_style.extract(filename) {
+            if let Some(StyleProperty::ClassName(generated)) = style.extract(filename) {
                 mapping.insert(class.to_string(), generated);
             }
         }
@@ -7,14 +7,23 @@
 // Using if-let chains would make the code harder to read and modify.
 #![allow(clippy::collapsible_if)]
 
-use css::style_selector::StyleSelector;
+use std::collections::HashSet;
+
+use css::breakpoints::ResponsiveKey;
+use css::style_selector::{AtRuleKind, StyleSelector};
+use css::tailwind_theme::{
+    TailwindScale, get_tailwind_screen, get_tailwind_screen_below, get_tailwind_value,
+    has_tailwind_value,
+};
+use css::theme_tokens::{is_color_token, is_typography_token};
 use phf::phf_map;
 
 use crate::extract_style::{
     extract_static_style::ExtractStaticStyle, extract_style_value::ExtractStyleValue,
 };
 
-/// Responsive breakpoint levels matching devup-ui convention
+/// Responsive breakpoint levels matching devup-ui convention, unless the
+/// registered Tailwind theme customizes the screens
 /// 0 = base (no prefix)
 /// 1 = sm (640px)
 /// 2 = md (768px)
@@ -31,8 +40,39 @@ static RESPONSIVE_PREFIX_MAP: phf::Map<&'static str, u8> = phf_map! {
     "2xl" => 5,
 };
 
+/// Container query sizes of `@sm:` and the like
+static CONTAINER_SIZES: phf::Map<&'static str, &'static str> = phf_map! {
+    "3xs" => "16rem",
+    "2xs" => "18rem",
+    "xs" => "20rem",
+    "sm" => "24rem",
+    "md" => "28rem",
+    "lg" => "32rem",
+    "xl" => "36rem",
+    "2xl" => "42rem",
+    "3xl" => "48rem",
+    "4xl" => "56rem",
+    "5xl" => "64rem",
+    "6xl" => "72rem",
+    "7xl" => "80rem",
+};
+
+/// `aria-*` variants of boolean ARIA attributes
+const ARIA_STATES: [&str; 9] = [
+    "busy", "checked", "disabled", "expanded", "hidden", "pressed", "readonly", "required",
+    "selected",
+];
+
+/// `[...]` to its content, underscores being spaces
+fn arbitrary(value: &str) -> Option<String> {
+    value
+        .strip_prefix('[')?
+        .strip_suffix(']')
+        .map(|value| value.replace('_', " "))
+}
+
 /// Variant prefixes that map to CSS pseudo-classes/selectors
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TailwindVariant {
     Hover,
     Focus,
@@ -95,11 +135,16 @@ pub enum TailwindVariant {
     ForcedColors,
     Rtl,
     Ltr,
+    /// Arbitrary, data, aria, `has-*`, group or peer selector, `&` being
+    /// the element
+    Selector(String),
+    /// Supports, container or arbitrary media query
+    At(AtRuleKind, String),
 }
 
 impl TailwindVariant {
     /// Convert variant to `StyleSelector`
-    pub fn to_selector(self) -> StyleSelector {
+    pub fn to_selector(&self) -> StyleSelector {
         match self {
             TailwindVariant::Hover => StyleSelector::Selector("&:hover".to_string()),
             TailwindVariant::Focus => StyleSelector::Selector("&:focus".to_string()),
@@ -199,12 +244,12 @@ impl TailwindVariant {
             },
             TailwindVariant::MotionReduce => StyleSelector::At {
                 kind: css::style_selector::AtRuleKind::Media,
-                query: "(prefers-reduced-motion: reduce)".to_string(),
+                query: "(prefers-reduced-motion:reduce)".to_string(),
                 selector: None,
             },
             TailwindVariant::MotionSafe => StyleSelector::At {
                 kind: css::style_selector::AtRuleKind::Media,
-                query: "(prefers-reduced-motion: no-preference)".to_string(),
+                query: "(prefers-reduced-motion:no-preference)".to_string(),
                 selector: None,
             },
             TailwindVariant::ContrastMore => StyleSelector::At {
@@ -224,9 +269,128 @@ impl TailwindVariant {
             },
             TailwindVariant::Rtl => StyleSelector::Selector("[dir=rtl] &".to_string()),
             TailwindVariant::Ltr => StyleSelector::Selector("[dir=ltr] &".to_string()),
+            TailwindVariant::Selector(selector) => StyleSelector::Selector(selector.clone()),
+            TailwindVariant::At(kind, query) => StyleSelector::At {
+                kind: *kind,
+                query: query.clone(),
+                selector: None,
+            },
         }
     }
 
+    /// Parse a variant prefix, including arbitrary (`[&>svg]`), `data-*`,
+    /// `aria-*`, `has-*`, `supports-*`, `min-[...]`, `max-[...]`, container
+    /// (`@md`) and `group-*` / `peer-*` of any other element variant
+    pub fn parse(prefix: &str) -> Option<Self> {
+        if let Some(variant) = Self::from_prefix(prefix) {
+            return Some(variant);
+        }
+        if let Some(selector) = arbitrary(prefix) {
+            return Some(if let Some(query) = selector.strip_prefix("@media") {
+                TailwindVariant::At(AtRuleKind::Media, query.trim().to_string())
+            } else if let Some(query) = selector.strip_prefix("@supports") {
+                TailwindVariant::At(AtRuleKind::Supports, query.trim().to_string())
+            } else if selector.contains('&') {
+                TailwindVariant::Selector(selector)
+            } else {
+                TailwindVariant::Selector(format!("&{selector}"))
+            });
+        }
+        if let Some(container) = prefix.strip_prefix('@') {
+            return Self::parse_container(container);
+        }
+        if let Some(rest) = prefix.strip_prefix("group-") {
+            return Self::parse(rest)?.relative_to(":is([role=group],[data-group])", " &");
+        }
+        if let Some(rest) = prefix.strip_prefix("peer-") {
+            return Self::parse(rest)?.relative_to(".peer", " ~ &");
+        }
+        if let Some(rest) = prefix.strip_prefix("data-") {
+            let attribute = arbitrary(rest).unwrap_or_else(|| rest.to_string());
+            return Some(TailwindVariant::Selector(format!("&[data-{attribute}]")));
+        }
+        if let Some(rest) = prefix.strip_prefix("aria-") {
+            let attribute = match arbitrary(rest) {
+                Some(attribute) => attribute,
+                None if ARIA_STATES.contains(&rest) => format!("{rest}=true"),
+                None => return None,
+            };
+            return Some(TailwindVariant::Selector(format!("&[aria-{attribute}]")));
+        }
+        if let Some(rest) = prefix.strip_prefix("has-") {
+            return Some(TailwindVariant::Selector(format!(
+                "&:has({})",
+                arbitrary(rest)?
+            )));
+        }
+        if let Some(rest) = prefix.strip_prefix("supports-") {
+            let query = match arbitrary(rest) {
+                Some(query) if query.starts_with('(') || query.starts_with("not ") => query,
+                Some(query) if query.contains(':') => format!("({query})"),
+                // `supports-[display]` and `supports-grid`
+                Some(property) => format!("({property}:var(--tw))"),
+                None => format!("({rest}:var(--tw))"),
+            };
+            return Some(TailwindVariant::At(AtRuleKind::Supports, query));
+        }
+        if let Some(rest) = prefix.strip_prefix("min-") {
+            let width = arbitrary(rest)?;
+            return Some(TailwindVariant::At(
+                AtRuleKind::Media,
+                format!("(min-width:{width})"),
+            ));
+        }
+        if let Some(rest) = prefix.strip_prefix("max-") {
+            let width = arbitrary(rest)?;
+            return Some(TailwindVariant::At(
+                AtRuleKind::Media,
+                format!("(max-width:{width})"),
+            ));
+        }
+        None
+    }
+
+    /// `@md`, `@max-md`, `@[500px]` or a named `@md/sidebar` container query
+    fn parse_container(container: &str) -> Option<Self> {
+        let (size, name) = match container.rsplit_once('/') {
+            Some((size, name)) if !name.contains(']') => (size, Some(name)),
+            _ => (container, None),
+        };
+        let (size, max) = size
+            .strip_prefix("max-")
+            .map_or((size, false), |size| (size, true));
+        let width =
+            arbitrary(size).or_else(|| CONTAINER_SIZES.get(size).map(ToString::to_string))?;
+        let query = if max {
+            format!("(not (min-width:{width}))")
+        } else {
+            format!("(min-width:{width})")
+        };
+        Some(TailwindVariant::At(
+            AtRuleKind::Container,
+            name.map_or_else(|| query.clone(), |name| format!("{name} {query}")),
+        ))
+    }
+
+    /// `group-*` and `peer-*`: the state of the element applied to `parent`,
+    /// `combinator` relating it to the element
+    fn relative_to(&self, parent: &str, combinator: &str) -> Option<Self> {
+        let StyleSelector::Selector(selector) = self.to_selector() else {
+            return None;
+        };
+        let state = selector
+            .strip_prefix('&')
+            .filter(|state| !state.contains('&'))?;
+        // pseudo-elements like `::before` and combinators like `>svg` are
+        // not states of the parent
+        if state.starts_with("::") || !state.starts_with([':', '[', '.']) {
+            return None;
+        }
+        Some(TailwindVariant::Selector(format!(
+            "{parent}{state}{combinator}"
+        )))
+    }
+
     /// Parse variant from string prefix
     pub fn from_prefix(prefix: &str) -> Option<Self> {
         match prefix {
@@ -301,6 +465,8 @@ impl TailwindVariant {
 pub struct TailwindClass {
     /// Responsive level (0=base, 1=sm, 2=md, 3=lg, 4=xl, 5=2xl)
     pub responsive: u8,
+    /// At-rule of a custom screen outside the responsive levels
+    pub screen: Option<(AtRuleKind, String)>,
     /// Variants/modifiers applied
     pub variants: Vec<TailwindVariant>,
     /// CSS property name
@@ -328,38 +494,66 @@ impl TailwindClass {
             // Combine multiple variants into a single selector
             Some(self.combine_selectors())
         };
+        let selector = match self.screen.clone() {
+            None => selector,
+            Some((kind, query)) => Some(match selector {
+                None => StyleSelector::At {
+                    kind,
+                    query,
+                    selector: None,
+                },
+                Some(StyleSelector::Selector(selector)) => StyleSelector::At {
+                    kind,
+                    query,
+                    selector: Some(selector),
+                },
+                // e.g. `tablet:print:` becomes `print and (min-width:700px)`
+                Some(StyleSelector::At {
+                    kind: variant_kind,
+                    query: variant_query,
+                    selector,
+                }) if variant_kind == kind => StyleSelector::At {
+                    kind,
+                    query: join_queries(&variant_query, &query),
+                    selector,
+                },
+                Some(selector) => selector,
+            }),
+        };
 
         ExtractStaticStyle::new(&self.property, &value, self.responsive, selector)
     }
 
-    /// Combine multiple variant selectors
-    fn combine_selectors(&self) -> StyleSelector {
-        if self.variants.len() == 1 {
-            return self.variants[0].to_selector();
+    /// Convert to `ExtractStyleValue`, devup typography being referenced by name
+    pub fn to_style_value(&self) -> ExtractStyleValue {
+        if self.property == "typography" {
+            ExtractStyleValue::Typography(self.value.clone())
+        } else {
+            ExtractStyleValue::Static(self.to_static_style())
         }
+    }
 
-        // For multiple variants, combine them
-        // e.g., dark:hover: becomes :root[data-theme=dark] &:hover
-        let mut selector_str = String::new();
-        let mut has_at_rule = None;
+    /// Combine multiple variant selectors
+    ///
+    /// Each variant applies to the selector of the ones before it, e.g.
+    /// `dark:hover:` becomes `:root[data-theme=dark] &:hover`. At-rules of the
+    /// same kind are joined with `and`.
+    fn combine_selectors(&self) -> StyleSelector {
+        let mut selector = "&".to_string();
+        let mut at_rule: Option<(AtRuleKind, String)> = None;
 
         for variant in &self.variants {
-            let sel = variant.to_selector();
-            match sel {
-                StyleSelector::Selector(s) => {
-                    if selector_str.is_empty() {
-                        selector_str = s;
-                    } else {
-                        // Combine selectors
-                        selector_str =
-                            format!("{}{}", selector_str.replace(" &", ""), s.replace('&', ""));
-                        if !selector_str.contains(" &") && !selector_str.ends_with(" &") {
-                            selector_str.push_str(" &");
-                        }
-                    }
+            match variant.to_selector() {
+                StyleSelector::Selector(variant_selector) => {
+                    selector = variant_selector.replace('&', &selector);
                 }
                 StyleSelector::At { kind, query, .. } => {
-                    has_at_rule = Some((kind, query));
+                    at_rule = Some(match at_rule {
+                        Some((at_kind, at_query)) if at_kind == kind => {
+                            (kind, join_queries(&at_query, &query))
+                        }
+                        _ => (kind, query),
+                    });
                 }
                 // SAFETY: TailwindVariant::to_selector() never produces Global.
                 // This arm exists only for exhaustive matching. If reached, it indicates
@@ -370,24 +564,20 @@ impl TailwindClass {
             }
         }
 
-        if let Some((kind, query)) = has_at_rule {
-            StyleSelector::At {
+        let selector = (selector != "&").then_some(selector);
+        match at_rule {
+            Some((kind, query)) => StyleSelector::At {
                 kind,
                 query,
-                selector: if selector_str.is_empty() {
-                    None
-                } else {
-                    Some(selector_str)
-                },
-            }
-        } else {
-            StyleSelector::Selector(selector_str)
+                selector,
+            },
+            None => StyleSelector::Selector(selector.unwrap_or_default()),
         }
     }
 }
 
 /// Tailwind color values
-static TAILWIND_COLORS: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_TAILWIND_COLORS: phf::Map<&'static str, &'static str> = phf_map! {
     // Inherit/Current/Transparent
     "inherit" => "inherit",
     "current" => "currentColor",
@@ -662,7 +852,7 @@ static TAILWIND_COLORS: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Spacing scale (Tailwind default: 1 unit = 0.25rem = 4px)
-static SPACING_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_SPACING_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "0" => "0px",
     "px" => "1px",
     "0.5" => "0.125rem",
@@ -736,7 +926,7 @@ static SPACING_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Font size scale
-static FONT_SIZE_SCALE: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {
+static DEFAULT_FONT_SIZE_SCALE: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {
     "xs" => ("0.75rem", "1rem"),
     "sm" => ("0.875rem", "1.25rem"),
     "base" => ("1rem", "1.5rem"),
@@ -753,7 +943,7 @@ static FONT_SIZE_SCALE: phf::Map<&'static str, (&'static str, &'static str)> = p
 };
 
 /// Font weight scale
-static FONT_WEIGHT_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_FONT_WEIGHT_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "thin" => "100",
     "extralight" => "200",
     "light" => "300",
@@ -766,7 +956,7 @@ static FONT_WEIGHT_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Border radius scale
-static BORDER_RADIUS_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_BORDER_RADIUS_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "none" => "0px",
     "sm" => "0.125rem",
     "" => "0.25rem",
@@ -779,7 +969,7 @@ static BORDER_RADIUS_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Opacity scale
-static OPACITY_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_OPACITY_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "0" => "0",
     "5" => "0.05",
     "10" => "0.1",
@@ -804,7 +994,7 @@ static OPACITY_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Z-index scale
-static Z_INDEX_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_Z_INDEX_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "0" => "0",
     "10" => "10",
     "20" => "20",
@@ -815,7 +1005,7 @@ static Z_INDEX_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Box shadow scale
-static BOX_SHADOW_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_BOX_SHADOW_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "sm" => "0 1px 2px 0 rgb(0 0 0 / 0.05)",
     "" => "0 1px 3px 0 rgb(0 0 0 / 0.1), 0 1px 2px -1px rgb(0 0 0 / 0.1)",
     "md" => "0 4px 6px -1px rgb(0 0 0 / 0.1), 0 2px 4px -2px rgb(0 0 0 / 0.1)",
@@ -827,7 +1017,7 @@ static BOX_SHADOW_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Border width scale
-static BORDER_WIDTH_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_BORDER_WIDTH_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "0" => "0px",
     "" => "1px",
     "2" => "2px",
@@ -836,7 +1026,7 @@ static BORDER_WIDTH_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Transition duration scale
-static DURATION_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_DURATION_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "0" => "0s",
     "75" => "75ms",
     "100" => "100ms",
@@ -849,13 +1039,79 @@ static DURATION_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
 };
 
 /// Ease timing functions
-static EASE_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
+static DEFAULT_EASE_SCALE: phf::Map<&'static str, &'static str> = phf_map! {
     "linear" => "linear",
     "in" => "cubic-bezier(0.4, 0, 1, 1)",
     "out" => "cubic-bezier(0, 0, 0.2, 1)",
     "in-out" => "cubic-bezier(0.4, 0, 0.2, 1)",
 };
 
+/// A scale of the default Tailwind theme, extended or replaced by the
+/// registered Tailwind theme
+struct Scale {
+    kind: TailwindScale,
+    defaults: fn(&str) -> Option<&'static str>,
+}
+
+impl Scale {
+    /// Colors of the devup theme come first, as `$token` references
+    fn get(&self, key: &str) -> Option<String> {
+        if self.kind == TailwindScale::Colors && is_color_token(key) {
+            return Some(format!("${key}"));
+        }
+        get_tailwind_value(self.kind, key, (self.defaults)(key))
+    }
+}
+
+static TAILWIND_COLORS: Scale = Scale {
+    kind: TailwindScale::Colors,
+    defaults: |key| DEFAULT_TAILWIND_COLORS.get(key).copied(),
+};
+static SPACING_SCALE: Scale = Scale {
+    kind: TailwindScale::Spacing,
+    defaults: |key| DEFAULT_SPACING_SCALE.get(key).copied(),
+};
+static FONT_SIZE_SCALE: Scale = Scale {
+    kind: TailwindScale::FontSize,
+    defaults: |key| {
+        DEFAULT_FONT_SIZE_SCALE
+            .get(key)
+            .map(|(size, _line_height)| *size)
+    },
+};
+static FONT_WEIGHT_SCALE: Scale = Scale {
+    kind: TailwindScale::FontWeight,
+    defaults: |key| DEFAULT_FONT_WEIGHT_SCALE.get(key).copied(),
+};
+static BORDER_RADIUS_SCALE: Scale = Scale {
+    kind: TailwindScale::BorderRadius,
+    defaults: |key| DEFAULT_BORDER_RADIUS_SCALE.get(key).copied(),
+};
+static OPACITY_SCALE: Scale = Scale {
+    kind: TailwindScale::Opacity,
+    defaults: |key| DEFAULT_OPACITY_SCALE.get(key).copied(),
+};
+static Z_INDEX_SCALE: Scale = Scale {
+    kind: TailwindScale::ZIndex,
+    defaults: |key| DEFAULT_Z_INDEX_SCALE.get(key).copied(),
+};
+static BOX_SHADOW_SCALE: Scale = Scale {
+    kind: TailwindScale::BoxShadow,
+    defaults: |key| DEFAULT_BOX_SHADOW_SCALE.get(key).copied(),
+};
+static BORDER_WIDTH_SCALE: Scale = Scale {
+    kind: TailwindScale::BorderWidth,
+    defaults: |key| DEFAULT_BORDER_WIDTH_SCALE.get(key).copied(),
+};
+static DURATION_SCALE: Scale = Scale {
+    kind: TailwindScale::Duration,
+    defaults: |key| DEFAULT_DURATION_SCALE.get(key).copied(),
+};
+static EASE_SCALE: Scale = Scale {
+    kind: TailwindScale::Ease,
+    defaults: |key| DEFAULT_EASE_SCALE.get(key).copied(),
+};
+
 /// Check if a string contains Tailwind classes
 pub fn has_tailwind_classes(class_str: &str) -> bool {
     // Simple heuristic: if it looks like a Tailwind class pattern
@@ -870,11 +1126,11 @@ pub fn has_tailwind_classes(class_str: &str) -> bool {
 /// Check if a single class looks like a Tailwind utility
 fn is_likely_tailwind_class(class: &str) -> bool {
     // Strip any responsive/variant prefixes
-    let class = class
-        .split(':')
-        .next_back()
-        .unwrap_or(class)
-        .trim_start_matches('-');
+    let mut class = class;
+    while let Some((_, rest)) = split_prefix(class) {
+        class = rest;
+    }
+    let class = class.trim_start_matches('-');
 
     // Common Tailwind prefixes
     let prefixes = [
@@ -1221,6 +1477,12 @@ fn is_valid_tailwind_value(value: &str) -> bool {
         return true;
     }
 
+    // Keys of the registered Tailwind theme (e.g., brand, brand-500) and
+    // tokens of the devup theme (e.g., primary, heading)
+    if has_tailwind_value(value) || is_color_token(value) || is_typography_token(value) {
+        return true;
+    }
+
     // Fraction values (1/2, 1/3, 2/3, etc.)
     if let Some((numerator, denominator)) = value.split_once('/') {
         if !denominator.contains('/')
@@ -1241,8 +1503,7 @@ pub fn parse_tailwind_to_styles(class_str: &str, filename: Option<&str>) -> Vec<
 
     for class in classes {
         if let Some(parsed) = parse_single_class(class) {
-            let static_style = parsed.to_static_style();
-            styles.push(ExtractStyleValue::Static(static_style));
+            styles.push(parsed.to_style_value());
         }
     }
 
@@ -1254,10 +1515,144 @@ pub fn parse_tailwind_to_styles(class_str: &str, filename: Option<&str>) -> Vec<
     styles
 }
 
+/// Properties a utility of `property` overrides besides its own, e.g. `p-4`
+/// overrides an earlier `px-2`
+fn longhands(property: &str) -> &'static [&'static str] {
+    match property {
+        "padding" => &[
+            "padding-inline",
+            "padding-block",
+            "padding-inline-start",
+            "padding-inline-end",
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        "padding-inline" => &[
+            "padding-inline-start",
+            "padding-inline-end",
+            "padding-right",
+            "padding-left",
+        ],
+        "padding-block" => &["padding-top", "padding-bottom"],
+        "margin" => &[
+            "margin-inline",
+            "margin-block",
+            "margin-inline-start",
+            "margin-inline-end",
+            "margin-top",
+            "margin-right",
+            "margin-bottom",
+            "margin-left",
+        ],
+        "margin-inline" => &[
+            "margin-inline-start",
+            "margin-inline-end",
+            "margin-right",
+            "margin-left",
+        ],
+        "margin-block" => &["margin-top", "margin-bottom"],
+        "inset" => &[
+            "inset-inline",
+            "inset-block",
+            "top",
+            "right",
+            "bottom",
+            "left",
+        ],
+        "inset-inline" => &["right", "left"],
+        "inset-block" => &["top", "bottom"],
+        "gap" => &["column-gap", "row-gap"],
+        "border-radius" => &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+        "border-width" => &[
+            "border-inline-width",
+            "border-block-width",
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        "border-inline-width" => &["border-right-width", "border-left-width"],
+        "border-block-width" => &["border-top-width", "border-bottom-width"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "overscroll-behavior" => &["overscroll-behavior-x", "overscroll-behavior-y"],
+        "flex" => &["flex-grow", "flex-shrink", "flex-basis"],
+        "grid-column" => &["grid-column-start", "grid-column-end"],
+        "grid-row" => &["grid-row-start", "grid-row-end"],
+        _ => &[],
+    }
+}
+
+/// Parse a className string like [`parse_tailwind_to_styles`], dropping the
+/// classes overridden by a later one (tailwind-merge semantics)
+///
+/// A class is overridden by a later class with the same responsive level and
+/// selector setting its property, or a shorthand of it: `p-2 p-4` and
+/// `px-2 p-4` keep `p-4` only, while `p-4 px-2` keeps both.
+pub fn merge_tailwind_to_styles(class_str: &str) -> Vec<ExtractStyleValue> {
+    let classes = class_str
+        .split_whitespace()
+        .filter_map(parse_single_class)
+        .collect::<Vec<_>>();
+    let mut overridden = HashSet::new();
+    let mut styles = classes
+        .iter()
+        .rev()
+        .filter_map(|class| {
+            let style = class.to_style_value();
+            let (level, selector) = match &style {
+                ExtractStyleValue::Static(style) => (style.level, style.selector.clone()),
+                _ => (0, None),
+            };
+            if overridden.contains(&(level, selector.clone(), class.property.as_str())) {
+                return None;
+            }
+            for property in longhands(&class.property) {
+                overridden.insert((level, selector.clone(), *property));
+            }
+            overridden.insert((level, selector, class.property.as_str()));
+            Some(style)
+        })
+        .collect::<Vec<_>>();
+    styles.reverse();
+    styles
+}
+
+/// Both conditions of one at-rule kind, a media type like `print` first
+pub fn join_queries(first: &str, second: &str) -> String {
+    if first.starts_with('(') && !second.starts_with('(') {
+        format!("{second} and {first}")
+    } else {
+        format!("{first} and {second}")
+    }
+}
+
+/// `md:hover:flex` -> `("md", "hover:flex")`, colons of arbitrary variants
+/// like `[&:hover]:` and values like `bg-[url(a:b)]` being kept
+fn split_prefix(class: &str) -> Option<(&str, &str)> {
+    let mut depth = 0_usize;
+    for (index, c) in class.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => return Some((&class[..index], &class[index + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
 /// Parse a single Tailwind class string
 pub fn parse_single_class(class: &str) -> Option<TailwindClass> {
     let mut remaining = class;
     let mut responsive_level: u8 = 0;
+    let mut screen: Option<(AtRuleKind, String)> = None;
     let mut variants: Vec<TailwindVariant> = Vec::new();
 
     // Handle negative prefix at the start
@@ -1267,30 +1662,50 @@ pub fn parse_single_class(class: &str) -> Option<TailwindClass> {
     }
 
     // Parse prefixes (responsive and variants)
-    while let Some(colon_pos) = remaining.find(':') {
-        let prefix = &remaining[..colon_pos];
-
+    while let Some((prefix, rest)) = split_prefix(remaining) {
         // Check if it's a responsive prefix
-        if let Some(&level) = RESPONSIVE_PREFIX_MAP.get(prefix) {
-            responsive_level = level;
-        } else if let Some(variant) = TailwindVariant::from_prefix(prefix) {
-            variants.push(variant);
-        } else {
-            // Unknown prefix, might be an arbitrary variant
-            // For now, skip unknown prefixes
+        let screen_below = prefix.strip_prefix("max-").and_then(|name| {
+            get_tailwind_screen_below(get_tailwind_screen(
+                name,
+                RESPONSIVE_PREFIX_MAP.get(name).copied(),
+            )?)
+        });
+        let key = match screen_below {
+            Some((kind, query)) => Some(ResponsiveKey::At(kind, query)),
+            None => get_tailwind_screen(prefix, RESPONSIVE_PREFIX_MAP.get(prefix).copied()),
+        };
+        match key {
+            Some(ResponsiveKey::Level(level)) => responsive_level = level,
+            // e.g. `max-md:wide:` applies between two screens
+            Some(ResponsiveKey::At(kind, query)) => {
+                screen = Some(match screen {
+                    Some((screen_kind, screen_query)) if screen_kind == kind => {
+                        (kind, join_queries(&screen_query, &query))
+                    }
+                    _ => (kind, query),
+                });
+            }
+            None => variants.push(TailwindVariant::parse(prefix)?),
         }
 
-        remaining = &remaining[colon_pos + 1..];
+        remaining = rest;
     }
 
     // Now parse the utility class
-    parse_utility(remaining, negative).map(|(property, value)| TailwindClass {
-        responsive: responsive_level,
-        variants,
-        property,
-        value,
-        negative,
-    })
+    parse_utility(remaining, negative)
+        // Typography classes apply on every screen and state
+        .filter(|(property, _)| {
+            property != "typography"
+                || (responsive_level == 0 && screen.is_none() && variants.is_empty() && !negative)
+        })
+        .map(|(property, value)| TailwindClass {
+            responsive: responsive_level,
+            screen,
+            variants,
+            property,
+            value,
+            negative,
+        })
 }
 
 /// Parse a utility class (without prefixes) into property and value
@@ -1658,45 +2073,45 @@ fn parse_layout_utility(class: &str) -> Option<(String, String)> {
 
             // Z-index
             if let Some(rest) = class.strip_prefix("z-") {
-                if let Some(&value) = Z_INDEX_SCALE.get(rest) {
-                    return Some(("z-index".to_string(), value.to_string()));
+                if let Some(value) = Z_INDEX_SCALE.get(rest) {
+                    return Some(("z-index".to_string(), value));
                 }
             }
 
             // Top/Right/Bottom/Left/Inset
             if let Some(rest) = class.strip_prefix("top-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("top".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("top".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("right-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("right".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("right".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("bottom-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("bottom".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("bottom".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("left-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("left".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("left".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("inset-x-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("inset-inline".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("inset-inline".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("inset-y-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("inset-block".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("inset-block".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("inset-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("inset".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("inset".to_string(), value));
                 }
             }
 
@@ -1858,8 +2273,8 @@ fn parse_flex_grid_utility(class: &str) -> Option<(String, String)> {
         _ => {
             // Flex basis with spacing scale
             if let Some(rest) = class.strip_prefix("basis-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("flex-basis".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("flex-basis".to_string(), value));
                 }
             }
 
@@ -1924,18 +2339,18 @@ fn parse_flex_grid_utility(class: &str) -> Option<(String, String)> {
 
             // Gap
             if let Some(rest) = class.strip_prefix("gap-x-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("column-gap".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("column-gap".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("gap-y-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("row-gap".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("row-gap".to_string(), value));
                 }
             }
             if let Some(rest) = class.strip_prefix("gap-") {
-                if let Some(&value) = SPACING_SCALE.get(rest) {
-                    return Some(("gap".to_string(), value.to_string()));
+                if let Some(value) = SPACING_SCALE.get(rest) {
+                    return Some(("gap".to_string(), value));
                 }
             }
 
@@ -1951,95 +2366,95 @@ fn parse_spacing_utility(class: &str, _is_negative: bool) -> Option<(String, Str
 
     // Padding
     if let Some(rest) = class.strip_prefix("px-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-inline".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-inline".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("py-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-block".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-block".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("pt-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-top".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-top".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("pr-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-right".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-right".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("pb-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-bottom".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-bottom".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("pl-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-left".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-left".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("ps-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-inline-start".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-inline-start".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("pe-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding-inline-end".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding-inline-end".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("p-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("padding".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("padding".to_string(), value));
         }
     }
 
     // Margin
     if let Some(rest) = class.strip_prefix("mx-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-inline".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-inline".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("my-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-block".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-block".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("mt-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-top".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-top".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("mr-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-right".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-right".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("mb-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-bottom".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-bottom".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("ml-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-left".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-left".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("ms-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-inline-start".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-inline-start".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("me-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin-inline-end".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin-inline-end".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("m-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("margin".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("margin".to_string(), value));
         }
     }
 
@@ -2048,16 +2463,16 @@ fn parse_spacing_utility(class: &str, _is_negative: bool) -> Option<(String, Str
         if rest == "reverse" {
             return Some(("--tw-space-x-reverse".to_string(), "1".to_string()));
         }
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("column-gap".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("column-gap".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("space-y-") {
         if rest == "reverse" {
             return Some(("--tw-space-y-reverse".to_string(), "1".to_string()));
         }
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("row-gap".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("row-gap".to_string(), value));
         }
     }
 
@@ -2068,8 +2483,8 @@ fn parse_spacing_utility(class: &str, _is_negative: bool) -> Option<(String, Str
 fn parse_sizing_utility(class: &str) -> Option<(String, String)> {
     // Width
     if let Some(rest) = class.strip_prefix("w-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("width".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("width".to_string(), value));
         }
     }
 
@@ -2081,13 +2496,7 @@ fn parse_sizing_utility(class: &str) -> Option<(String, String)> {
             "min" => "min-content".to_string(),
             "max" => "max-content".to_string(),
             "fit" => "fit-content".to_string(),
-            _ => {
-                if let Some(&v) = SPACING_SCALE.get(rest) {
-                    v.to_string()
-                } else {
-                    return None;
-                }
-            }
+            _ => SPACING_SCALE.get(rest)?,
         };
         return Some(("min-width".to_string(), value));
     }
@@ -2118,13 +2527,7 @@ fn parse_sizing_utility(class: &str) -> Option<(String, String)> {
             "screen-lg" => "1024px".to_string(),
             "screen-xl" => "1280px".to_string(),
             "screen-2xl" => "1536px".to_string(),
-            _ => {
-                if let Some(&v) = SPACING_SCALE.get(rest) {
-                    v.to_string()
-                } else {
-                    return None;
-                }
-            }
+            _ => SPACING_SCALE.get(rest)?,
         };
         return Some(("max-width".to_string(), value));
     }
@@ -2136,13 +2539,7 @@ fn parse_sizing_utility(class: &str) -> Option<(String, String)> {
             "svh" => "100svh".to_string(),
             "lvh" => "100lvh".to_string(),
             "dvh" => "100dvh".to_string(),
-            _ => {
-                if let Some(&v) = SPACING_SCALE.get(rest) {
-                    v.to_string()
-                } else {
-                    return None;
-                }
-            }
+            _ => SPACING_SCALE.get(rest)?,
         };
         return Some(("height".to_string(), value));
     }
@@ -2159,13 +2556,7 @@ fn parse_sizing_utility(class: &str) -> Option<(String, String)> {
             "min" => "min-content".to_string(),
             "max" => "max-content".to_string(),
             "fit" => "fit-content".to_string(),
-            _ => {
-                if let Some(&v) = SPACING_SCALE.get(rest) {
-                    v.to_string()
-                } else {
-                    return None;
-                }
-            }
+            _ => SPACING_SCALE.get(rest)?,
         };
         return Some(("min-height".to_string(), value));
     }
@@ -2182,23 +2573,17 @@ fn parse_sizing_utility(class: &str) -> Option<(String, String)> {
             "min" => "min-content".to_string(),
             "max" => "max-content".to_string(),
             "fit" => "fit-content".to_string(),
-            _ => {
-                if let Some(&v) = SPACING_SCALE.get(rest) {
-                    v.to_string()
-                } else {
-                    return None;
-                }
-            }
+            _ => SPACING_SCALE.get(rest)?,
         };
         return Some(("max-height".to_string(), value));
     }
 
     // Size (width and height)
     if let Some(rest) = class.strip_prefix("size-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
+        if let Some(value) = SPACING_SCALE.get(rest) {
             // This should set both width and height
             // For simplicity, we'll use the width shorthand and handle height separately
-            return Some(("width".to_string(), value.to_string()));
+            return Some(("width".to_string(), value));
         }
     }
 
@@ -2217,14 +2602,18 @@ fn parse_typography_utility(class: &str) -> Option<(String, String)> {
 
     // Font size
     if let Some(rest) = class.strip_prefix("text-") {
-        // First check if it's a color
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("color".to_string(), color.to_string()));
+        // Typography of the devup theme
+        if is_typography_token(rest) {
+            return Some(("typography".to_string(), rest.to_string()));
+        }
+        // Then check if it's a color
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("color".to_string(), color));
         }
         // Then check if it's a font size
-        if let Some(&(size, _line_height)) = FONT_SIZE_SCALE.get(rest) {
+        if let Some(size) = FONT_SIZE_SCALE.get(rest) {
             // Return font-size (line-height would need separate handling)
-            return Some(("font-size".to_string(), size.to_string()));
+            return Some(("font-size".to_string(), size));
         }
         // Text alignment
         match rest {
@@ -2240,8 +2629,8 @@ fn parse_typography_utility(class: &str) -> Option<(String, String)> {
 
     // Font weight
     if let Some(rest) = class.strip_prefix("font-") {
-        if let Some(&weight) = FONT_WEIGHT_SCALE.get(rest) {
-            return Some(("font-weight".to_string(), weight.to_string()));
+        if let Some(weight) = FONT_WEIGHT_SCALE.get(rest) {
+            return Some(("font-weight".to_string(), weight));
         }
     }
 
@@ -2381,8 +2770,8 @@ fn parse_background_utility(class: &str) -> Option<(String, String)> {
     // Background color
     if let Some(rest) = class.strip_prefix("bg-") {
         // Check if it's a color
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("background-color".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("background-color".to_string(), color));
         }
         // Background attachment
         match rest {
@@ -2467,18 +2856,18 @@ fn parse_background_utility(class: &str) -> Option<(String, String)> {
 
     // Gradient color stops
     if let Some(rest) = class.strip_prefix("from-") {
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("--tw-gradient-from".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("--tw-gradient-from".to_string(), color));
         }
     }
     if let Some(rest) = class.strip_prefix("via-") {
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("--tw-gradient-via".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("--tw-gradient-via".to_string(), color));
         }
     }
     if let Some(rest) = class.strip_prefix("to-") {
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("--tw-gradient-to".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("--tw-gradient-to".to_string(), color));
         }
     }
 
@@ -2491,47 +2880,47 @@ fn parse_border_utility(class: &str) -> Option<(String, String)> {
     if let Some(rest) = class.strip_prefix("rounded-") {
         // Specific corners
         if let Some(corner) = rest.strip_prefix("t-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-top-left-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-top-left-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("r-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-top-right-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-top-right-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("b-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-bottom-right-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-bottom-right-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("l-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-bottom-left-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-bottom-left-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("tl-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-top-left-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-top-left-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("tr-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-top-right-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-top-right-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("br-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-bottom-right-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-bottom-right-radius".to_string(), value));
             }
         }
         if let Some(corner) = rest.strip_prefix("bl-") {
-            if let Some(&value) = BORDER_RADIUS_SCALE.get(corner) {
-                return Some(("border-bottom-left-radius".to_string(), value.to_string()));
+            if let Some(value) = BORDER_RADIUS_SCALE.get(corner) {
+                return Some(("border-bottom-left-radius".to_string(), value));
             }
         }
-        if let Some(&value) = BORDER_RADIUS_SCALE.get(rest) {
-            return Some(("border-radius".to_string(), value.to_string()));
+        if let Some(value) = BORDER_RADIUS_SCALE.get(rest) {
+            return Some(("border-radius".to_string(), value));
         }
     }
 
@@ -2544,45 +2933,45 @@ fn parse_border_utility(class: &str) -> Option<(String, String)> {
     // Border width
     if let Some(rest) = class.strip_prefix("border-") {
         // Border color
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("border-color".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("border-color".to_string(), color));
         }
 
         // Border width per side
         if let Some(width) = rest.strip_prefix("t-") {
-            if let Some(&value) = BORDER_WIDTH_SCALE.get(width) {
-                return Some(("border-top-width".to_string(), value.to_string()));
+            if let Some(value) = BORDER_WIDTH_SCALE.get(width) {
+                return Some(("border-top-width".to_string(), value));
             }
         }
         if let Some(width) = rest.strip_prefix("r-") {
-            if let Some(&value) = BORDER_WIDTH_SCALE.get(width) {
-                return Some(("border-right-width".to_string(), value.to_string()));
+            if let Some(value) = BORDER_WIDTH_SCALE.get(width) {
+                return Some(("border-right-width".to_string(), value));
             }
         }
         if let Some(width) = rest.strip_prefix("b-") {
-            if let Some(&value) = BORDER_WIDTH_SCALE.get(width) {
-                return Some(("border-bottom-width".to_string(), value.to_string()));
+            if let Some(value) = BORDER_WIDTH_SCALE.get(width) {
+                return Some(("border-bottom-width".to_string(), value));
             }
         }
         if let Some(width) = rest.strip_prefix("l-") {
-            if let Some(&value) = BORDER_WIDTH_SCALE.get(width) {
-                return Some(("border-left-width".to_string(), value.to_string()));
+            if let Some(value) = BORDER_WIDTH_SCALE.get(width) {
+                return Some(("border-left-width".to_string(), value));
             }
         }
         if let Some(width) = rest.strip_prefix("x-") {
-            if let Some(&value) = BORDER_WIDTH_SCALE.get(width) {
-                return Some(("border-inline-width".to_string(), value.to_string()));
+            if let Some(value) = BORDER_WIDTH_SCALE.get(width) {
+                return Some(("border-inline-width".to_string(), value));
             }
         }
         if let Some(width) = rest.strip_prefix("y-") {
-            if let Some(&value) = BORDER_WIDTH_SCALE.get(width) {
-                return Some(("border-block-width".to_string(), value.to_string()));
+            if let Some(value) = BORDER_WIDTH_SCALE.get(width) {
+                return Some(("border-block-width".to_string(), value));
             }
         }
 
         // Border width
-        if let Some(&value) = BORDER_WIDTH_SCALE.get(rest) {
-            return Some(("border-width".to_string(), value.to_string()));
+        if let Some(value) = BORDER_WIDTH_SCALE.get(rest) {
+            return Some(("border-width".to_string(), value));
         }
 
         // Border style
@@ -2626,8 +3015,8 @@ fn parse_border_utility(class: &str) -> Option<(String, String)> {
             "dotted" => return Some(("outline-style".to_string(), "dotted".to_string())),
             "double" => return Some(("outline-style".to_string(), "double".to_string())),
             _ => {
-                if let Some(&color) = TAILWIND_COLORS.get(rest) {
-                    return Some(("outline-color".to_string(), color.to_string()));
+                if let Some(color) = TAILWIND_COLORS.get(rest) {
+                    return Some(("outline-color".to_string(), color));
                 }
             }
         }
@@ -2671,8 +3060,8 @@ fn parse_border_utility(class: &str) -> Option<(String, String)> {
             }
             "inset" => return Some(("--tw-ring-inset".to_string(), "inset".to_string())),
             _ => {
-                if let Some(&color) = TAILWIND_COLORS.get(rest) {
-                    return Some(("--tw-ring-color".to_string(), color.to_string()));
+                if let Some(color) = TAILWIND_COLORS.get(rest) {
+                    return Some(("--tw-ring-color".to_string(), color));
                 }
             }
         }
@@ -2686,8 +3075,8 @@ fn parse_border_utility(class: &str) -> Option<(String, String)> {
 
     // Divide
     if let Some(rest) = class.strip_prefix("divide-") {
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("--tw-divide-color".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("--tw-divide-color".to_string(), color));
         }
         match rest {
             "x" => return Some(("--tw-divide-x-reverse".to_string(), "0".to_string())),
@@ -2718,12 +3107,12 @@ fn parse_border_utility(class: &str) -> Option<(String, String)> {
 fn parse_effects_utility(class: &str) -> Option<(String, String)> {
     // Box shadow
     if let Some(rest) = class.strip_prefix("shadow-") {
-        if let Some(&value) = BOX_SHADOW_SCALE.get(rest) {
-            return Some(("box-shadow".to_string(), value.to_string()));
+        if let Some(value) = BOX_SHADOW_SCALE.get(rest) {
+            return Some(("box-shadow".to_string(), value));
         }
         // Shadow color
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("--tw-shadow-color".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("--tw-shadow-color".to_string(), color));
         }
     }
     if class == "shadow" {
@@ -2735,8 +3124,8 @@ fn parse_effects_utility(class: &str) -> Option<(String, String)> {
 
     // Opacity
     if let Some(rest) = class.strip_prefix("opacity-") {
-        if let Some(&value) = OPACITY_SCALE.get(rest) {
-            return Some(("opacity".to_string(), value.to_string()));
+        if let Some(value) = OPACITY_SCALE.get(rest) {
+            return Some(("opacity".to_string(), value));
         }
     }
 
@@ -2947,7 +3336,7 @@ fn parse_filter_utility(class: &str) -> Option<(String, String)> {
     }
 
     if let Some(rest) = class.strip_prefix("backdrop-opacity-") {
-        if let Some(&value) = OPACITY_SCALE.get(rest) {
+        if let Some(value) = OPACITY_SCALE.get(rest) {
             return Some(("backdrop-filter".to_string(), format!("opacity({value})")));
         }
     }
@@ -2990,22 +3379,22 @@ fn parse_transition_utility(class: &str) -> Option<(String, String)> {
 
     // Duration
     if let Some(rest) = class.strip_prefix("duration-") {
-        if let Some(&value) = DURATION_SCALE.get(rest) {
-            return Some(("transition-duration".to_string(), value.to_string()));
+        if let Some(value) = DURATION_SCALE.get(rest) {
+            return Some(("transition-duration".to_string(), value));
         }
     }
 
     // Ease (timing function)
     if let Some(rest) = class.strip_prefix("ease-") {
-        if let Some(&value) = EASE_SCALE.get(rest) {
-            return Some(("transition-timing-function".to_string(), value.to_string()));
+        if let Some(value) = EASE_SCALE.get(rest) {
+            return Some(("transition-timing-function".to_string(), value));
         }
     }
 
     // Delay
     if let Some(rest) = class.strip_prefix("delay-") {
-        if let Some(&value) = DURATION_SCALE.get(rest) {
-            return Some(("transition-delay".to_string(), value.to_string()));
+        if let Some(value) = DURATION_SCALE.get(rest) {
+            return Some(("transition-delay".to_string(), value));
         }
     }
 
@@ -3078,7 +3467,7 @@ fn parse_transform_utility(class: &str, is_negative: bool) -> Option<(String, St
 
     // Translate
     if let Some(rest) = class.strip_prefix("translate-x-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
+        if let Some(value) = SPACING_SCALE.get(rest) {
             let neg_prefix = if is_negative { "-" } else { "" };
             return Some((
                 "transform".to_string(),
@@ -3087,7 +3476,7 @@ fn parse_transform_utility(class: &str, is_negative: bool) -> Option<(String, St
         }
     }
     if let Some(rest) = class.strip_prefix("translate-y-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
+        if let Some(value) = SPACING_SCALE.get(rest) {
             let neg_prefix = if is_negative { "-" } else { "" };
             return Some((
                 "transform".to_string(),
@@ -3163,8 +3552,8 @@ fn parse_interactivity_utility(class: &str) -> Option<(String, String)> {
         if rest == "auto" {
             return Some(("accent-color".to_string(), "auto".to_string()));
         }
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("accent-color".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("accent-color".to_string(), color));
         }
     }
 
@@ -3182,8 +3571,8 @@ fn parse_interactivity_utility(class: &str) -> Option<(String, String)> {
 
     // Caret color
     if let Some(rest) = class.strip_prefix("caret-") {
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("caret-color".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("caret-color".to_string(), color));
         }
     }
 
@@ -3212,13 +3601,13 @@ fn parse_interactivity_utility(class: &str) -> Option<(String, String)> {
 
     // Scroll margin/padding
     if let Some(rest) = class.strip_prefix("scroll-m-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("scroll-margin".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("scroll-margin".to_string(), value));
         }
     }
     if let Some(rest) = class.strip_prefix("scroll-p-") {
-        if let Some(&value) = SPACING_SCALE.get(rest) {
-            return Some(("scroll-padding".to_string(), value.to_string()));
+        if let Some(value) = SPACING_SCALE.get(rest) {
+            return Some(("scroll-padding".to_string(), value));
         }
     }
 
@@ -3298,8 +3687,8 @@ fn parse_svg_utility(class: &str) -> Option<(String, String)> {
         if rest == "none" {
             return Some(("fill".to_string(), "none".to_string()));
         }
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("fill".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("fill".to_string(), color));
         }
     }
 
@@ -3316,8 +3705,8 @@ fn parse_svg_utility(class: &str) -> Option<(String, String)> {
             _ => {}
         }
         // Stroke color
-        if let Some(&color) = TAILWIND_COLORS.get(rest) {
-            return Some(("stroke".to_string(), color.to_string()));
+        if let Some(color) = TAILWIND_COLORS.get(rest) {
+            return Some(("stroke".to_string(), color));
         }
     }
 
@@ -3342,11 +3731,14 @@ fn parse_accessibility_utility(class: &str) -> Option<(String, String)> {
 mod tests {
     use super::*;
     use css::class_map::reset_class_map;
+    use css::context::CssContext;
     use css::file_map::reset_file_map;
+    use css::theme_tokens::set_theme_token_names;
     use insta::assert_debug_snapshot;
     use rstest::rstest;
     use serial_test::serial;
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeSet, HashSet};
+    use std::sync::Arc;
 
     // Helper to sort styles for consistent snapshots
     fn sort_styles(styles: Vec<ExtractStyleValue>) -> BTreeSet<ExtractStyleValue> {
@@ -3997,8 +4389,8 @@ mod tests {
     #[case(TailwindVariant::Screen, "screen")]
     #[case(TailwindVariant::Portrait, "(orientation: portrait)")]
     #[case(TailwindVariant::Landscape, "(orientation: landscape)")]
-    #[case(TailwindVariant::MotionReduce, "(prefers-reduced-motion: reduce)")]
-    #[case(TailwindVariant::MotionSafe, "(prefers-reduced-motion: no-preference)")]
+    #[case(TailwindVariant::MotionReduce, "(prefers-reduced-motion:reduce)")]
+    #[case(TailwindVariant::MotionSafe, "(prefers-reduced-motion:no-preference)")]
     #[case(TailwindVariant::ContrastMore, "(prefers-contrast: more)")]
     #[case(TailwindVariant::ContrastLess, "(prefers-contrast: less)")]
     #[case(TailwindVariant::ForcedColors, "(forced-colors: active)")]
@@ -5973,4 +6365,256 @@ mod tests {
     ) {
         assert_eq!(is_valid_tailwind_value(value), expected);
     }
+
+    #[test]
+    fn test_custom_tailwind_theme() {
+        use css::context::CssContext;
+        use css::tailwind_theme::{TailwindTheme, TailwindValues, set_tailwind_theme};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let values = |entries: &[(&str, &str)], replace: bool| TailwindValues {
+            values: entries
+                .iter()
+                .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                .collect(),
+            replace,
+        };
+        Arc::new(CssContext::default()).enter(|| {
+            set_tailwind_theme(TailwindTheme {
+                scales: HashMap::from([
+                    (
+                        TailwindScale::Colors,
+                        values(&[("brand", "#123456"), ("brand-500", "#654321")], false),
+                    ),
+                    (TailwindScale::Spacing, values(&[("1", "2px")], true)),
+                    (TailwindScale::FontSize, values(&[("huge", "5rem")], false)),
+                ]),
+                screens: TailwindValues {
+                    values: HashMap::from([
+                        ("tablet".to_string(), ResponsiveKey::Level(2)),
+                        (
+                            "wide".to_string(),
+                            ResponsiveKey::At(AtRuleKind::Media, "(min-width:1400px)".to_string()),
+                        ),
+                    ]),
+                    replace: true,
+                },
+                ..Default::default()
+            });
+
+            assert!(has_tailwind_classes("bg-brand"));
+            assert_eq!(
+                parse_single_class("bg-brand-500").map(|class| class.value),
+                Some("#654321".to_string())
+            );
+            assert_eq!(
+                parse_single_class("text-red-500").map(|class| class.value),
+                Some("#ef4444".to_string())
+            );
+            assert_eq!(
+                parse_single_class("text-huge").map(|class| class.value),
+                Some("5rem".to_string())
+            );
+            assert_eq!(
+                parse_single_class("p-1").map(|class| class.value),
+                Some("2px".to_string())
+            );
+            assert_eq!(parse_single_class("p-2"), None);
+
+            let parsed = parse_single_class("tablet:flex").unwrap();
+            assert_eq!((parsed.responsive, parsed.screen), (2, None));
+            // default screens are replaced
+            assert_eq!(parse_single_class("md:flex"), None);
+
+            assert_debug_snapshot!(
+                ["wide:flex", "wide:hover:flex", "wide:print:flex"]
+                    .map(|class| parse_single_class(class).unwrap().to_static_style())
+            );
+        });
+    }
+
+    #[test]
+    fn test_devup_theme_tokens() {
+        Arc::new(CssContext::default()).enter(|| {
+            set_theme_token_names(
+                HashSet::from(["primary".to_string(), "gray-100".to_string()]),
+                HashSet::from(["heading".to_string()]),
+            );
+
+            assert!(has_tailwind_classes("bg-primary"));
+            assert!(has_tailwind_classes("text-heading"));
+            assert_eq!(
+                parse_single_class("bg-primary").map(|class| class.value),
+                Some("$primary".to_string())
+            );
+            assert_eq!(
+                parse_single_class("hover:border-gray-100").map(|class| class.value),
+                Some("$gray-100".to_string())
+            );
+            assert_eq!(
+                parse_single_class("bg-red-500").map(|class| class.value),
+                Some("#ef4444".to_string())
+            );
+            assert_eq!(
+                parse_single_class("text-heading").map(|class| class.to_style_value()),
+                Some(ExtractStyleValue::Typography("heading".to_string()))
+            );
+            assert_eq!(parse_single_class("md:text-heading"), None);
+            assert_eq!(parse_single_class("hover:text-heading"), None);
+            assert_debug_snapshot!(sort_styles(parse_tailwind_to_styles(
+                "text-primary text-heading text-lg",
+                None
+            )));
+        });
+    }
+
+    #[rstest]
+    #[case("[&>svg]", "&>svg")]
+    #[case("[.dark_&]", ".dark &")]
+    #[case("[:hover]", "&:hover")]
+    #[case("data-[state=open]", "&[data-state=open]")]
+    #[case("data-active", "&[data-active]")]
+    #[case("aria-expanded", "&[aria-expanded=true]")]
+    #[case("aria-[sort=ascending]", "&[aria-sort=ascending]")]
+    #[case("has-[input:checked]", "&:has(input:checked)")]
+    #[case("group-hover", ":is([role=group],[data-group]):hover &")]
+    #[case(
+        "group-data-[state=open]",
+        ":is([role=group],[data-group])[data-state=open] &"
+    )]
+    #[case("peer-checked", ".peer:checked ~ &")]
+    #[case("peer-aria-checked", ".peer[aria-checked=true] ~ &")]
+    #[case("group-[.active]", ":is([role=group],[data-group]).active &")]
+    fn test_parse_selector_variants(#[case] prefix: &str, #[case] expected: &str) {
+        assert_eq!(
+            TailwindVariant::parse(prefix).map(|variant| variant.to_selector()),
+            Some(StyleSelector::Selector(expected.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("supports-[display:grid]", AtRuleKind::Supports, "(display:grid)")]
+    #[case("supports-grid", AtRuleKind::Supports, "(grid:var(--tw))")]
+    #[case("supports-[not_(gap:1px)]", AtRuleKind::Supports, "not (gap:1px)")]
+    #[case("[@supports(display:grid)]", AtRuleKind::Supports, "(display:grid)")]
+    #[case("[@media(hover:hover)]", AtRuleKind::Media, "(hover:hover)")]
+    #[case("min-[900px]", AtRuleKind::Media, "(min-width:900px)")]
+    #[case("max-[600px]", AtRuleKind::Media, "(max-width:600px)")]
+    #[case("@md", AtRuleKind::Container, "(min-width:28rem)")]
+    #[case("@max-md", AtRuleKind::Container, "(not (min-width:28rem))")]
+    #[case("@[500px]", AtRuleKind::Container, "(min-width:500px)")]
+    #[case("@lg/sidebar", AtRuleKind::Container, "sidebar (min-width:32rem)")]
+    fn test_parse_at_rule_variants(
+        #[case] prefix: &str,
+        #[case] kind: AtRuleKind,
+        #[case] query: &str,
+    ) {
+        assert_eq!(
+            TailwindVariant::parse(prefix),
+            Some(TailwindVariant::At(kind, query.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("aria-unknown")]
+    #[case("has-checked")]
+    #[case("group-before")]
+    #[case("group-[&>svg]")]
+    #[case("min-md")]
+    #[case("@huge")]
+    fn test_parse_invalid_variants(#[case] prefix: &str) {
+        assert_eq!(TailwindVariant::parse(prefix), None);
+    }
+
+    #[test]
+    fn test_split_prefix() {
+        assert_eq!(split_prefix("md:hover:flex"), Some(("md", "hover:flex")));
+        assert_eq!(split_prefix("[&:hover]:flex"), Some(("[&:hover]", "flex")));
+        assert_eq!(split_prefix("bg-[url(a:b)]"), None);
+        assert!(is_likely_tailwind_class("[&>svg:first-child]:w-4"));
+    }
+
+    #[test]
+    fn test_arbitrary_variants() {
+        assert_eq!(parse_single_class("unknown:flex"), None);
+        assert_debug_snapshot!(
+            [
+                "[&>svg]:w-4",
+                "data-[state=open]:bg-white",
+                "aria-expanded:flex",
+                "peer-focus:hidden",
+                "group-hover:[&>svg]:block",
+                "dark:hover:flex",
+                "has-[:checked]:md:flex",
+                "supports-[display:grid]:grid",
+                "@md:flex",
+                "@md:@max-xl:flex",
+                "motion-safe:print:flex",
+                "print:hover:flex",
+            ]
+            .map(|class| parse_single_class(class).unwrap().to_static_style())
+        );
+    }
+
+    #[test]
+    fn test_max_screen_variants() {
+        use css::context::CssContext;
+        use css::tailwind_theme::{TailwindTheme, TailwindValues, set_tailwind_theme};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        Arc::new(CssContext::default()).enter(|| {
+            // no breakpoints registered
+            assert_eq!(parse_single_class("max-md:flex"), None);
+
+            set_tailwind_theme(TailwindTheme {
+                screens: TailwindValues {
+                    values: HashMap::from([(
+                        "wide".to_string(),
+                        ResponsiveKey::At(AtRuleKind::Media, "(min-width:1400px)".to_string()),
+                    )]),
+                    replace: false,
+                },
+                below: HashMap::from([
+                    (2, (AtRuleKind::Media, "(max-width:767.98px)".to_string())),
+                    (3, (AtRuleKind::Media, "(max-width:991.98px)".to_string())),
+                ]),
+                ..Default::default()
+            });
+            assert_debug_snapshot!(
+                [
+                    "max-md:flex",
+                    "sm:max-lg:flex",
+                    "max-wide:flex",
+                    "max-wide:max-md:hover:flex",
+                    "max-lg:print:flex",
+                ]
+                .map(|class| parse_single_class(class).unwrap().to_static_style())
+            );
+        });
+    }
+
+    #[rstest]
+    #[case("p-2 p-4", "p-4")]
+    #[case("px-2 p-4", "p-4")]
+    #[case("p-4 px-2", "p-4 px-2")]
+    #[case("pl-2 px-4 pr-1", "px-4 pr-1")]
+    #[case("mt-2 -mt-4", "-mt-4")]
+    #[case("top-0 inset-x-2 inset-4", "inset-4")]
+    #[case("rounded-t-md rounded-lg", "rounded-lg")]
+    #[case("p-2 md:p-4 md:p-6", "p-2 md:p-6")]
+    #[case(
+        "hover:bg-red-500 bg-blue-500 hover:bg-green-500",
+        "bg-blue-500 hover:bg-green-500"
+    )]
+    #[case("text-red-500 text-lg text-blue-500", "text-lg text-blue-500")]
+    #[case("dark:hover:flex dark:hover:hidden", "dark:hover:hidden")]
+    #[case("flex unknown-class hidden", "hidden")]
+    fn test_merge_tailwind_to_styles(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(
+            merge_tailwind_to_styles(input),
+            parse_tailwind_to_styles(expected, None)
+        );
+    }
 }
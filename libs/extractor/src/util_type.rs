@@ -3,6 +3,7 @@ pub enum UtilType {
     Css,
     GlobalCss,
     Keyframes,
+    Recipe,
 }
 
 impl TryFrom<String> for UtilType {
@@ -21,6 +22,8 @@ impl UtilType {
             Ok(UtilType::GlobalCss)
         } else if value == "keyframes" {
             Ok(UtilType::Keyframes)
+        } else if value == "recipe" {
+            Ok(UtilType::Recipe)
         } else {
             Err(())
         }
@@ -36,6 +39,7 @@ mod tests {
     #[case("css".to_string(), Ok(UtilType::Css))]
     #[case("globalCss".to_string(), Ok(UtilType::GlobalCss))]
     #[case("keyframes".to_string(), Ok(UtilType::Keyframes))]
+    #[case("recipe".to_string(), Ok(UtilType::Recipe))]
     #[case("unknown".to_string(), Err("unknown".to_string()))]
     #[case(String::new(), Err(String::new()))]
     fn test_util_type_try_from(#[case] input: String, #[case] expected: Result<UtilType, String>) {
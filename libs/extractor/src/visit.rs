@@ -1,11 +1,13 @@
 use crate::as_visit::AsVisitor;
-use crate::component::ExportVariableKind;
+use crate::component::{CustomComponent, ExportVariableKind};
 use crate::css_utils::{css_to_style_literal, keyframes_to_keyframes_style, optimize_css_block};
+use crate::diagnostic::{DiagnosticCode, DiagnosticSeverity, report, warn};
 use crate::extract_style::ExtractStyleProperty;
 use crate::extract_style::extract_css::ExtractCss;
 use crate::extract_style::extract_keyframes::ExtractKeyframes;
-use crate::extractor::KeyframesExtractResult;
+use crate::extract_style::extract_static_style::SourceSpan;
 use crate::extractor::extract_keyframes_from_expression::extract_keyframes_from_expression;
+use crate::extractor::extract_recipe_from_expression::extract_recipe_from_expression;
 use crate::extractor::extract_style_from_stylex::extract_stylex_namespace_styles;
 use crate::extractor::{
     ExtractResult, GlobalExtractResult,
@@ -14,6 +16,7 @@ use crate::extractor::{
     extract_style_from_jsx::extract_style_from_jsx,
     extract_style_from_styled::extract_style_from_styled,
 };
+use crate::extractor::{KeyframesExtractResult, RecipeExtractResult};
 use crate::gen_class_name::{gen_class_names, merge_expression_for_class_name};
 use crate::prop_modify_utils::{modify_prop_object, modify_props};
 use crate::stylex::{StylexDynamicInfo, StylexFunction, StylexNamespaceValue};
@@ -35,21 +38,23 @@ use oxc_ast_visit::walk_mut::{
     walk_call_expression, walk_expression, walk_import_declaration, walk_jsx_element, walk_program,
     walk_variable_declarator, walk_variable_declarators,
 };
-use strum::IntoEnumIterator;
 
 use crate::utils::{
     ParsedStyleOrder, expression_to_style_order, get_string_by_property_key,
     jsx_expression_to_style_order,
 };
 use oxc_ast::AstBuilder;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN, Span};
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::HashMap;
 use std::rc::Rc;
 
 pub struct DevupVisitor<'a> {
     pub ast: AstBuilder<'a>,
     filename: String,
     imports: FxHashMap<String, ExportVariableKind>,
+    /// User-defined components resolved like the built-in ones
+    components: FxHashMap<String, Rc<CustomComponent>>,
     import_object: Option<String>,
     jsx_imports: FxHashMap<String, String>,
     util_imports: FxHashMap<String, Rc<UtilType>>,
@@ -78,6 +83,10 @@ pub struct DevupVisitor<'a> {
     /// Set in `visit_jsx_element`, consumed in `visit_expression` to replace
     /// `Expression::JSXElement` with `Expression::JSXFragment`.
     pending_fragment_children: Option<oxc_allocator::Vec<'a, JSXChild<'a>>>,
+    /// Parsed source, `None` when it was generated and spans are meaningless
+    source_text: Option<&'a str>,
+    /// Byte offset of every line of `source_text`
+    line_starts: Vec<u32>,
 }
 
 impl<'a> DevupVisitor<'a> {
@@ -87,11 +96,17 @@ impl<'a> DevupVisitor<'a> {
         package: &str,
         css_files: Vec<String>,
         split_filename: Option<String>,
+        components: &HashMap<String, CustomComponent>,
+        source_text: Option<&'a str>,
     ) -> Self {
         Self {
             ast: AstBuilder::new(allocator),
             filename: filename.to_string(),
             imports: FxHashMap::default(),
+            components: components
+                .iter()
+                .map(|(name, component)| (name.clone(), Rc::new(component.clone())))
+                .collect(),
             jsx_imports: FxHashMap::default(),
             package: package.to_string(),
             css_files,
@@ -108,6 +123,42 @@ impl<'a> DevupVisitor<'a> {
             stylex_pending_keyframe_name: None,
             stylex_keyframe_names: FxHashMap::default(),
             pending_fragment_children: None,
+            source_text,
+            line_starts: source_text.map_or_else(Vec::new, |source| {
+                std::iter::once(0)
+                    .chain(
+                        source
+                            .match_indices('\n')
+                            .map(|(index, _)| u32::try_from(index + 1).unwrap_or(u32::MAX)),
+                    )
+                    .collect()
+            }),
+        }
+    }
+
+    /// Locate `span` in the source for source maps
+    fn source_span(&self, span: Span) -> Option<SourceSpan> {
+        let source = self.source_text?;
+        let line = self
+            .line_starts
+            .partition_point(|&start| start <= span.start)
+            .saturating_sub(1);
+        let line_start = self.line_starts.get(line).copied().unwrap_or_default() as usize;
+        let column = source
+            .get(line_start..span.start as usize)
+            .map_or(0, |text| text.encode_utf16().count());
+        Some(SourceSpan {
+            start: span.start,
+            end: span.end,
+            line: u32::try_from(line).unwrap_or(u32::MAX),
+            column: u32::try_from(column).unwrap_or(u32::MAX),
+        })
+    }
+
+    /// Record that `styles` were written at `span`
+    fn set_span(&self, span: Span, styles: &mut [ExtractStyleProp<'a>]) {
+        if let Some(span) = self.source_span(span) {
+            styles.iter_mut().for_each(|style| style.set_span(span));
         }
     }
 }
@@ -412,12 +463,14 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
             };
 
             if is_styled {
-                let (result, new_expr) = extract_style_from_styled(
+                let span = it.span();
+                let (mut result, new_expr) = extract_style_from_styled(
                     &self.ast,
                     it,
                     self.split_filename.as_deref(),
                     &self.imports,
                 );
+                self.set_span(span, &mut result.styles);
                 self.styles
                     .extend(result.styles.into_iter().flat_map(|ex| ex.extract()));
                 *it = new_expr;
@@ -429,6 +482,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
             && self.is_stylex_create_call(&call.callee)
             && call.arguments.len() == 1
         {
+            let span = call.span;
             let arg = call.arguments[0].to_expression_mut();
             let namespaces =
                 extract_stylex_namespace_styles(&self.ast, arg, &self.stylex_keyframe_names);
@@ -436,6 +490,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
             let mut namespace_map: FxHashMap<String, StylexNamespaceValue> = FxHashMap::default();
             let mut properties = oxc_allocator::Vec::new_in(self.ast.allocator);
             for (ns_name, mut styles, css_vars, include_refs) in namespaces {
+                self.set_span(span, &mut styles);
                 let class_name =
                     gen_class_names(&self.ast, &mut styles, None, self.split_filename.as_deref());
                 self.styles
@@ -584,6 +639,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                 && let Some(util_type) = self.util_imports.get(&util_import_key)
             {
                 if call.arguments.len() == 1 {
+                    let span = call.span;
                     let r = util_type.as_ref();
                     *it = if matches!(r, UtilType::Css) {
                         let ExtractResult {
@@ -615,6 +671,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                                 self.split_filename.as_deref(),
                             );
 
+                            self.set_span(span, &mut styles);
                             // already set style order
                             self.styles
                                 .extend(styles.into_iter().flat_map(|ex| ex.extract()));
@@ -625,6 +682,24 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                                     .expression_string_literal(SPAN, self.ast.str(""), None)
                             }
                         }
+                    } else if matches!(r, UtilType::Recipe) {
+                        let RecipeExtractResult {
+                            mut styles,
+                            expression,
+                        } = extract_recipe_from_expression(
+                            &self.ast,
+                            if let Argument::SpreadElement(spread) = &mut call.arguments[0] {
+                                &mut spread.argument
+                            } else {
+                                call.arguments[0].to_expression_mut()
+                            },
+                            self.split_filename.as_deref(),
+                        );
+                        if let Some(span) = self.source_span(span) {
+                            styles.iter_mut().for_each(|style| style.set_span(span));
+                        }
+                        self.styles.extend(styles);
+                        expression
                     } else if matches!(r, UtilType::Keyframes) {
                         let KeyframesExtractResult { keyframes } =
                             extract_keyframes_from_expression(
@@ -645,7 +720,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                     } else {
                         // global
                         let GlobalExtractResult {
-                            styles,
+                            mut styles,
                             style_order,
                         } = extract_global_style_from_expression(
                             &self.ast,
@@ -656,6 +731,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                             },
                             &self.filename,
                         );
+                        self.set_span(span, &mut styles);
                         // already set style order
                         self.styles.extend(styles.into_iter().flat_map(|mut ex| {
                             if let ExtractStyleProp::Static(css) = &mut ex {
@@ -674,6 +750,10 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                         UtilType::GlobalCss => {
                             self.ast.expression_identifier(SPAN, self.ast.str(""))
                         }
+                        UtilType::Recipe => {
+                            let mut empty = self.ast.expression_object(SPAN, self.ast.vec());
+                            extract_recipe_from_expression(&self.ast, &mut empty, None).expression
+                        }
                     };
                 }
             }
@@ -701,7 +781,14 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                     self.split_filename.as_deref(),
                 );
 
-                self.styles.extend(styles.into_iter().map(Into::into));
+                let span = self.source_span(tag.span);
+                self.styles.extend(styles.into_iter().map(|style| {
+                    let mut style: ExtractStyleValue = style.into();
+                    if let Some(span) = span {
+                        style.set_span(span);
+                    }
+                    style
+                }));
                 if let Some(cls) = class_name {
                     cls
                 } else {
@@ -711,7 +798,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                 // already set style order
             } else if matches!(r, UtilType::Keyframes) {
                 let keyframes = ExtractKeyframes {
-                    keyframes: keyframes_to_keyframes_style(&css_str),
+                    keyframes: keyframes_to_keyframes_style(&css_str, tag.quasi.span),
                 };
                 let name = keyframes
                     .extract(self.split_filename.as_deref())
@@ -766,7 +853,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                 && let Expression::Identifier(ident) = &member.object
                 && self.import_object.as_deref() == Some(ident.name.as_str())
             {
-                ExportVariableKind::try_from(member.property.name.to_string()).ok()
+                ExportVariableKind::resolve(member.property.name.as_str(), &self.components)
             } else {
                 None
             };
@@ -811,6 +898,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                     LiteralHandling::ExpandResponsiveThemeToken,
                 );
                 props_styles.extend(styles);
+                self.set_span(it.arguments[1].span(), &mut props_styles);
 
                 if let Some(t) = _tag {
                     tag = t;
@@ -940,11 +1028,12 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                 } else if let BindingPattern::ObjectPattern(object) = &it.id {
                     for prop in &object.properties {
                         if let Some(name) = get_string_by_property_key(&prop.key)
-                            && let Ok(kind) = ExportVariableKind::try_from(
+                            && let Some(kind) = ExportVariableKind::resolve(
                                 prop.value
                                     .get_binding_identifier()
-                                    .map(|id| id.name.to_string())
+                                    .map(|id| id.name.as_str())
                                     .unwrap_or_default(),
+                                &self.components,
                             )
                         {
                             self.imports.insert(name, kind);
@@ -958,8 +1047,11 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
 
         // Phase 4c: Check for destructuring of stylex.create()
         if self.stylex_pending_create.is_some() && it.id.get_binding_identifier().is_none() {
-            eprintln!(
-                "[stylex] ERROR: Destructuring stylex.create() is not supported. Assign the result to a single variable (e.g., `const styles = stylex.create({{...}})`)."
+            report(
+                DiagnosticSeverity::Error,
+                DiagnosticCode::UnsupportedValue,
+                "[stylex] Destructuring stylex.create() is not supported. Assign the result to a single variable (e.g., `const styles = stylex.create({...})`).",
+                it.id.span(),
             );
             self.stylex_pending_create.take();
         }
@@ -998,7 +1090,9 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                 match &specifiers[i] {
                     ImportSpecifier(import) => {
                         let imported_str = import.imported.to_string();
-                        if let Ok(kind) = ExportVariableKind::from_str(&imported_str) {
+                        if let Some(kind) =
+                            ExportVariableKind::resolve(&imported_str, &self.components)
+                        {
                             self.imports.insert(import.local.to_string(), kind);
                             specifiers.remove(i);
                         } else if let Ok(kind) = UtilType::from_str(&imported_str) {
@@ -1013,7 +1107,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                     ImportDeclarationSpecifier::ImportDefaultSpecifier(
                         import_default_specifier,
                     ) => {
-                        for kind in ExportVariableKind::iter() {
+                        for kind in ExportVariableKind::all(&self.components) {
                             self.imports.insert(
                                 format!("{}.{}", import_default_specifier.local, kind),
                                 kind,
@@ -1032,7 +1126,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                     ImportDeclarationSpecifier::ImportNamespaceSpecifier(
                         import_namespace_specifier,
                     ) => {
-                        for kind in ExportVariableKind::iter() {
+                        for kind in ExportVariableKind::all(&self.components) {
                             self.imports.insert(
                                 format!("{}.{}", import_namespace_specifier.local, kind),
                                 kind,
@@ -1129,8 +1223,10 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                                     style_vars = Some(expression.clone_in(self.ast.allocator));
                                 }
                             } else if let Some(at) = &mut attr.value {
-                                let ExtractResult { styles, tag, .. } =
-                                    extract_style_from_jsx(&self.ast, &name, at);
+                                let ExtractResult {
+                                    mut styles, tag, ..
+                                } = extract_style_from_jsx(&self.ast, &name, at);
+                                self.set_span(attr.span, &mut styles);
                                 props_styles.extend(styles.into_iter().rev());
                                 tag_name = tag.unwrap_or(tag_name);
                             }
@@ -1138,7 +1234,7 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                     }
                 } else if let JSXAttributeItem::SpreadAttribute(spread) = &mut attr {
                     // Extract styles from spread attributes (e.g., {...{"@media": {...}}})
-                    let ExtractResult { styles, .. } = extract_style_from_expression(
+                    let ExtractResult { mut styles, .. } = extract_style_from_expression(
                         &self.ast,
                         None,
                         &mut spread.argument,
@@ -1147,8 +1243,14 @@ impl<'a> VisitMut<'a> for DevupVisitor<'a> {
                         LiteralHandling::ExpandResponsiveThemeToken,
                     );
                     if styles.is_empty() {
+                        warn(
+                            DiagnosticCode::UnresolvedSpread,
+                            "spread props cannot be resolved statically and are left to the runtime",
+                            spread.span,
+                        );
                         attrs.insert(i, attr);
                     } else {
+                        self.set_span(spread.span, &mut styles);
                         props_styles.extend(styles.into_iter().rev());
                     }
                 } else {
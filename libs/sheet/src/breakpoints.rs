@@ -0,0 +1,358 @@
+//! Widths the levels of responsive values start at.
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use css::breakpoints::ResponsiveKey;
+use css::style_selector::AtRuleKind;
+use serde::de::Error;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+const DEFAULT_BREAKPOINTS: [u16; 6] = [0, 480, 768, 992, 1280, 1600];
+const DEFAULT_NAMES: [&str; 6] = ["xs", "sm", "md", "lg", "xl", "2xl"];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BreakpointUnit {
+    #[default]
+    Px,
+    Em,
+}
+
+impl Display for BreakpointUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Px => "px",
+            Self::Em => "em",
+        })
+    }
+}
+
+/// Breakpoints of a theme, one per responsive level
+///
+/// Deserializes from an array of pixel widths (`[0, 480, 768]`, named `xs`,
+/// `sm`, `md`, ... by level) or from an object of named widths in px or em
+/// (`{"sm": 480, "md": "48em"}`), sorted by width. Level 0 is added when no
+/// width is 0, and is also called `base`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoints {
+    values: Vec<u16>,
+    names: Vec<Option<String>>,
+    unit: BreakpointUnit,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new(DEFAULT_BREAKPOINTS.to_vec())
+    }
+}
+
+fn default_name(level: usize) -> Option<String> {
+    DEFAULT_NAMES.get(level).map(ToString::to_string)
+}
+
+impl Breakpoints {
+    /// Pixel breakpoints with the default names
+    #[must_use]
+    pub fn new(values: Vec<u16>) -> Self {
+        Self {
+            names: (0..values.len()).map(default_name).collect(),
+            values,
+            unit: BreakpointUnit::Px,
+        }
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[u16] {
+        &self.values
+    }
+
+    #[must_use]
+    pub fn get(&self, level: usize) -> Option<u16> {
+        self.values.get(level).copied()
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<u16> {
+        self.values.last().copied()
+    }
+
+    #[must_use]
+    pub const fn unit(&self) -> BreakpointUnit {
+        self.unit
+    }
+
+    #[must_use]
+    pub fn name(&self, level: usize) -> Option<&str> {
+        self.names.get(level)?.as_deref()
+    }
+
+    /// Names of the named levels, by level
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().flatten().map(String::as_str)
+    }
+
+    /// Overwrite the widths by level, appending the levels past the end
+    pub fn update(&mut self, values: Vec<u16>) {
+        for (level, value) in values.into_iter().enumerate() {
+            if let Some(prev) = self.values.get_mut(level) {
+                *prev = value;
+            } else {
+                self.values.push(value);
+                self.names.push(default_name(level));
+            }
+        }
+    }
+
+    /// `(min-width:…)` starting at `value`
+    #[must_use]
+    pub fn min_width(&self, value: u16) -> String {
+        format!("(min-width:{value}{})", self.unit)
+    }
+
+    /// `(max-width:…)` ending just below `value`
+    #[must_use]
+    pub fn max_width(&self, value: u16) -> String {
+        let below = value.saturating_sub(1);
+        match self.unit {
+            BreakpointUnit::Px => format!("(max-width:{below}.98px)"),
+            BreakpointUnit::Em => format!("(max-width:{below}.99875em)"),
+        }
+    }
+
+    /// Media features from level `from` up to just below level `to`
+    ///
+    /// `None` when the range is unbounded on both ends.
+    fn range(&self, from: usize, to: Option<usize>) -> Option<String> {
+        let min = self
+            .get(from)
+            .filter(|value| *value > 0)
+            .map(|value| self.min_width(value));
+        let max = to
+            .and_then(|to| self.get(to))
+            .filter(|value| *value > 0)
+            .map(|value| self.max_width(value));
+        match (min, max) {
+            (Some(min), Some(max)) => Some(format!("{min}and {max}")),
+            (min, max) => min.or(max),
+        }
+    }
+
+    /// Keys of responsive objects: `base`, every name, and for every name
+    /// `{name}Down` (below it), `{name}Only` (up to the next level) and
+    /// `{name}To{Other}` (up to a later level)
+    ///
+    /// Keys outside the levels become `kind` at-rules, prefixed by `container`
+    /// when set.
+    #[must_use]
+    pub fn responsive_keys(
+        &self,
+        kind: AtRuleKind,
+        container: Option<&str>,
+    ) -> HashMap<String, ResponsiveKey> {
+        let at = |query: String| {
+            ResponsiveKey::At(
+                kind,
+                container.map_or_else(|| query.clone(), |name| format!("{name} {query}")),
+            )
+        };
+        let named = self
+            .names
+            .iter()
+            .enumerate()
+            .filter_map(|(level, name)| Some((level, name.as_deref()?)))
+            .filter_map(|(level, name)| Some((u8::try_from(level).ok()?, level, name)))
+            .collect::<Vec<_>>();
+
+        let mut keys = HashMap::from([("base".to_string(), ResponsiveKey::Level(0))]);
+        for &(level, index, name) in &named {
+            keys.insert(name.to_string(), ResponsiveKey::Level(level));
+            if index > 0
+                && let Some(query) = self.range(0, Some(index))
+            {
+                keys.insert(format!("{name}Down"), at(query));
+            }
+            if let Some(query) = self.range(index, Some(index + 1)) {
+                keys.insert(format!("{name}Only"), at(query));
+            }
+            for &(_, to, to_name) in named.iter().filter(|(_, to, _)| *to > index) {
+                if let Some(query) = self.range(index, Some(to)) {
+                    keys.insert(format!("{name}To{}", capitalize(to_name)), at(query));
+                }
+            }
+        }
+        keys
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+fn parse_width(value: &Value) -> Result<(u16, BreakpointUnit), String> {
+    let invalid = || format!("invalid breakpoint `{value}`, expected a whole number of px or em");
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .and_then(|width| u16::try_from(width).ok())
+            .map(|width| (width, BreakpointUnit::Px))
+            .ok_or_else(invalid),
+        Value::String(width) => {
+            let width = width.trim();
+            let (width, unit) = if let Some(width) = width.strip_suffix("em") {
+                (width, BreakpointUnit::Em)
+            } else {
+                (
+                    width.strip_suffix("px").unwrap_or(width),
+                    BreakpointUnit::Px,
+                )
+            };
+            width
+                .trim()
+                .parse()
+                .map(|width| (width, unit))
+                .map_err(|_| invalid())
+        }
+        _ => Err(invalid()),
+    }
+}
+
+impl<'de> Deserialize<'de> for Breakpoints {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let map = match Value::deserialize(deserializer)? {
+            Value::Object(map) => map,
+            value => {
+                return serde_json::from_value(value)
+                    .map(Self::new)
+                    .map_err(D::Error::custom);
+            }
+        };
+        let mut entries = map
+            .iter()
+            .map(|(name, value)| parse_width(value).map(|width| (width, name)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(D::Error::custom)?;
+        entries.sort_by_key(|((width, _), _)| *width);
+
+        let mut units = entries
+            .iter()
+            .filter(|((width, _), _)| *width > 0)
+            .map(|((_, unit), _)| *unit);
+        let unit = units.next().unwrap_or_default();
+        if units.any(|other| other != unit) {
+            return Err(D::Error::custom("breakpoints mix px and em"));
+        }
+
+        let mut breakpoints = Self {
+            values: vec![],
+            names: vec![],
+            unit,
+        };
+        if entries.first().is_none_or(|((width, _), _)| *width > 0) {
+            breakpoints.values.push(0);
+            breakpoints.names.push(None);
+        }
+        for ((width, _), name) in entries {
+            breakpoints.values.push(width);
+            breakpoints.names.push(Some(name.clone()));
+        }
+        Ok(breakpoints)
+    }
+}
+
+impl Serialize for Breakpoints {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.unit == BreakpointUnit::Px
+            && (0..self.values.len()).all(|level| self.names[level] == default_name(level))
+        {
+            return self.values.serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(None)?;
+        for (level, (value, name)) in self.values.iter().zip(&self.names).enumerate() {
+            let key = match name {
+                Some(name) => name.clone(),
+                None if *value == 0 => continue,
+                None => level.to_string(),
+            };
+            match self.unit {
+                BreakpointUnit::Px => map.serialize_entry(&key, value)?,
+                BreakpointUnit::Em => map.serialize_entry(&key, &format!("{value}em"))?,
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("[0, 500, 900]")]
+    #[case(r#"{"sm": 480, "md": 768, "lg": "992px"}"#)]
+    #[case(r#"{"base": 0, "md": "48em", "sm": "30em"}"#)]
+    fn test_deserialize_and_serialize(#[case] json: &str) {
+        let breakpoints: Breakpoints = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&breakpoints).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Breakpoints>(&serialized).unwrap(),
+            breakpoints
+        );
+        assert_debug_snapshot!((breakpoints, serialized));
+    }
+
+    #[rstest]
+    #[case(r#"{"sm": "30em", "md": 768}"#, "breakpoints mix px and em")]
+    #[case(
+        r#"{"sm": "wide"}"#,
+        "invalid breakpoint `\"wide\"`, expected a whole number of px or em"
+    )]
+    #[case(
+        r#"{"sm": 47.5}"#,
+        "invalid breakpoint `47.5`, expected a whole number of px or em"
+    )]
+    #[case("[-1]", "invalid value: integer `-1`, expected u16")]
+    fn test_deserialize_error(#[case] json: &str, #[case] expected: &str) {
+        assert_eq!(
+            serde_json::from_str::<Breakpoints>(json)
+                .unwrap_err()
+                .to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        let mut breakpoints = Breakpoints::new(vec![0, 480]);
+        breakpoints.update(vec![0, 500, 800]);
+        assert_eq!(breakpoints.values(), [0, 500, 800]);
+        assert_eq!(breakpoints.names().collect::<Vec<_>>(), ["xs", "sm", "md"]);
+    }
+
+    #[test]
+    fn test_responsive_keys() {
+        let breakpoints: Breakpoints =
+            serde_json::from_str(r#"{"sm": "30em", "md": "48em"}"#).unwrap();
+        let keys = breakpoints.responsive_keys(AtRuleKind::Media, None);
+        assert_debug_snapshot!(keys.iter().collect::<std::collections::BTreeMap<_, _>>());
+        assert_eq!(
+            Breakpoints::default().responsive_keys(AtRuleKind::Container, Some("panel"))["mdOnly"],
+            ResponsiveKey::At(
+                AtRuleKind::Container,
+                "panel (min-width:768px)and (max-width:991.98px)".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use css::context::{CssContext, default_context};
+use extractor::extract_style::extract_style_value::ExtractStyleValue;
+use extractor::{ExtractOption, ExtractOutput, extract};
+use rustc_hash::FxHashSet;
+
+use crate::StyleSheet;
+use crate::format::CssFormat;
+use crate::theme::{Theme, ThemeDiff};
+
+/// Everything one app extracts into: its naming state and its style sheet
+///
+/// Two contexts never share class names, file numbers, prefix, debug flag,
+/// atom hoisting, theme token levels or styles, so several apps can be built in
+/// one process. The free functions ([`extract`], [`css::set_prefix`], ...)
+/// operate on [`DevupContext::shared`].
+///
+/// Every method runs against this context whatever context the calling thread
+/// is in; its naming state is reached through [`DevupContext::css_context`].
+#[derive(Debug)]
+pub struct DevupContext {
+    css: Arc<CssContext>,
+    pub sheet: StyleSheet,
+}
+
+impl Default for DevupContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DevupContext {
+    /// A context isolated from every other one
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            css: Arc::default(),
+            sheet: StyleSheet::default(),
+        }
+    }
+
+    /// A context over the naming state used by the free functions
+    #[must_use]
+    pub fn shared() -> Self {
+        Self {
+            css: default_context(),
+            sheet: StyleSheet::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn css_context(&self) -> &Arc<CssContext> {
+        &self.css
+    }
+
+    /// Run `f` with this context's naming state
+    fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.css.enter(f)
+    }
+
+    pub fn extract(
+        &self,
+        filename: &str,
+        code: &str,
+        option: ExtractOption,
+    ) -> Result<ExtractOutput, Box<dyn Error>> {
+        self.enter(|| extract(filename, code, option))
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.css.enter(|| self.sheet.set_theme(theme));
+    }
+
+    /// See [`StyleSheet::update_theme`]
+    pub fn update_theme(&mut self, theme: Theme) -> ThemeDiff {
+        self.css.enter(|| self.sheet.update_theme(theme))
+    }
+
+    pub fn update_styles(
+        &mut self,
+        styles: &FxHashSet<ExtractStyleValue>,
+        filename: &str,
+        single_css: bool,
+    ) -> (bool, bool) {
+        self.css
+            .enter(|| self.sheet.update_styles(styles, filename, single_css))
+    }
+
+    /// See [`StyleSheet::update_file`]
+    pub fn update_file(
+        &mut self,
+        styles: &FxHashSet<ExtractStyleValue>,
+        filename: &str,
+        single_css: bool,
+        import_main_css: bool,
+    ) -> (bool, Option<String>) {
+        self.css.enter(|| {
+            self.sheet
+                .update_file(styles, filename, single_css, import_main_css)
+        })
+    }
+
+    #[must_use]
+    pub fn create_css(&self, filename: Option<&str>, import_main_css: bool) -> String {
+        self.enter(|| self.sheet.create_css(filename, import_main_css))
+    }
+
+    /// See [`StyleSheet::create_css_with_format`]
+    #[must_use]
+    pub fn create_css_with_format(
+        &self,
+        filename: Option<&str>,
+        import_main_css: bool,
+        format: CssFormat,
+    ) -> String {
+        self.enter(|| {
+            self.sheet
+                .create_css_with_format(filename, import_main_css, format)
+        })
+    }
+
+    /// See [`StyleSheet::create_css_with_source_map`]
+    #[must_use]
+    pub fn create_css_with_source_map(
+        &self,
+        filename: Option<&str>,
+        import_main_css: bool,
+    ) -> (String, String) {
+        self.enter(|| {
+            self.sheet
+                .create_css_with_source_map(filename, import_main_css)
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use css::class_map::{get_class_map, reset_class_map};
+    use insta::assert_debug_snapshot;
+    use serial_test::serial;
+
+    fn build(context: &mut DevupContext, prefix: &str, color: &str) -> (String, String) {
+        context.css_context().set_prefix(Some(prefix.to_string()));
+        let mut theme = Theme::default();
+        let mut colors = crate::theme::ColorTheme::default();
+        colors.add_color("primary", color);
+        theme.add_color_theme("light", colors);
+        context.set_theme(theme);
+        let output = context
+            .extract(
+                "src/index.tsx",
+                "import {Box} from '@devup-ui/react';<Box color='$primary' p={1}/>",
+                ExtractOption::default(),
+            )
+            .unwrap();
+        context.update_file(&output.styles, "src/index.tsx", false, false);
+        (
+            output.code,
+            context.create_css(Some("src/index.tsx"), false),
+        )
+    }
+
+    #[test]
+    #[serial]
+    fn test_contexts_are_isolated() {
+        reset_class_map();
+        let mut first = DevupContext::new();
+        let mut second = DevupContext::new();
+        assert_debug_snapshot!(build(&mut first, "one-", "red"));
+        assert_debug_snapshot!(build(&mut second, "two-", "blue"));
+        assert!(get_class_map().is_empty());
+        assert_eq!(css::get_prefix(), None);
+        assert!(first.create_css(None, false).contains("--primary:red"));
+        assert!(second.create_css(None, false).contains("--primary:blue"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_shared_context() {
+        reset_class_map();
+        let context = DevupContext::shared();
+        context
+            .extract(
+                "src/index.tsx",
+                "import {Box} from '@devup-ui/react';<Box p={1}/>",
+                ExtractOption::default(),
+            )
+            .unwrap();
+        assert!(!get_class_map().is_empty());
+        assert!(Arc::ptr_eq(context.css_context(), &default_context()));
+    }
+}
@@ -0,0 +1,383 @@
+//! Themes from W3C Design Tokens Community Group documents.
+//!
+//! Tokens are objects with a `$value`, typed by their own `$type` or the
+//! nearest group's. A value of `"{group.token}"` aliases another token, and
+//! aliases may appear inside composite values. Every token is named after its
+//! dotted path, e.g. `$color.brand.500` or `typography="typography.heading.h1"`.
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::theme::{ColorTheme, Theme, TokenValues, Typography};
+
+/// The variant every token is registered under
+const VARIANT: &str = "default";
+
+struct Token<'a> {
+    value: &'a Value,
+    kind: Option<&'a str>,
+}
+
+/// Convert a DTCG document into a [`Theme`]
+///
+/// Colors, typography composites, dimensions, shadows, durations and cubic
+/// Béziers become colors, typography, length, shadows, durations and easings.
+/// Tokens of other types can still be aliased.
+///
+/// # Errors
+/// A malformed token, an alias to a missing token or a circular alias.
+pub fn theme_from_tokens(document: &Value) -> Result<Theme, String> {
+    let Value::Object(document) = document else {
+        return Err("design tokens must be an object".to_string());
+    };
+    let mut tokens = BTreeMap::new();
+    collect_tokens("", document, None, &mut tokens)?;
+
+    let mut theme = Theme::default();
+    let mut colors = None;
+    for (path, token) in &tokens {
+        let (value, kind) = resolve(&tokens, path, &mut vec![])?;
+        let Some(kind) = token.kind.or(kind) else {
+            continue;
+        };
+        let invalid = |error: String| format!("token `{path}`: {error}");
+        match kind {
+            "color" => colors
+                .get_or_insert_with(ColorTheme::default)
+                .add_color(path, &color(&value).map_err(invalid)?),
+            "dimension" => insert(&mut theme.length, path, dimension(&value).map_err(invalid)?),
+            "shadow" => insert(&mut theme.shadows, path, shadow(&value).map_err(invalid)?),
+            "duration" => insert(
+                &mut theme.durations,
+                path,
+                dimension(&value).map_err(invalid)?,
+            ),
+            "cubicBezier" => insert(
+                &mut theme.easings,
+                path,
+                cubic_bezier(&value).map_err(invalid)?,
+            ),
+            "typography" => {
+                theme.add_typography(path, vec![Some(typography(&value).map_err(invalid)?)]);
+            }
+            _ => {}
+        }
+    }
+    if let Some(colors) = colors {
+        theme.add_color_theme(VARIANT, colors);
+    }
+    Ok(theme)
+}
+
+fn insert(themes: &mut BTreeMap<String, BTreeMap<String, TokenValues>>, path: &str, value: String) {
+    themes
+        .entry(VARIANT.to_string())
+        .or_default()
+        .insert(path.to_string(), vec![Some(value)].into());
+}
+
+fn collect_tokens<'a>(
+    prefix: &str,
+    group: &'a Map<String, Value>,
+    kind: Option<&'a str>,
+    tokens: &mut BTreeMap<String, Token<'a>>,
+) -> Result<(), String> {
+    let kind = group.get("$type").and_then(Value::as_str).or(kind);
+    for (key, child) in group.iter().filter(|(key, _)| !key.starts_with('$')) {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        let Value::Object(child) = child else {
+            return Err(format!("`{path}` must be a token or a group"));
+        };
+        if let Some(value) = child.get("$value") {
+            tokens.insert(
+                path,
+                Token {
+                    value,
+                    kind: child.get("$type").and_then(Value::as_str).or(kind),
+                },
+            );
+        } else {
+            collect_tokens(&path, child, kind, tokens)?;
+        }
+    }
+    Ok(())
+}
+
+/// `group.token` when `value` is `"{group.token}"`
+fn alias(value: &Value) -> Option<&str> {
+    value.as_str()?.strip_prefix('{')?.strip_suffix('}')
+}
+
+/// The value of token `path` with every alias replaced, and the type of the
+/// token its alias chain ends at
+fn resolve<'a>(
+    tokens: &BTreeMap<String, Token<'a>>,
+    path: &str,
+    stack: &mut Vec<String>,
+) -> Result<(Value, Option<&'a str>), String> {
+    if let Some(start) = stack.iter().position(|visited| visited == path) {
+        let mut cycle = stack[start..].to_vec();
+        cycle.push(path.to_string());
+        return Err(format!("circular token reference: {}", cycle.join(" -> ")));
+    }
+    let token = tokens.get(path).ok_or_else(|| match stack.last() {
+        Some(from) => format!("token `{from}` references missing token `{path}`"),
+        None => format!("missing token `{path}`"),
+    })?;
+    stack.push(path.to_string());
+    let resolved = match alias(token.value) {
+        Some(target) => {
+            resolve(tokens, target, stack).map(|(value, kind)| (value, token.kind.or(kind)))
+        }
+        None => resolve_value(tokens, token.value, stack).map(|value| (value, token.kind)),
+    };
+    stack.pop();
+    resolved
+}
+
+/// `value` with the aliases nested in composites replaced
+fn resolve_value(
+    tokens: &BTreeMap<String, Token<'_>>,
+    value: &Value,
+    stack: &mut Vec<String>,
+) -> Result<Value, String> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| resolve_value(tokens, item, stack))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        Value::Object(members) => members
+            .iter()
+            .map(|(key, member)| Ok((key.clone(), resolve_value(tokens, member, stack)?)))
+            .collect::<Result<_, String>>()
+            .map(Value::Object),
+        _ => match alias(value) {
+            Some(target) => resolve(tokens, target, stack).map(|(value, _)| value),
+            None => Ok(value.clone()),
+        },
+    }
+}
+
+fn color(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(color) => Ok(color.clone()),
+        Value::Object(color) => color
+            .get("hex")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+            .ok_or_else(|| "color objects need a `hex` fallback".to_string()),
+        _ => Err(format!("invalid color {value}")),
+    }
+}
+
+/// Dimensions and durations, as a string or a `{value, unit}` object
+fn dimension(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(dimension) => Ok(dimension.clone()),
+        Value::Object(dimension) => match (dimension.get("value"), dimension.get("unit")) {
+            (Some(Value::Number(number)), Some(Value::String(unit))) => {
+                Ok(format!("{number}{unit}"))
+            }
+            _ => Err(format!("invalid dimension {value}")),
+        },
+        _ => Err(format!("invalid dimension {value}")),
+    }
+}
+
+fn number(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(number) => Ok(number.clone()),
+        Value::Number(number) => Ok(number.to_string()),
+        _ => Err(format!("invalid number {value}")),
+    }
+}
+
+fn font_family(value: &Value) -> Result<String, String> {
+    let family = |family: &str| {
+        if family.contains(' ') && !family.starts_with(['"', '\'']) {
+            format!("\"{family}\"")
+        } else {
+            family.to_string()
+        }
+    };
+    match value {
+        Value::String(name) => Ok(family(name)),
+        Value::Array(names) => names
+            .iter()
+            .map(|name| {
+                name.as_str()
+                    .map(family)
+                    .ok_or_else(|| format!("invalid font family {name}"))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|names| names.join(",")),
+        _ => Err(format!("invalid font family {value}")),
+    }
+}
+
+fn cubic_bezier(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(easing) => Ok(easing.clone()),
+        Value::Array(points) if points.len() == 4 && points.iter().all(Value::is_number) => {
+            Ok(format!(
+                "cubic-bezier({})",
+                points
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ))
+        }
+        _ => Err(format!("invalid cubic bezier {value}")),
+    }
+}
+
+fn shadow(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(shadow) => Ok(shadow.clone()),
+        Value::Array(layers) => layers
+            .iter()
+            .map(shadow)
+            .collect::<Result<Vec<_>, _>>()
+            .map(|layers| layers.join(",")),
+        Value::Object(layer) => {
+            let mut parts = vec![];
+            if layer.get("inset").and_then(Value::as_bool) == Some(true) {
+                parts.push("inset".to_string());
+            }
+            for member in ["offsetX", "offsetY", "blur", "spread"] {
+                parts.push(
+                    layer
+                        .get(member)
+                        .map_or_else(|| Ok("0".to_string()), dimension)?,
+                );
+            }
+            if let Some(value) = layer.get("color") {
+                parts.push(color(value)?);
+            }
+            Ok(parts.join(" "))
+        }
+        _ => Err(format!("invalid shadow {value}")),
+    }
+}
+
+fn typography(value: &Value) -> Result<Typography, String> {
+    let Value::Object(typography) = value else {
+        return Err(format!("invalid typography {value}"));
+    };
+    let member = |name: &str, convert: fn(&Value) -> Result<String, String>| {
+        typography.get(name).map(convert).transpose()
+    };
+    Ok(Typography::new(
+        member("fontFamily", font_family)?,
+        member("fontSize", dimension)?,
+        member("fontWeight", number)?,
+        member("lineHeight", number)?,
+        member("letterSpacing", dimension)?,
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+    use rstest::rstest;
+
+    #[test]
+    fn test_theme_from_tokens() {
+        let mut theme = theme_from_tokens(&serde_json::json!({
+            "color": {
+                "$type": "color",
+                "brand": {
+                    "500": { "$value": "#3366ff" },
+                    "600": { "$value": { "colorSpace": "srgb", "components": [0, 0, 1], "hex": "#0000ff" } }
+                },
+                "primary": { "$value": "{color.brand.500}" }
+            },
+            "size": {
+                "$type": "dimension",
+                "base": { "$value": { "value": 4, "unit": "px" } },
+                "gutter": { "$value": "{size.base}" }
+            },
+            "font": {
+                "sans": { "$type": "fontFamily", "$value": ["Pretendard Variable", "sans-serif"] },
+                "bold": { "$type": "fontWeight", "$value": 700 }
+            },
+            "typography": {
+                "heading": {
+                    "h1": {
+                        "$type": "typography",
+                        "$description": "Page titles",
+                        "$value": {
+                            "fontFamily": "{font.sans}",
+                            "fontSize": "32px",
+                            "fontWeight": "{font.bold}",
+                            "lineHeight": 1.2,
+                            "letterSpacing": { "value": -0.02, "unit": "em" }
+                        }
+                    }
+                }
+            },
+            "shadow": {
+                "card": {
+                    "$type": "shadow",
+                    "$value": [
+                        { "color": "{color.primary}", "offsetX": "0px", "offsetY": "1px", "blur": "2px" },
+                        { "color": "#0003", "offsetX": "0px", "offsetY": "4px", "blur": "8px", "spread": "-2px", "inset": true }
+                    ]
+                }
+            },
+            "motion": {
+                "fast": { "$type": "duration", "$value": "150ms" },
+                "standard": { "$type": "cubicBezier", "$value": [0.4, 0, 0.2, 1] }
+            }
+        }))
+        .unwrap();
+        let colors = theme.colors.remove("default").unwrap();
+        assert_eq!(colors.get("color-primary").unwrap(), "#3366ff");
+        assert_eq!(colors.get("color-brand-600").unwrap(), "#0000ff");
+        assert!(theme.colors.is_empty());
+        assert_debug_snapshot!(theme.to_css());
+    }
+
+    #[rstest]
+    #[case(serde_json::json!([]), "design tokens must be an object")]
+    #[case(serde_json::json!({"a": 1}), "`a` must be a token or a group")]
+    #[case(
+        serde_json::json!({"a": {"$type": "color", "$value": "{b}"}}),
+        "token `a` references missing token `b`"
+    )]
+    #[case(
+        serde_json::json!({
+            "a": {"$value": "{b}"},
+            "b": {"$value": "{c}"},
+            "c": {"$value": "{a}"}
+        }),
+        "circular token reference: a -> b -> c -> a"
+    )]
+    #[case(
+        serde_json::json!({"t": {"$type": "typography", "$value": {"fontSize": "{t}"}}}),
+        "circular token reference: t -> t"
+    )]
+    #[case(
+        serde_json::json!({"s": {"$type": "shadow", "$value": {"offsetX": "{missing}"}}}),
+        "token `s` references missing token `missing`"
+    )]
+    #[case(
+        serde_json::json!({"c": {"$type": "color", "$value": 1}}),
+        "token `c`: invalid color 1"
+    )]
+    #[case(
+        serde_json::json!({"d": {"$type": "dimension", "$value": 4}}),
+        "token `d`: invalid dimension 4"
+    )]
+    fn test_theme_from_tokens_error(#[case] document: Value, #[case] expected: &str) {
+        assert_eq!(theme_from_tokens(&document).unwrap_err(), expected);
+    }
+}
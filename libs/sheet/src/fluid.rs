@@ -0,0 +1,260 @@
+//! Values growing linearly with the viewport between two breakpoints.
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::breakpoints::{BreakpointUnit, Breakpoints};
+
+/// Width a fluid value starts or stops growing at: a breakpoint name or a
+/// width in px
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum FluidBound {
+    Width(u16),
+    Breakpoint(String),
+}
+
+/// `{"min": "16px", "max": "24px", "from": "sm", "to": "xl"}`, compiled to
+/// `clamp()`
+///
+/// `min` and `max` share one of the px, rem or em units, plain numbers being
+/// px. The value grows from the first non-zero breakpoint to the last one
+/// unless `from` and `to` say otherwise.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Fluid {
+    pub min: String,
+    pub max: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<FluidBound>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<FluidBound>,
+}
+
+/// `16px` -> `(16.0, "px")`
+fn parse_length(value: &str) -> Option<(f64, &str)> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(unit_start);
+    let unit = if unit.is_empty() { "px" } else { unit };
+    if !matches!(unit, "px" | "rem" | "em") {
+        return None;
+    }
+    number.parse().ok().map(|number| (number, unit))
+}
+
+/// Rounded to 4 decimals without leading or trailing zeros
+///
+/// Zero lengths keep their unit inside `clamp()`, so values are not passed
+/// through `optimize_value`.
+fn format_number(value: f64) -> String {
+    let value = format!("{value:.4}");
+    let value = value.trim_end_matches('0').trim_end_matches('.');
+    match value {
+        "" | "-0" => "0".to_string(),
+        _ => value.strip_prefix("-0.").map_or_else(
+            || {
+                value
+                    .strip_prefix("0.")
+                    .map_or_else(|| value.to_string(), |fraction| format!(".{fraction}"))
+            },
+            |fraction| format!("-.{fraction}"),
+        ),
+    }
+}
+
+impl Fluid {
+    /// Whether `value` is written as a fluid value
+    #[must_use]
+    pub fn is_fluid(value: &Value) -> bool {
+        value
+            .as_object()
+            .is_some_and(|obj| obj.contains_key("min") && obj.contains_key("max"))
+    }
+
+    /// Error when `from` or `to` names a breakpoint `breakpoints` do not have
+    ///
+    /// # Errors
+    /// The name of the unknown breakpoint.
+    pub fn check_bounds(&self, breakpoints: &Breakpoints) -> Result<(), String> {
+        for bound in [&self.from, &self.to].into_iter().flatten() {
+            if let FluidBound::Breakpoint(name) = bound
+                && !breakpoints.names().any(|known| known == name)
+            {
+                return Err(format!(
+                    "Fluid value bound `{name}` is not a breakpoint, expected one of: {}",
+                    breakpoints.names().collect::<Vec<_>>().join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Width of `bound` in px, `None` when unset; names are checked by
+    /// [`Self::check_bounds`] when the theme is deserialized
+    fn bound_width(bound: Option<&FluidBound>, breakpoints: &Breakpoints) -> Option<f64> {
+        let width = match bound? {
+            FluidBound::Width(width) => return Some(f64::from(*width)),
+            FluidBound::Breakpoint(name) => (0..breakpoints.values().len())
+                .find(|level| breakpoints.name(*level) == Some(name.as_str()))
+                .and_then(|level| breakpoints.get(level))?,
+        };
+        Some(breakpoint_px(width, breakpoints))
+    }
+
+    /// `clamp()` growing between the bounds resolved against `breakpoints`
+    #[must_use]
+    pub fn to_clamp(&self, breakpoints: &Breakpoints) -> String {
+        let (Some((min, unit)), Some((max, _))) =
+            (parse_length(&self.min), parse_length(&self.max))
+        else {
+            // rejected when deserialized
+            return self.min.clone();
+        };
+        let from = Self::bound_width(self.from.as_ref(), breakpoints).unwrap_or_else(|| {
+            breakpoints
+                .values()
+                .iter()
+                .find(|width| **width > 0)
+                .map_or(0.0, |width| breakpoint_px(*width, breakpoints))
+        });
+        let to = Self::bound_width(self.to.as_ref(), breakpoints).unwrap_or_else(|| {
+            breakpoints
+                .last()
+                .map_or(0.0, |width| breakpoint_px(width, breakpoints))
+        });
+        let to_unit = |px: f64| if unit == "px" { px } else { px / 16.0 };
+        let (from, to) = (to_unit(from), to_unit(to));
+        let (lower, upper) = if min <= max { (min, max) } else { (max, min) };
+        if (to - from).abs() < f64::EPSILON {
+            return format!("{}{unit}", format_number(max));
+        }
+
+        let slope = (max - min) / (to - from);
+        let intercept = slope.mul_add(-from, min);
+        let growth = format_number(slope.abs() * 100.0);
+        let preferred = if format_number(intercept) == "0" {
+            format!("{}{growth}vw", if slope < 0.0 { "-" } else { "" })
+        } else {
+            format!(
+                "calc({}{unit} {} {growth}vw)",
+                format_number(intercept),
+                if slope < 0.0 { '-' } else { '+' }
+            )
+        };
+        format!(
+            "clamp({}{unit},{preferred},{}{unit})",
+            format_number(lower),
+            format_number(upper)
+        )
+    }
+}
+
+/// Breakpoints are compared in px
+fn breakpoint_px(width: u16, breakpoints: &Breakpoints) -> f64 {
+    match breakpoints.unit() {
+        BreakpointUnit::Px => f64::from(width),
+        BreakpointUnit::Em => f64::from(width) * 16.0,
+    }
+}
+
+impl<'de> Deserialize<'de> for Fluid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawFluid {
+            min: Value,
+            max: Value,
+            #[serde(default)]
+            from: Option<FluidBound>,
+            #[serde(default)]
+            to: Option<FluidBound>,
+        }
+
+        let raw = RawFluid::deserialize(deserializer)?;
+        let length = |value: &Value| match value {
+            Value::String(s) => Ok(s.clone()),
+            Value::Number(n) => Ok(format!("{n}px")),
+            _ => Err(D::Error::custom(format!(
+                "Expected string or number, got: {value:?}"
+            ))),
+        };
+        let (min, max) = (length(&raw.min)?, length(&raw.max)?);
+        match (parse_length(&min), parse_length(&max)) {
+            (Some((_, min_unit)), Some((_, max_unit))) if min_unit == max_unit => Ok(Self {
+                min,
+                max,
+                from: raw.from,
+                to: raw.to,
+            }),
+            _ => Err(D::Error::custom(format!(
+                "Fluid value needs min and max in the same unit (px, rem or em), got: {min}, {max}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(json!({"min": "16px", "max": "24px"}), "clamp(16px,calc(12.5714px + .7143vw),24px)")]
+    #[case(json!({"min": 16, "max": 32, "from": 0, "to": 1600}), "clamp(16px,calc(16px + 1vw),32px)")]
+    #[case(json!({"min": "0px", "max": "16px", "from": 0, "to": 1600}), "clamp(0px,1vw,16px)")]
+    #[case(json!({"min": "1rem", "max": "2rem", "from": "sm", "to": "lg"}), "clamp(1rem,calc(.0625rem + 3.125vw),2rem)")]
+    #[case(json!({"min": "2rem", "max": "1rem", "from": "md", "to": "xl"}), "clamp(1rem,calc(3.5rem - 3.125vw),2rem)")]
+    #[case(json!({"min": "16px", "max": "24px", "from": "md", "to": "md"}), "24px")]
+    #[case(json!({"min": "-1px", "max": "1px", "from": 0, "to": 200}), "clamp(-1px,calc(-1px + 1vw),1px)")]
+    #[case(json!({"min": "10.5px", "max": "20.5px", "from": 100, "to": 1100}), "clamp(10.5px,calc(9.5px + 1vw),20.5px)")]
+    fn test_to_clamp(#[case] value: Value, #[case] expected: &str) {
+        let fluid: Fluid = serde_json::from_value(value).unwrap();
+        assert_eq!(fluid.to_clamp(&Breakpoints::default()), expected);
+    }
+
+    #[test]
+    fn test_em_breakpoints() {
+        let fluid: Fluid = serde_json::from_value(json!({"min": "1rem", "max": "2rem"})).unwrap();
+        let breakpoints: Breakpoints =
+            serde_json::from_value(json!({"sm": "30em", "lg": "60em"})).unwrap();
+        assert_eq!(fluid.to_clamp(&breakpoints), "clamp(1rem,3.3333vw,2rem)");
+    }
+
+    #[rstest]
+    #[case(json!({"min": "1rem", "max": "24px"}))]
+    #[case(json!({"min": "1vw", "max": "2vw"}))]
+    #[case(json!({"min": true, "max": "2px"}))]
+    fn test_invalid_fluid(#[case] value: Value) {
+        assert!(serde_json::from_value::<Fluid>(value).is_err());
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let breakpoints = Breakpoints::default();
+        let fluid: Fluid =
+            serde_json::from_value(json!({"min": 1, "max": 2, "from": "sm", "to": 1200})).unwrap();
+        assert_eq!(fluid.check_bounds(&breakpoints), Ok(()));
+        let fluid: Fluid =
+            serde_json::from_value(json!({"min": 1, "max": 2, "from": "tablt"})).unwrap();
+        assert_eq!(
+            fluid.check_bounds(&breakpoints),
+            Err(
+                "Fluid value bound `tablt` is not a breakpoint, expected one of: xs, sm, md, lg, xl, 2xl"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_is_fluid() {
+        assert!(Fluid::is_fluid(&json!({"min": "1px", "max": "2px"})));
+        assert!(!Fluid::is_fluid(&json!({"min": "1px"})));
+        assert!(!Fluid::is_fluid(&json!("1px")));
+    }
+}
@@ -0,0 +1,191 @@
+//! Output format of the generated CSS.
+//!
+//! Every writer emits minified CSS; [`CssFormat::Pretty`] re-indents the result
+//! so the writers never have to care about whitespace.
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+const INDENT: &str = "  ";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CssFormat {
+    /// No whitespace, as shipped to browsers
+    #[default]
+    Minified,
+    /// One rule or declaration per line, indented by nesting
+    Pretty,
+}
+
+impl CssFormat {
+    /// Format minified `css`
+    #[must_use]
+    pub fn apply(self, css: &str) -> Cow<'_, str> {
+        match self {
+            Self::Minified => Cow::Borrowed(css),
+            Self::Pretty => Cow::Owned(pretty(css)),
+        }
+    }
+}
+
+impl FromStr for CssFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minified" => Ok(Self::Minified),
+            "pretty" => Ok(Self::Pretty),
+            _ => Err(format!(
+                "unknown css format `{s}`, expected `minified` or `pretty`"
+            )),
+        }
+    }
+}
+
+impl Display for CssFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Minified => "minified",
+            Self::Pretty => "pretty",
+        })
+    }
+}
+
+fn pretty(css: &str) -> String {
+    let mut output = String::with_capacity(css.len() * 2);
+    let mut depth = 0;
+    let mut token = String::new();
+    let mut parens = 0_usize;
+    let mut chars = css.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '/' if chars.peek() == Some(&'*') => {
+                push_statement(&mut output, depth, &token);
+                token.clear();
+                chars.next();
+                let mut comment = String::from("/*");
+                let mut last = ' ';
+                for ch in chars.by_ref() {
+                    comment.push(ch);
+                    if last == '*' && ch == '/' {
+                        break;
+                    }
+                    last = ch;
+                }
+                push_line(&mut output, depth, &comment);
+            }
+            '"' | '\'' => {
+                token.push(ch);
+                let mut escaped = false;
+                for next in chars.by_ref() {
+                    token.push(next);
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == ch {
+                        break;
+                    }
+                }
+            }
+            '(' => {
+                parens += 1;
+                token.push(ch);
+            }
+            ')' => {
+                parens = parens.saturating_sub(1);
+                token.push(ch);
+            }
+            '{' if parens == 0 => {
+                push_line(&mut output, depth, &format!("{} {{", token.trim()));
+                token.clear();
+                depth += 1;
+            }
+            ';' if parens == 0 => {
+                push_statement(&mut output, depth, &token);
+                token.clear();
+            }
+            '}' if parens == 0 => {
+                push_statement(&mut output, depth, &token);
+                token.clear();
+                depth = depth.saturating_sub(1);
+                push_line(&mut output, depth, "}");
+            }
+            _ => token.push(ch),
+        }
+    }
+    push_statement(&mut output, depth, &token);
+    output
+}
+
+/// Push a declaration (`property: value;`) or an at-rule statement
+fn push_statement(output: &mut String, depth: usize, statement: &str) {
+    let statement = statement.trim();
+    if statement.is_empty() {
+        return;
+    }
+    if !statement.starts_with('@')
+        && let Some((property, value)) = statement.split_once(':')
+    {
+        push_line(
+            output,
+            depth,
+            &format!("{}: {};", property.trim(), value.trim()),
+        );
+    } else {
+        push_line(output, depth, &format!("{statement};"));
+    }
+}
+
+fn push_line(output: &mut String, depth: usize, line: &str) {
+    for _ in 0..depth {
+        output.push_str(INDENT);
+    }
+    output.push_str(line);
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+    use rstest::rstest;
+
+    #[test]
+    fn test_minified() {
+        assert!(matches!(
+            CssFormat::Minified.apply(".a{color:red}"),
+            Cow::Borrowed(".a{color:red}")
+        ));
+    }
+
+    #[test]
+    fn test_pretty() {
+        assert_snapshot!(CssFormat::Pretty.apply(concat!(
+            "/*! devup-ui */@import \"./a.css\";@layer b,t;",
+            "@layer t{:root{--primary:#000;--font:\"a;b\"}}",
+            "@layer b{.a{color:red;background:url(data:image/png;base64,AA==)}}",
+            "@media(min-width:480px){.b:hover{margin:0}}",
+            "@keyframes c{from{opacity:0}to{opacity:1}}",
+            ".d{content:\"{\"}"
+        )));
+    }
+
+    #[rstest]
+    #[case("minified", Ok(CssFormat::Minified))]
+    #[case("pretty", Ok(CssFormat::Pretty))]
+    #[case(
+        "compact",
+        Err("unknown css format `compact`, expected `minified` or `pretty`".to_string())
+    )]
+    fn test_from_str(#[case] input: &str, #[case] expected: Result<CssFormat, String>) {
+        let format = input.parse::<CssFormat>();
+        assert_eq!(format, expected);
+        if let Ok(format) = format {
+            assert_eq!(format.to_string(), input);
+        }
+    }
+}
@@ -1,13 +1,26 @@
+pub mod breakpoints;
+pub mod context;
+pub mod dtcg;
+pub mod fluid;
+pub mod format;
+pub mod source_map;
+pub mod tailwind;
 pub mod theme;
 
-use crate::theme::Theme;
+use crate::breakpoints::Breakpoints;
+pub use crate::context::DevupContext;
+use crate::format::CssFormat;
+use crate::source_map::{StyleSource, create_source_map};
+use crate::theme::{ColorTheme, ResponsiveMode, Theme, ThemeDiff};
 use css::{
     atom_hoist::{atom_hoist_threshold, is_atom_hoist},
-    file_map::canonical,
+    breakpoints::set_responsive_keys,
+    file_map::{canonical, is_global},
     file_routes::route_count_for_files,
     merge_selector, sheet_to_classname,
     style_selector::{AtRuleKind, StyleSelector},
-    theme_tokens::set_theme_token_levels,
+    tailwind_theme::set_tailwind_theme,
+    theme_tokens::{set_theme_token_levels, set_theme_token_names},
 };
 use extractor::extract_style::ExtractStyleProperty;
 use extractor::extract_style::extract_static_style::ThemeTokenResolution;
@@ -133,6 +146,31 @@ fn convert_theme_variable_value(value: &str) -> Cow<'_, str> {
     }
 }
 
+/// Names of the interfaces listing the radii, spacing, z-index, duration,
+/// easing and opacity tokens
+#[derive(Debug, Clone, Copy)]
+pub struct TokenInterfaceNames<'a> {
+    pub radii: &'a str,
+    pub spacing: &'a str,
+    pub z_index: &'a str,
+    pub durations: &'a str,
+    pub easings: &'a str,
+    pub opacity: &'a str,
+}
+
+impl Default for TokenInterfaceNames<'_> {
+    fn default() -> Self {
+        Self {
+            radii: "DevupThemeRadii",
+            spacing: "DevupThemeSpacing",
+            z_index: "DevupThemeZIndex",
+            durations: "DevupThemeDurations",
+            easings: "DevupThemeEasings",
+            opacity: "DevupThemeOpacity",
+        }
+    }
+}
+
 #[derive(Debug, Hash, Eq, PartialEq, Deserialize, Serialize, Ord, PartialOrd)]
 pub struct StyleSheetCss {
     pub css: String,
@@ -182,8 +220,14 @@ pub struct StyleSheet {
     pub imports: BTreeMap<String, BTreeSet<String>>,
     #[serde(default)]
     pub font_faces: BTreeMap<String, BTreeSet<BTreeMap<String, String>>>,
+    /// class name -> where its style was written, for source maps
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<String, StyleSource>,
     #[serde(skip)]
     pub theme: Theme,
+    /// Format of [`Self::create_css`]
+    #[serde(skip)]
+    pub format: CssFormat,
 }
 
 impl StyleSheet {
@@ -339,11 +383,43 @@ impl StyleSheet {
         true
     }
 
+    /// Replace the theme, returning what changed
+    ///
+    /// Unless [`ThemeDiff::settings_changed`], only the theme layer of the CSS
+    /// changes, see [`Self::create_theme_layer`].
+    pub fn update_theme(&mut self, theme: Theme) -> ThemeDiff {
+        let diff = self.theme.diff(&theme);
+        self.set_theme(theme);
+        diff
+    }
+
+    /// The `@layer t{...}` block of the theme variables, empty without any
+    #[must_use]
+    pub fn create_theme_layer(&self) -> String {
+        let theme_css = self.theme.to_css();
+        if theme_css.is_empty() {
+            theme_css
+        } else {
+            format!("@layer t{{{theme_css}}}")
+        }
+    }
+
     pub fn set_theme(&mut self, theme: Theme) {
         set_theme_token_levels(
             theme.get_length_token_levels(),
             theme.get_shadow_token_levels(),
         );
+        set_theme_token_names(
+            theme
+                .colors
+                .values()
+                .flat_map(ColorTheme::css_keys)
+                .cloned()
+                .collect(),
+            theme.typography.keys().cloned().collect(),
+        );
+        set_responsive_keys(theme.responsive_keys());
+        set_tailwind_theme(theme.tailwind_theme());
         self.theme = theme;
     }
 
@@ -352,6 +428,17 @@ impl StyleSheet {
         styles: &FxHashSet<ExtractStyleValue>,
         filename: &str,
         single_css: bool,
+    ) -> (bool, bool) {
+        self.update_styles_from(styles, filename, filename, single_css)
+    }
+
+    /// [`Self::update_styles`] for styles written in `source_file`
+    fn update_styles_from(
+        &mut self,
+        styles: &FxHashSet<ExtractStyleValue>,
+        filename: &str,
+        source_file: &str,
+        single_css: bool,
     ) -> (bool, bool) {
         let mut collected = false;
         let mut updated_base_style = false;
@@ -408,6 +495,16 @@ impl StyleSheet {
                             }
                         };
 
+                    if let Some(span) = st.span() {
+                        self.sources.insert(
+                            class_name.clone(),
+                            StyleSource {
+                                file: source_file.to_string(),
+                                line: span.line,
+                                column: span.column,
+                            },
+                        );
+                    }
                     if self.add_property_with_layer(
                         &class_name,
                         st.property(),
@@ -495,7 +592,47 @@ impl StyleSheet {
         (collected, updated_base_style)
     }
 
+    /// Replace the styles extracted from `filename` and build its CSS chunk
+    ///
+    /// Returns whether the global stylesheet changed and the new chunk, or `None`
+    /// when nothing was collected. Global (shared-chunk) files are treated like
+    /// single-css and emitted into `devup-ui.css`.
+    pub fn update_file(
+        &mut self,
+        styles: &FxHashSet<ExtractStyleValue>,
+        filename: &str,
+        single_css: bool,
+        import_main_css: bool,
+    ) -> (bool, Option<String>) {
+        // Use the bucket identity (single-importer collapse) so the CSS naming +
+        // property bucket + emitted chunk match the canonical class names the
+        // extractor already baked into the code. Identity when no map is loaded.
+        let canonical_filename = canonical(filename);
+        let global = single_css || is_global(filename);
+        // globalCss (@font-face / global selectors) is per-SOURCE-file, never
+        // collapsed. rm_global_css MUST use the RAW filename so a collapsed
+        // member (sharing the bucket-root's canonical) never wipes the root's
+        // globalCss. Atom property bucketing still uses canonical_filename.
+        let default_collected = self.rm_global_css(filename, global);
+        let (collected, updated_base_style) =
+            self.update_styles_from(styles, &canonical_filename, filename, global);
+        (
+            updated_base_style || default_collected,
+            (collected || default_collected).then(|| {
+                self.create_css(
+                    if global {
+                        None
+                    } else {
+                        Some(&canonical_filename)
+                    },
+                    import_main_css,
+                )
+            }),
+        )
+    }
+
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub fn create_interface(
         &self,
         package_name: &str,
@@ -504,6 +641,8 @@ impl StyleSheet {
         length_interface_name: &str,
         shadows_interface_name: &str,
         theme_interface_name: &str,
+        breakpoints_interface_name: &str,
+        token_interface_names: &TokenInterfaceNames,
     ) -> String {
         let mut color_keys = BTreeSet::new();
         let mut typography_keys = BTreeSet::new();
@@ -524,11 +663,37 @@ impl StyleSheet {
         self.theme.colors.keys().for_each(|key| {
             theme_keys.insert(key.clone());
         });
+        let token_keys = [
+            (token_interface_names.radii, &self.theme.radii),
+            (token_interface_names.spacing, &self.theme.spacing),
+            (token_interface_names.z_index, &self.theme.z_index),
+            (token_interface_names.durations, &self.theme.durations),
+            (token_interface_names.easings, &self.theme.easings),
+            (token_interface_names.opacity, &self.theme.opacity),
+        ]
+        .map(|(name, themes)| {
+            (
+                name,
+                themes
+                    .values()
+                    .flat_map(|t| t.keys().cloned())
+                    .collect::<BTreeSet<_>>(),
+            )
+        });
+
+        let breakpoint_keys = self
+            .theme
+            .breakpoints
+            .names()
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>();
 
         if color_keys.is_empty()
             && typography_keys.is_empty()
             && length_keys.is_empty()
             && shadows_keys.is_empty()
+            && token_keys.iter().all(|(_, keys)| keys.is_empty())
+            && self.theme.breakpoints == Breakpoints::default()
         {
             String::new()
         } else {
@@ -554,8 +719,18 @@ impl StyleSheet {
                 }
                 contents
             };
+            let mut token_interfaces = String::new();
+            for (name, keys) in token_keys {
+                if !keys.is_empty() {
+                    push_fmt!(
+                        &mut token_interfaces,
+                        "interface {name}{{{}}}",
+                        dollar_keys(keys)
+                    );
+                }
+            }
             format!(
-                "import \"{}\";declare module \"{}\"{{interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}}}",
+                "import \"{}\";declare module \"{}\"{{interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}interface {}{{{}}}{}}}",
                 package_name,
                 package_name,
                 color_interface_name,
@@ -567,7 +742,10 @@ impl StyleSheet {
                 shadows_interface_name,
                 dollar_keys(shadows_keys),
                 theme_interface_name,
-                plain_keys(theme_keys)
+                plain_keys(theme_keys),
+                breakpoints_interface_name,
+                plain_keys(breakpoint_keys),
+                token_interfaces
             )
         }
     }
@@ -583,7 +761,7 @@ impl StyleSheet {
         // Estimate ~64 bytes per property for pre-allocation
         let prop_count: usize = map.values().map(FxHashSet::len).sum();
         let mut current_css = String::with_capacity(prop_count * 64);
-        for (level, props) in map {
+        for (level, props) in self.theme.cascade_order(map) {
             let (mut global_props, rest): (Vec<_>, Vec<_>) = props
                 .iter()
                 .partition(|prop| matches!(prop.selector, Some(StyleSelector::Global(_, _))));
@@ -609,17 +787,16 @@ impl StyleSheet {
                 None
             } else {
                 Some(
-                    self.theme
-                        .breakpoints
-                        .iter()
-                        .enumerate()
-                        .find(|(idx, _)| (*idx as u8) == *level)
-                        .map_or_else(
-                            || self.theme.breakpoints.last().copied().unwrap_or(0),
-                            |(_, bp)| *bp,
-                        ),
+                    self.theme.breakpoint_query(
+                        self.theme
+                            .breakpoints
+                            .get(*level as usize)
+                            .or_else(|| self.theme.breakpoints.last())
+                            .unwrap_or(0),
+                    ),
                 )
             };
+            let container_mode = self.theme.responsive_mode == ResponsiveMode::Container;
 
             if !global_props.is_empty() {
                 // Separate layered and non-layered global props
@@ -648,8 +825,8 @@ impl StyleSheet {
                             selector_map.entry(selector).or_default().push(prop);
                         }
                     }
-                    if let Some(break_point) = break_point {
-                        push_fmt!(&mut current_css, "@media(min-width:{break_point}px){{");
+                    if let Some(break_point) = &break_point {
+                        push_fmt!(&mut current_css, "{break_point}{{");
                     }
                     for (selector, props) in selector_map {
                         current_css.push_str(selector);
@@ -673,8 +850,8 @@ impl StyleSheet {
             }
 
             if !sorted_props.is_empty() {
-                if let Some(break_point) = break_point {
-                    push_fmt!(&mut current_css, "@media(min-width:{break_point}px){{");
+                if let Some(break_point) = &break_point {
+                    push_fmt!(&mut current_css, "{break_point}{{");
                 }
                 for prop in sorted_props {
                     prop.write_extract(&mut current_css);
@@ -684,38 +861,36 @@ impl StyleSheet {
                 }
             }
             for ((kind, query), props) in at_rules {
-                if let Some(break_point) = break_point {
+                if let Some(break_point) = &break_point {
                     match kind {
+                        AtRuleKind::Media if !container_mode => {
+                            push_fmt!(&mut current_css, "{break_point}and {query}{{");
+                        }
                         AtRuleKind::Media => {
+                            let separator = if query.starts_with('(') { "" } else { " " };
                             push_fmt!(
                                 &mut current_css,
-                                "@media(min-width:{break_point}px)and {query}{{"
+                                "{break_point}{{@media{separator}{query}{{"
                             );
                         }
                         AtRuleKind::Supports => {
-                            push_fmt!(
-                                &mut current_css,
-                                "@media(min-width:{break_point}px){{@supports{query}{{"
-                            );
+                            push_fmt!(&mut current_css, "{break_point}{{@supports{query}{{");
                         }
                         AtRuleKind::Container => {
-                            push_fmt!(
-                                &mut current_css,
-                                "@media(min-width:{break_point}px){{@container{query}{{"
-                            );
+                            push_fmt!(&mut current_css, "{break_point}{{@container{query}{{");
                         }
                         AtRuleKind::Layer => {
-                            push_fmt!(
-                                &mut current_css,
-                                "@media(min-width:{break_point}px){{@layer {query}{{"
-                            );
+                            push_fmt!(&mut current_css, "{break_point}{{@layer {query}{{");
+                        }
+                        AtRuleKind::Scope => {
+                            push_fmt!(&mut current_css, "{break_point}{{@scope{query}{{");
                         }
                     }
                     for prop in props {
                         prop.write_extract(&mut current_css);
                     }
                     match kind {
-                        AtRuleKind::Media => current_css.push('}'),
+                        AtRuleKind::Media if !container_mode => current_css.push('}'),
                         _ => current_css.push_str("}}"),
                     }
                 } else {
@@ -772,8 +947,43 @@ impl StyleSheet {
             .collect()
     }
 
+    /// [`Self::create_css`] plus its source map (v3)
+    ///
+    /// Each rule of an extracted style is mapped to the JSX prop, styled
+    /// template or `css()` call it came from; sources are the filenames given to
+    /// [`Self::update_styles`].
+    #[must_use]
+    pub fn create_css_with_source_map(
+        &self,
+        filename: Option<&str>,
+        import_main_css: bool,
+    ) -> (String, String) {
+        let css = self.create_css(filename, import_main_css);
+        let map = create_source_map(&css, &self.sources);
+        (css, map)
+    }
+
     #[must_use]
     pub fn create_css(&self, filename: Option<&str>, import_main_css: bool) -> String {
+        self.create_css_with_format(filename, import_main_css, self.format)
+    }
+
+    /// [`Self::create_css`] in `format` instead of [`Self::format`]
+    #[must_use]
+    pub fn create_css_with_format(
+        &self,
+        filename: Option<&str>,
+        import_main_css: bool,
+        format: CssFormat,
+    ) -> String {
+        let css = self.create_minified_css(filename, import_main_css);
+        match format.apply(&css) {
+            Cow::Borrowed(_) => css,
+            Cow::Owned(formatted) => formatted,
+        }
+    }
+
+    fn create_minified_css(&self, filename: Option<&str>, import_main_css: bool) -> String {
         let mut css = String::with_capacity(4096);
         css.push_str(Self::create_header());
         for import in self.imports.values().flatten() {
@@ -809,9 +1019,9 @@ impl StyleSheet {
             style_orders.remove(&255);
             // base style
 
-            let theme_css = self.theme.to_css();
+            let theme_layer = self.create_theme_layer();
             let has_base = style_orders.remove(&0);
-            let has_theme = !theme_css.is_empty();
+            let has_theme = !theme_layer.is_empty();
             let has_orders = !style_orders.is_empty();
             if has_base || has_theme || has_orders {
                 css.push_str("@layer ");
@@ -837,9 +1047,7 @@ impl StyleSheet {
                 }
                 css.push(';');
             }
-            if !theme_css.is_empty() {
-                push_fmt!(&mut css, "@layer t{{{theme_css}}}");
-            }
+            css.push_str(&theme_layer);
             // One source file extracted under multiple passes (e.g. Next
             // server + client compilations) registers identical @font-face rules
             // under multiple file keys; emit each distinct rule only once.
@@ -1037,7 +1245,7 @@ mod tests {
         ExtractStaticStyle, ThemeTokenResolution,
     };
     use extractor::{ExtractOption, extract};
-    use insta::assert_debug_snapshot;
+    use insta::{assert_debug_snapshot, assert_snapshot};
 
     use rstest::rstest;
     use rustc_hash::FxHashSet;
@@ -1072,6 +1280,59 @@ mod tests {
         assert_debug_snapshot!(sheet.create_css(None, false).split("*/").nth(1).unwrap());
     }
 
+    #[test]
+    #[serial]
+    fn test_create_css_with_source_map() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        let output = extract(
+            "src/index.tsx",
+            r#"import {Box,css} from '@devup-ui/react'
+const a = css({ color: "red" });
+<Box
+  bg="blue" _hover={{ p: 1 }} className={a}
+/>"#,
+            ExtractOption::default(),
+        )
+        .unwrap();
+        sheet.update_file(&output.styles, "src/index.tsx", false, false);
+        assert_debug_snapshot!(sheet.create_css_with_source_map(Some("src/index.tsx"), false));
+        assert_debug_snapshot!(sheet.create_css_with_source_map(None, false).1);
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_css_pretty() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        let mut theme = Theme::default();
+        let mut color_theme = theme::ColorTheme::default();
+        color_theme.add_color("primary", "red");
+        theme.add_color_theme("light", color_theme);
+        sheet.set_theme(theme);
+        let output = extract(
+            "src/index.tsx",
+            r#"import {Box,keyframes} from '@devup-ui/react'
+keyframes({ from: { opacity: 0 }, to: { opacity: 1 } });
+<Box color="$primary" p={[1, 2]} _hover={{ bg: "blue" }} />"#,
+            ExtractOption::default(),
+        )
+        .unwrap();
+        sheet.update_styles(&output.styles, "src/index.tsx", true);
+        let minified = sheet.create_css(None, false);
+        sheet.format = CssFormat::Pretty;
+        assert_eq!(
+            sheet.create_css_with_format(None, false, CssFormat::Minified),
+            minified
+        );
+        let (css, map) = sheet.create_css_with_source_map(None, false);
+        assert_eq!(css, sheet.create_css(None, false));
+        assert_snapshot!(css);
+        assert_debug_snapshot!(map);
+    }
+
     // Atom-level hoisting emission. Without an atom-hoist test these branches in
     // compute_hoisted_atoms / create_css were uncovered:
     //   * compute_hoisted_atoms skips style_order 0
@@ -1989,6 +2250,31 @@ mod tests {
         assert_debug_snapshot!(sheet.create_css(None, false).split("*/").nth(1).unwrap());
     }
 
+    #[test]
+    fn test_selector_with_scoped_theme() {
+        let mut sheet = StyleSheet::default();
+        sheet.add_property(
+            "test",
+            "background",
+            0,
+            "black",
+            Some(&"scoped-theme-dark".into()),
+            None,
+            None,
+        );
+        sheet.add_property(
+            "test",
+            "background",
+            1,
+            "gray",
+            Some(&(&"scoped-theme-dark".into(), "hover").into()),
+            None,
+            None,
+        );
+
+        assert_debug_snapshot!(sheet.create_css(None, false).split("*/").nth(1).unwrap());
+    }
+
     #[test]
     fn test_deserialize() {
         {
@@ -2278,7 +2564,9 @@ mod tests {
                 "TypographyInterface",
                 "LengthInterface",
                 "ShadowsInterface",
-                "ThemeInterface"
+                "ThemeInterface",
+                "BreakpointsInterface",
+                &TokenInterfaceNames::default(),
             ),
             ""
         );
@@ -2295,7 +2583,9 @@ mod tests {
             "TypographyInterface",
             "LengthInterface",
             "ShadowsInterface",
-            "ThemeInterface"
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
         ));
 
         // test wrong case (backticks and special characters)
@@ -2321,7 +2611,9 @@ mod tests {
             "TypographyInterface",
             "LengthInterface",
             "ShadowsInterface",
-            "ThemeInterface"
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
         ));
 
         // test nested colors - interface keys should use dots for TypeScript
@@ -2348,7 +2640,9 @@ mod tests {
             "TypographyInterface",
             "LengthInterface",
             "ShadowsInterface",
-            "ThemeInterface"
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
         ));
 
         // test deep nested colors
@@ -2375,7 +2669,9 @@ mod tests {
             "TypographyInterface",
             "LengthInterface",
             "ShadowsInterface",
-            "ThemeInterface"
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
         ));
 
         // Multiple typography keys + multiple color themes exercise the
@@ -2406,7 +2702,9 @@ mod tests {
             "TypographyInterface",
             "LengthInterface",
             "ShadowsInterface",
-            "ThemeInterface"
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
         ));
     }
 
@@ -2485,7 +2783,7 @@ mod tests {
         );
 
         let mut sheet = StyleSheet::default();
-        let output = extract("index.tsx", "import {Box,globalCss,keyframes,Flex} from '@devup-ui/core';<Flex/>;keyframes({from:{opacity:0},to:{opacity:1}});<Box w={1} h={variable} />;globalCss`div{color:red}`;globalCss({div:{display:flex},imports:['https://test.com/a.css'],fontFaces:[{fontFamily:'Roboto',src:'url(/fonts/Roboto-Regular.ttf)'}]})", ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: std::collections::HashMap::new() }).unwrap();
+        let output = extract("index.tsx", "import {Box,globalCss,keyframes,Flex} from '@devup-ui/core';<Flex/>;keyframes({from:{opacity:0},to:{opacity:1}});<Box w={1} h={variable} />;globalCss`div{color:red}`;globalCss({div:{display:flex},imports:['https://test.com/a.css'],fontFaces:[{fontFamily:'Roboto',src:'url(/fonts/Roboto-Regular.ttf)'}]})", ExtractOption { package: "@devup-ui/core".to_string(), css_dir: "@devup-ui/core".to_string(), single_css: true, import_main_css: false, import_aliases: std::collections::HashMap::new(), strict: false, components: std::collections::HashMap::new() }).unwrap();
         sheet.update_styles(&output.styles, "index.tsx", true);
         assert_debug_snapshot!(sheet.create_css(None, true).split("*/").nth(1).unwrap());
     }
@@ -2601,6 +2899,141 @@ mod tests {
         assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
     }
 
+    #[test]
+    #[serial]
+    fn test_named_breakpoints() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        sheet.set_theme(
+            serde_json::from_str(r#"{"breakpoints":{"sm":"30em","md":"48em"}}"#).unwrap(),
+        );
+        let output = extract(
+            "src/index.tsx",
+            r"import {Box} from '@devup-ui/react'
+<Box p={{ base: 1, md: 2 }} m={{ smOnly: 1, smDown: 2, smToMd: 3 }} />",
+            ExtractOption::default(),
+        )
+        .unwrap();
+        sheet.update_styles(&output.styles, "src/index.tsx", true);
+        assert_debug_snapshot!(sheet.create_css(None, false).split("*/").nth(1).unwrap());
+        assert_debug_snapshot!(sheet.create_interface(
+            "package",
+            "ColorInterface",
+            "TypographyInterface",
+            "LengthInterface",
+            "ShadowsInterface",
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
+        ));
+    }
+
+    #[test]
+    fn test_desktop_first() {
+        let mut sheet = StyleSheet::default();
+        let mut theme = Theme {
+            desktop_first: true,
+            ..Theme::default()
+        };
+        theme.add_typography(
+            "body",
+            vec![
+                Some(Typography::new(
+                    None,
+                    Some("16px".to_string()),
+                    None,
+                    None,
+                    None,
+                )),
+                Some(Typography::new(
+                    None,
+                    Some("12px".to_string()),
+                    None,
+                    None,
+                    None,
+                )),
+                Some(Typography::new(
+                    None,
+                    Some("14px".to_string()),
+                    None,
+                    None,
+                    None,
+                )),
+            ],
+        );
+        sheet.set_theme(theme);
+        sheet.add_property("a", "width", 0, "100%", None, Some(0), None);
+        sheet.add_property("b", "width", 1, "25%", None, Some(0), None);
+        sheet.add_property("c", "width", 2, "50%", None, Some(0), None);
+        sheet.add_property(
+            "d",
+            "width",
+            3,
+            "75%",
+            Some(&StyleSelector::At {
+                kind: AtRuleKind::Media,
+                query: "print".to_string(),
+                selector: None,
+            }),
+            Some(0),
+            None,
+        );
+        let css = sheet.create_css(None, false);
+        assert!(!css.contains("min-width"));
+        assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("panel"))]
+    fn test_container_responsive_mode(#[case] container: Option<&str>) {
+        let mut sheet = StyleSheet::default();
+        let mut theme = Theme {
+            responsive_mode: ResponsiveMode::Container,
+            responsive_container: container.map(ToString::to_string),
+            ..Theme::default()
+        };
+        theme.add_typography(
+            "body",
+            vec![
+                Some(Typography::new(
+                    None,
+                    Some("14px".to_string()),
+                    None,
+                    None,
+                    None,
+                )),
+                Some(Typography::new(
+                    None,
+                    Some("16px".to_string()),
+                    None,
+                    None,
+                    None,
+                )),
+            ],
+        );
+        sheet.set_theme(theme);
+        sheet.add_property("a", "width", 0, "100%", None, Some(0), None);
+        sheet.add_property("b", "width", 1, "50%", None, Some(0), None);
+        sheet.add_property(
+            "c",
+            "width",
+            2,
+            "25%",
+            Some(&StyleSelector::At {
+                kind: AtRuleKind::Media,
+                query: "print".to_string(),
+                selector: None,
+            }),
+            Some(0),
+            None,
+        );
+        let css = sheet.create_css(None, false);
+        assert!(!css.contains("@media(min-width"));
+        assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
+    }
+
     #[test]
     fn test_theme_layer_in_css() {
         let mut sheet = StyleSheet::default();
@@ -2822,6 +3255,8 @@ const color = "red";
                 single_css: true,
                 import_main_css: false,
                 import_aliases: std::collections::HashMap::new(),
+                strict: false,
+                components: std::collections::HashMap::new(),
             },
         )
         .unwrap();
@@ -2842,4 +3277,214 @@ const color = "red";
             output.code,
         );
     }
+
+    #[test]
+    fn test_token_categories_interface() {
+        let mut sheet = StyleSheet::default();
+        sheet.set_theme(
+            serde_json::from_str(
+                r#"{
+                    "radii": { "default": { "sm": 4, "card": ["8px", "16px"] } },
+                    "zIndex": { "default": { "modal": 1000 } },
+                    "easings": { "default": { "standard": "ease-in-out" } }
+                }"#,
+            )
+            .unwrap(),
+        );
+        assert_debug_snapshot!(sheet.create_interface(
+            "package",
+            "ColorInterface",
+            "TypographyInterface",
+            "LengthInterface",
+            "ShadowsInterface",
+            "ThemeInterface",
+            "BreakpointsInterface",
+            &TokenInterfaceNames::default(),
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_responsive_radius_token() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        sheet.set_theme(
+            serde_json::from_str(
+                r#"{"radii": {"default": {"card": ["8px", null, "16px"]}}, "zIndex": {"default": {"modal": 1000}}}"#,
+            )
+            .unwrap(),
+        );
+
+        let output = extract(
+            "test.tsx",
+            r#"import {Box} from '@devup-ui/core'
+<Box borderRadius="$card" zIndex="$modal" />
+"#,
+            ExtractOption {
+                package: "@devup-ui/core".to_string(),
+                css_dir: "@devup-ui/core".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: std::collections::HashMap::new(),
+                strict: false,
+                components: std::collections::HashMap::new(),
+            },
+        )
+        .unwrap();
+        sheet.update_styles(&output.styles, "test.tsx", true);
+        let css = sheet.create_css(None, false);
+        sheet.set_theme(Theme::default());
+
+        assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_theme() {
+        let mut sheet = StyleSheet::default();
+        assert_eq!(sheet.create_theme_layer(), "");
+
+        let diff = sheet.update_theme(
+            serde_json::from_str(r##"{"colors": {"light": {"primary": "#000"}}}"##).unwrap(),
+        );
+        assert_eq!(diff.added, vec!["colors.light.primary"]);
+        assert_eq!(
+            sheet.create_theme_layer(),
+            "@layer t{:root{--primary:#000}}"
+        );
+        assert!(
+            sheet
+                .create_css(None, false)
+                .contains(&sheet.create_theme_layer())
+        );
+
+        let diff = sheet.update_theme(
+            serde_json::from_str(r##"{"colors": {"light": {"primary": "#fff"}}}"##).unwrap(),
+        );
+        assert_eq!(diff.changed, vec!["colors.light.primary"]);
+        assert!(!diff.settings_changed);
+        assert_eq!(
+            sheet.create_theme_layer(),
+            "@layer t{:root{--primary:#FFF}}"
+        );
+        sheet.set_theme(Theme::default());
+    }
+
+    #[test]
+    #[serial]
+    fn test_tailwind_theme() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        sheet.set_theme(
+            serde_json::from_str(
+                r##"{
+                    "tailwind": {
+                        "screens": { "tablet": "768px", "wide": "1400px" },
+                        "extend": { "colors": { "brand": "#123456" }, "spacing": { "18": "4.5rem" } }
+                    }
+                }"##,
+            )
+            .unwrap(),
+        );
+
+        let output = extract(
+            "test.tsx",
+            r#"import {Box} from '@devup-ui/core'
+<Box className="bg-brand p-18 tablet:p-4 wide:flex md:hidden" />
+"#,
+            ExtractOption {
+                package: "@devup-ui/core".to_string(),
+                css_dir: "@devup-ui/core".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: std::collections::HashMap::new(),
+                strict: false,
+                components: std::collections::HashMap::new(),
+            },
+        )
+        .unwrap();
+        sheet.update_styles(&output.styles, "test.tsx", true);
+        let css = sheet.create_css(None, false);
+        sheet.set_theme(Theme::default());
+
+        assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_tailwind_variants() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        sheet.set_theme(
+            serde_json::from_str(
+                r#"{ "tailwind": { "extend": { "screens": { "wide": "1400px" } } } }"#,
+            )
+            .unwrap(),
+        );
+
+        let output = extract(
+            "test.tsx",
+            r#"import {Box} from '@devup-ui/core'
+<Box className="max-md:flex sm:max-lg:grid max-wide:block [&>svg]:w-4 data-[state=open]:p-2 @md:p-4 supports-[display:grid]:grid" />
+"#,
+            ExtractOption {
+                package: "@devup-ui/core".to_string(),
+                css_dir: "@devup-ui/core".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: std::collections::HashMap::new(),
+                strict: false,
+                components: std::collections::HashMap::new(),
+            },
+        )
+        .unwrap();
+        sheet.update_styles(&output.styles, "test.tsx", true);
+        let css = sheet.create_css(None, false);
+        sheet.set_theme(Theme::default());
+
+        assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_tailwind_devup_tokens() {
+        reset_class_map();
+        reset_file_map();
+        let mut sheet = StyleSheet::default();
+        sheet.set_theme(
+            serde_json::from_str(
+                r##"{
+                    "colors": { "light": { "primary": "#000" }, "dark": { "primary": "#fff" } },
+                    "typography": { "heading": { "fontSize": "32px", "fontWeight": 700 } }
+                }"##,
+            )
+            .unwrap(),
+        );
+
+        let output = extract(
+            "test.tsx",
+            r#"import {Box} from '@devup-ui/core'
+<Box className="bg-primary text-heading" />
+"#,
+            ExtractOption {
+                package: "@devup-ui/core".to_string(),
+                css_dir: "@devup-ui/core".to_string(),
+                single_css: true,
+                import_main_css: false,
+                import_aliases: std::collections::HashMap::new(),
+                strict: false,
+                components: std::collections::HashMap::new(),
+            },
+        )
+        .unwrap();
+        sheet.update_styles(&output.styles, "test.tsx", true);
+        let css = sheet.create_css(None, false);
+        sheet.set_theme(Theme::default());
+
+        assert!(output.code.contains("typo-heading"));
+        assert_debug_snapshot!(css.split("*/").nth(1).unwrap());
+    }
 }
@@ -0,0 +1,247 @@
+//! CSS source maps (v3) pointing emitted rules back to the code that wrote them.
+//!
+//! Class names are unique per style, so instead of threading positions through
+//! every writer, the generated CSS is scanned for rule preludes and each one
+//! containing a known class is mapped to where that class was extracted from.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Where the style behind a class name was written
+///
+/// `line` and `column` are 0-based, as in source maps.
+#[derive(Debug, Hash, Eq, PartialEq, Deserialize, Serialize, Clone)]
+pub struct StyleSource {
+    #[serde(rename = "f")]
+    pub file: String,
+    #[serde(rename = "l")]
+    pub line: u32,
+    #[serde(rename = "c")]
+    pub column: u32,
+}
+
+struct Mapping<'a> {
+    line: u32,
+    column: u32,
+    source: &'a StyleSource,
+}
+
+/// Build the source map of `css`, whose class names are looked up in `sources`
+pub(crate) fn create_source_map(css: &str, sources: &BTreeMap<String, StyleSource>) -> String {
+    let mappings = find_mappings(css, sources);
+    let files = mappings
+        .iter()
+        .map(|mapping| mapping.source.file.as_str())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut encoded = String::new();
+    let mut line = 0;
+    let mut previous_column = 0;
+    let mut previous = (0, 0, 0);
+    for mapping in &mappings {
+        if mapping.line == line {
+            if !encoded.is_empty() && !encoded.ends_with(';') {
+                encoded.push(',');
+            }
+        } else {
+            while line < mapping.line {
+                encoded.push(';');
+                line += 1;
+            }
+            previous_column = 0;
+        }
+        let file = files
+            .binary_search(&mapping.source.file.as_str())
+            .unwrap_or_default();
+        let current = (
+            i64::try_from(file).unwrap_or_default(),
+            i64::from(mapping.source.line),
+            i64::from(mapping.source.column),
+        );
+        encode_vlq(&mut encoded, i64::from(mapping.column) - previous_column);
+        encode_vlq(&mut encoded, current.0 - previous.0);
+        encode_vlq(&mut encoded, current.1 - previous.1);
+        encode_vlq(&mut encoded, current.2 - previous.2);
+        previous_column = i64::from(mapping.column);
+        previous = current;
+    }
+
+    serde_json::json!({
+        "version": 3,
+        "sources": files,
+        "names": [],
+        "mappings": encoded,
+    })
+    .to_string()
+}
+
+/// Find every rule prelude naming a known class, in output order
+fn find_mappings<'a>(css: &str, sources: &'a BTreeMap<String, StyleSource>) -> Vec<Mapping<'a>> {
+    let mut mappings = vec![];
+    let mut line = 0;
+    let mut column = 0;
+    // start of the current prelude or declaration
+    let mut segment = Some((0, 0, 0));
+    let mut chars = css.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '/' if chars.peek().is_some_and(|(_, next)| *next == '*') => {
+                // skip comments
+                advance(ch, &mut line, &mut column);
+                chars.next();
+                advance('*', &mut line, &mut column);
+                let mut end = css.len();
+                let mut last = ' ';
+                for (index, ch) in chars.by_ref() {
+                    advance(ch, &mut line, &mut column);
+                    if last == '*' && ch == '/' {
+                        end = index + 1;
+                        break;
+                    }
+                    last = ch;
+                }
+                if segment.is_some() {
+                    segment = Some((end, line, column));
+                }
+                continue;
+            }
+            '"' | '\'' => {
+                // skip strings
+                let mut escaped = false;
+                advance(ch, &mut line, &mut column);
+                for (_, next) in chars.by_ref() {
+                    advance(next, &mut line, &mut column);
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == ch {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '{' => {
+                if let Some((start, start_line, start_column)) = segment
+                    && let Some(source) = find_class(&css[start..index], sources)
+                {
+                    mappings.push(Mapping {
+                        line: start_line,
+                        column: start_column,
+                        source,
+                    });
+                }
+                segment = None;
+            }
+            '}' | ';' => segment = None,
+            _ => {}
+        }
+        advance(ch, &mut line, &mut column);
+        if matches!(ch, '{' | '}' | ';')
+            || (ch.is_whitespace() && segment.is_some_and(|(start, ..)| start == index))
+        {
+            segment = Some((index + ch.len_utf8(), line, column));
+        }
+    }
+    mappings
+}
+
+fn find_class<'a>(
+    prelude: &str,
+    sources: &'a BTreeMap<String, StyleSource>,
+) -> Option<&'a StyleSource> {
+    prelude.match_indices('.').find_map(|(index, _)| {
+        let name = &prelude[index + 1..];
+        let end = name
+            .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '-' || ch == '_'))
+            .unwrap_or(name.len());
+        sources.get(&name[..end])
+    })
+}
+
+const fn advance(ch: char, line: &mut u32, column: &mut u32) {
+    if ch == '\n' {
+        *line += 1;
+        *column = 0;
+    } else {
+        *column += if ch as u32 > 0xFFFF { 2 } else { 1 };
+    }
+}
+
+fn encode_vlq(target: &mut String, value: i64) {
+    let mut value = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = value & 0b1_1111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b10_0000;
+        }
+        target.push(char::from(
+            BASE64[usize::try_from(digit).unwrap_or_default()],
+        ));
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn source(file: &str, line: u32, column: u32) -> StyleSource {
+        StyleSource {
+            file: file.to_string(),
+            line,
+            column,
+        }
+    }
+
+    #[rstest]
+    #[case(0, "A")]
+    #[case(1, "C")]
+    #[case(-1, "D")]
+    #[case(15, "e")]
+    #[case(16, "gB")]
+    #[case(-17, "jB")]
+    #[case(1000, "w+B")]
+    fn test_encode_vlq(#[case] value: i64, #[case] expected: &str) {
+        let mut encoded = String::new();
+        encode_vlq(&mut encoded, value);
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn test_create_source_map() {
+        let sources = BTreeMap::from([
+            ("a".to_string(), source("src/b.tsx", 3, 4)),
+            ("b".to_string(), source("src/a.tsx", 0, 10)),
+            ("com".to_string(), source("src/c.tsx", 0, 0)),
+        ]);
+        assert_eq!(
+            create_source_map(
+                "/*! https://devup-ui.com */@layer b;.a{color:red}@media(min-width:0.5px){.b:hover{content:\"{.a\"}}\n:root .a{x:y}.c{color:blue}",
+                &sources,
+            ),
+            r#"{"mappings":"oCCGI,qCDHM;ACGN","names":[],"sources":["src/a.tsx","src/b.tsx"],"version":3}"#
+        );
+    }
+
+    #[test]
+    fn test_create_source_map_empty() {
+        assert_eq!(
+            create_source_map(".a{color:red}", &BTreeMap::new()),
+            r#"{"mappings":"","names":[],"sources":[],"version":3}"#
+        );
+    }
+}
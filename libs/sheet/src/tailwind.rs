@@ -0,0 +1,464 @@
+//! Customized Tailwind theme, the `tailwind` key of a devup theme.
+//!
+//! Accepts the `theme` section of a `tailwind.config` (scales replacing the
+//! default ones, `extend` adding to them) and the variables of a v4 `@theme`
+//! block (`--color-brand: #123456`), which extend the default scales unless
+//! reset with `--color-*: initial`.
+use std::collections::HashMap;
+
+use css::breakpoints::ResponsiveKey;
+use css::tailwind_theme::{TailwindScale, TailwindTheme, TailwindValues};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::breakpoints::BreakpointUnit;
+use crate::theme::Theme;
+
+/// Keys of the scales in a `tailwind.config` theme
+const CONFIG_SCALES: [(&str, TailwindScale); 11] = [
+    ("colors", TailwindScale::Colors),
+    ("spacing", TailwindScale::Spacing),
+    ("fontSize", TailwindScale::FontSize),
+    ("fontWeight", TailwindScale::FontWeight),
+    ("borderRadius", TailwindScale::BorderRadius),
+    ("borderWidth", TailwindScale::BorderWidth),
+    ("boxShadow", TailwindScale::BoxShadow),
+    ("opacity", TailwindScale::Opacity),
+    ("zIndex", TailwindScale::ZIndex),
+    ("transitionDuration", TailwindScale::Duration),
+    ("transitionTimingFunction", TailwindScale::Ease),
+];
+
+/// Namespaces of the variables in a v4 `@theme` block
+const THEME_NAMESPACES: [(&str, TailwindScale); 8] = [
+    ("color", TailwindScale::Colors),
+    ("spacing", TailwindScale::Spacing),
+    ("text", TailwindScale::FontSize),
+    ("font-weight", TailwindScale::FontWeight),
+    ("radius", TailwindScale::BorderRadius),
+    ("shadow", TailwindScale::BoxShadow),
+    ("opacity", TailwindScale::Opacity),
+    ("ease", TailwindScale::Ease),
+];
+
+const SCREEN_NAMESPACE: &str = "breakpoint";
+
+/// Width of a screen, from `min` and below `max`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Screen {
+    min: Option<String>,
+    max: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ParsedConfig {
+    scales: HashMap<TailwindScale, TailwindValues<String>>,
+    screens: TailwindValues<Screen>,
+}
+
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct TailwindConfig {
+    raw: Map<String, Value>,
+    #[serde(skip)]
+    parsed: ParsedConfig,
+}
+
+impl<'de> Deserialize<'de> for TailwindConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Map::deserialize(deserializer)?;
+        let parsed = parse_config(&raw).map_err(D::Error::custom)?;
+        Ok(Self { raw, parsed })
+    }
+}
+
+/// Flatten nested values into dashed keys, `DEFAULT` naming the parent
+///
+/// Arrays keep their first item, like the `[size, lineHeight]` of a font size.
+fn flatten(
+    prefix: &str,
+    value: &Value,
+    values: &mut HashMap<String, String>,
+) -> Result<(), String> {
+    match value {
+        Value::String(value) => {
+            values.insert(prefix.to_string(), value.clone());
+        }
+        Value::Number(value) => {
+            values.insert(prefix.to_string(), value.to_string());
+        }
+        Value::Array(items) if !items.is_empty() => flatten(prefix, &items[0], values)?,
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = match (prefix, key.as_str()) {
+                    (_, "DEFAULT") => prefix.to_string(),
+                    ("", key) => key.to_string(),
+                    (prefix, key) => format!("{prefix}-{key}"),
+                };
+                flatten(&key, value, values)?;
+            }
+        }
+        _ => return Err(format!("invalid Tailwind theme value `{value}`")),
+    }
+    Ok(())
+}
+
+fn parse_screen(value: &Value) -> Result<Screen, String> {
+    let width = |value: &Value| match value {
+        Value::String(width) => Ok(width.trim().to_string()),
+        Value::Number(width) => Ok(format!("{width}px")),
+        _ => Err(format!("invalid Tailwind screen `{value}`")),
+    };
+    match value {
+        Value::Object(map) => {
+            let screen = Screen {
+                min: map.get("min").map(width).transpose()?,
+                max: map.get("max").map(width).transpose()?,
+            };
+            if screen.min.is_none() && screen.max.is_none() {
+                return Err(format!("invalid Tailwind screen `{value}`"));
+            }
+            Ok(screen)
+        }
+        value => Ok(Screen {
+            min: Some(width(value)?),
+            max: None,
+        }),
+    }
+}
+
+fn parse_screens(value: &Value, screens: &mut TailwindValues<Screen>) -> Result<(), String> {
+    let Value::Object(map) = value else {
+        return Err(format!("invalid Tailwind screens `{value}`"));
+    };
+    for (name, screen) in map {
+        screens.values.insert(name.clone(), parse_screen(screen)?);
+    }
+    Ok(())
+}
+
+/// Add a `tailwind.config` theme section, replacing the default scales
+fn parse_section(
+    section: &Map<String, Value>,
+    replace: bool,
+    parsed: &mut ParsedConfig,
+) -> Result<(), String> {
+    for (key, scale) in CONFIG_SCALES {
+        if let Some(value) = section.get(key) {
+            if !value.is_object() {
+                return Err(format!("invalid Tailwind theme {key} `{value}`"));
+            }
+            let values = parsed.scales.entry(scale).or_default();
+            values.replace |= replace;
+            flatten("", value, &mut values.values)?;
+        }
+    }
+    if let Some(value) = section.get("screens") {
+        parsed.screens.replace |= replace;
+        parse_screens(value, &mut parsed.screens)?;
+    }
+    Ok(())
+}
+
+/// Add a v4 `@theme` variable like `--color-brand`
+fn parse_variable(name: &str, value: &Value, parsed: &mut ParsedConfig) -> Result<(), String> {
+    let reset = value.as_str() == Some("initial");
+    if let Some(namespace) = name.strip_suffix("-*") {
+        if !reset {
+            return Err(format!(
+                "invalid Tailwind theme variable `--{name}: {value}`"
+            ));
+        }
+        if namespace == SCREEN_NAMESPACE {
+            parsed.screens.replace = true;
+        } else if let Some((_, scale)) = THEME_NAMESPACES.iter().find(|(ns, _)| *ns == namespace) {
+            parsed.scales.entry(*scale).or_default().replace = true;
+        }
+        return Ok(());
+    }
+    if let Some(key) = name
+        .strip_prefix(SCREEN_NAMESPACE)
+        .and_then(|key| key.strip_prefix('-'))
+    {
+        parsed
+            .screens
+            .values
+            .insert(key.to_string(), parse_screen(value)?);
+        return Ok(());
+    }
+    if let Some((key, scale)) = THEME_NAMESPACES.iter().find_map(|(namespace, scale)| {
+        name.strip_prefix(namespace)?
+            .strip_prefix('-')
+            .map(|key| (key, *scale))
+    }) {
+        // `--text-xl--line-height` and other sub-properties
+        if !key.contains("--") {
+            flatten(
+                key,
+                value,
+                &mut parsed.scales.entry(scale).or_default().values,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn parse_config(raw: &Map<String, Value>) -> Result<ParsedConfig, String> {
+    let mut parsed = ParsedConfig::default();
+    let mut extend = None;
+    for (key, value) in raw {
+        if let Some(name) = key.strip_prefix("--") {
+            parse_variable(name, value, &mut parsed)?;
+        } else if key == "extend" {
+            extend = Some(
+                value
+                    .as_object()
+                    .ok_or_else(|| format!("invalid Tailwind theme extend `{value}`"))?,
+            );
+        }
+    }
+    parse_section(raw, true, &mut parsed)?;
+    if let Some(extend) = extend {
+        parse_section(extend, false, &mut parsed)?;
+    }
+    Ok(parsed)
+}
+
+/// `768px`, `48rem` or `48em` in px
+fn width_px(width: &str) -> Option<f64> {
+    let (number, scale) = if let Some(number) = width.strip_suffix("px") {
+        (number, 1.0)
+    } else if let Some(number) = width
+        .strip_suffix("rem")
+        .or_else(|| width.strip_suffix("em"))
+    {
+        (number, 16.0)
+    } else {
+        (width, 1.0)
+    };
+    number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .map(|number| number * scale)
+}
+
+fn css_width(width: &str) -> String {
+    if width.parse::<f64>().is_ok() {
+        format!("{width}px")
+    } else {
+        width.to_string()
+    }
+}
+
+impl TailwindConfig {
+    /// The level of `theme` starting at the width of `screen`, when any
+    fn level(screen: &Screen, theme: &Theme) -> Option<u8> {
+        if theme.desktop_first || screen.max.is_some() {
+            return None;
+        }
+        let width = width_px(screen.min.as_deref()?)?;
+        let scale = match theme.breakpoints.unit() {
+            BreakpointUnit::Px => 1.0,
+            BreakpointUnit::Em => 16.0,
+        };
+        let level = theme.breakpoints.values().iter().position(|breakpoint| {
+            f64::from(*breakpoint).mul_add(scale, -width).abs() < f64::EPSILON
+        })?;
+        u8::try_from(level).ok()
+    }
+
+    /// Where a screen applies: the level of `theme` starting at its width, or
+    /// an at-rule of the responsive mode of `theme`
+    fn responsive_key(screen: &Screen, theme: &Theme) -> ResponsiveKey {
+        if let Some(level) = Self::level(screen, theme) {
+            return ResponsiveKey::Level(level);
+        }
+        let query = [
+            screen
+                .min
+                .as_deref()
+                .map(|min| format!("(min-width:{})", css_width(min))),
+            screen
+                .max
+                .as_deref()
+                .map(|max| format!("(max-width:{})", css_width(max))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("and ");
+        let (kind, query) = theme.responsive_at_rule(query);
+        ResponsiveKey::At(kind, query)
+    }
+
+    /// Scales and screens to resolve Tailwind classes with, screens mapped
+    /// onto the breakpoints of `theme`
+    #[must_use]
+    pub fn to_tailwind_theme(&self, theme: &Theme) -> TailwindTheme {
+        TailwindTheme {
+            scales: self.parsed.scales.clone(),
+            screens: TailwindValues {
+                values: self
+                    .parsed
+                    .screens
+                    .values
+                    .iter()
+                    .map(|(name, screen)| (name.clone(), Self::responsive_key(screen, theme)))
+                    .collect(),
+                replace: self.parsed.screens.replace,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use css::style_selector::AtRuleKind;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn tailwind_theme(tailwind: Value, theme: Value) -> TailwindTheme {
+        let mut theme = theme;
+        theme["tailwind"] = tailwind;
+        serde_json::from_value::<Theme>(theme)
+            .unwrap()
+            .tailwind_theme()
+    }
+
+    fn scale(theme: &TailwindTheme, scale: TailwindScale) -> (Vec<(String, String)>, bool) {
+        let values = &theme.scales[&scale];
+        let mut entries = values.values.clone().into_iter().collect::<Vec<_>>();
+        entries.sort();
+        (entries, values.replace)
+    }
+
+    #[test]
+    fn test_config_scales() {
+        let theme = tailwind_theme(
+            json!({
+                "colors": { "brand": { "DEFAULT": "#123456", "500": "#654321" }, "white": "#fff" },
+                "extend": {
+                    "spacing": { "18": "4.5rem" },
+                    "fontSize": { "huge": ["5rem", { "lineHeight": "1" }] },
+                    "zIndex": { "modal": 100 }
+                }
+            }),
+            json!({}),
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::Colors),
+            (
+                vec![
+                    ("brand".to_string(), "#123456".to_string()),
+                    ("brand-500".to_string(), "#654321".to_string()),
+                    ("white".to_string(), "#fff".to_string()),
+                ],
+                true
+            )
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::Spacing),
+            (vec![("18".to_string(), "4.5rem".to_string())], false)
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::FontSize),
+            (vec![("huge".to_string(), "5rem".to_string())], false)
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::ZIndex),
+            (vec![("modal".to_string(), "100".to_string())], false)
+        );
+    }
+
+    #[test]
+    fn test_theme_variables() {
+        let theme = tailwind_theme(
+            json!({
+                "--color-*": "initial",
+                "--color-brand-500": "#654321",
+                "--text-huge": "5rem",
+                "--text-huge--line-height": "1",
+                "--font-weight-chunky": 850,
+                "--breakpoint-tablet": "48rem"
+            }),
+            json!({}),
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::Colors),
+            (vec![("brand-500".to_string(), "#654321".to_string())], true)
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::FontSize),
+            (vec![("huge".to_string(), "5rem".to_string())], false)
+        );
+        assert_eq!(
+            scale(&theme, TailwindScale::FontWeight),
+            (vec![("chunky".to_string(), "850".to_string())], false)
+        );
+        assert_eq!(theme.screens.values["tablet"], ResponsiveKey::Level(2));
+        assert!(!theme.screens.replace);
+    }
+
+    #[test]
+    fn test_below() {
+        let theme = tailwind_theme(json!({}), json!({}));
+        assert_eq!(
+            theme.below[&2],
+            (AtRuleKind::Media, "(max-width:767.98px)".to_string())
+        );
+        assert!(!theme.below.contains_key(&0));
+
+        let theme = serde_json::from_value::<Theme>(
+            json!({"responsiveMode": "container", "responsiveContainer": "card"}),
+        )
+        .unwrap()
+        .tailwind_theme();
+        assert_eq!(
+            theme.below[&1],
+            (
+                AtRuleKind::Container,
+                "card (max-width:479.98px)".to_string()
+            )
+        );
+    }
+
+    #[rstest]
+    #[case(json!("768px"), json!({}), ResponsiveKey::Level(2))]
+    #[case(json!(992), json!({}), ResponsiveKey::Level(3))]
+    #[case(json!("48em"), json!({"breakpoints": {"sm": "30em", "md": "48em"}}), ResponsiveKey::Level(2))]
+    #[case(json!("700px"), json!({}), ResponsiveKey::At(AtRuleKind::Media, "(min-width:700px)".to_string()))]
+    #[case(json!({"max": "767px"}), json!({}), ResponsiveKey::At(AtRuleKind::Media, "(max-width:767px)".to_string()))]
+    #[case(json!({"min": 640, "max": "767px"}), json!({}), ResponsiveKey::At(AtRuleKind::Media, "(min-width:640px)and (max-width:767px)".to_string()))]
+    #[case(json!("768px"), json!({"desktopFirst": true}), ResponsiveKey::At(AtRuleKind::Media, "(min-width:768px)".to_string()))]
+    #[case(json!("700px"), json!({"responsiveMode": "container", "responsiveContainer": "card"}), ResponsiveKey::At(AtRuleKind::Container, "card (min-width:700px)".to_string()))]
+    fn test_screens(#[case] screen: Value, #[case] theme: Value, #[case] expected: ResponsiveKey) {
+        let theme = tailwind_theme(json!({ "screens": { "tablet": screen } }), theme);
+        assert_eq!(theme.screens.values["tablet"], expected);
+        assert!(theme.screens.replace);
+    }
+
+    #[rstest]
+    #[case(json!({ "colors": { "brand": true } }))]
+    #[case(json!({ "screens": { "tablet": {} } }))]
+    #[case(json!({ "screens": ["640px"] }))]
+    #[case(json!({ "extend": "colors" }))]
+    #[case(json!({ "--color-*": "#fff" }))]
+    fn test_invalid_config(#[case] tailwind: Value) {
+        assert!(serde_json::from_value::<Theme>(json!({ "tailwind": tailwind })).is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let config = json!({ "extend": { "colors": { "brand": "#123456" } } });
+        let theme: Theme = serde_json::from_value(json!({ "tailwind": config })).unwrap();
+        assert_eq!(serde_json::to_value(&theme).unwrap()["tailwind"], config);
+    }
+}
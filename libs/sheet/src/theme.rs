@@ -1,7 +1,14 @@
+use crate::breakpoints::Breakpoints;
+use crate::fluid::Fluid;
+use crate::format::CssFormat;
+use crate::tailwind::TailwindConfig;
+use css::breakpoints::ResponsiveKey;
 use css::optimize_value::optimize_value;
+use css::style_selector::AtRuleKind;
+use css::tailwind_theme::TailwindTheme;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Write as _;
 
 /// `ColorEntry` stores both the original key (for TypeScript interface) and CSS key (for CSS variables)
@@ -21,6 +28,10 @@ pub struct ColorEntry {
 /// - Dot notation: `"primary.100": "#000"` -> `interface_key`: "primary.100", `css_key`: "primary-100"
 /// - Nested object: `hello: { 100: "#000" }` -> `interface_key`: "hello.100", `css_key`: "hello-100"
 /// - Deep nested: `gray: { light: { 100: "#000" } }` -> `interface_key`: "gray.light.100", `css_key`: "gray-light-100"
+/// - Alias: `primaryHover: "$primary"` -> value `var(--primary)`
+/// - Derivation: `primaryLight: { "mix": ["$primary", "white", "20%"] }` -> value `color-mix(in srgb,var(--primary),white 20%)`
+///
+/// Circular aliases are deserialization errors.
 #[derive(Default, Serialize, Debug)]
 pub struct ColorTheme {
     /// Map from `css_key` to `ColorEntry` for quick lookup
@@ -29,20 +40,40 @@ pub struct ColorTheme {
 
 /// Recursively flatten a JSON value into `ColorEntry` list
 /// `interface_prefix` uses dots, `css_prefix` uses dashes
+///
+/// The CSS keys each entry references are collected into `refs`.
 fn flatten_color_value(
     interface_prefix: &str,
     css_prefix: &str,
     value: &Value,
     result: &mut HashMap<String, ColorEntry>,
+    refs: &mut HashMap<String, Vec<String>>,
 ) -> Result<(), String> {
     match value {
-        Value::String(s) => {
+        Value::String(_) => {
+            let mut entry_refs = vec![];
+            let value = compile_color(interface_prefix, value, &mut entry_refs)?;
+            refs.insert(css_prefix.to_string(), entry_refs);
             result.insert(
                 css_prefix.to_string(),
                 ColorEntry {
                     interface_key: interface_prefix.to_string(),
                     css_key: css_prefix.to_string(),
-                    value: s.clone(),
+                    value,
+                },
+            );
+            Ok(())
+        }
+        Value::Object(obj) if obj.get("mix").is_some_and(Value::is_array) => {
+            let mut entry_refs = vec![];
+            let value = compile_color_mix(interface_prefix, obj, &mut entry_refs)?;
+            refs.insert(css_prefix.to_string(), entry_refs);
+            result.insert(
+                css_prefix.to_string(),
+                ColorEntry {
+                    interface_key: interface_prefix.to_string(),
+                    css_key: css_prefix.to_string(),
+                    value,
                 },
             );
             Ok(())
@@ -59,7 +90,7 @@ fn flatten_color_value(
                 } else {
                     format!("{}-{}", css_prefix, key.replace('.', "-"))
                 };
-                flatten_color_value(&new_interface_prefix, &new_css_prefix, val, result)?;
+                flatten_color_value(&new_interface_prefix, &new_css_prefix, val, result, refs)?;
             }
             Ok(())
         }
@@ -69,6 +100,112 @@ fn flatten_color_value(
     }
 }
 
+/// A color as CSS, `$primary.100` aliases becoming `var(--primary-100)`
+fn compile_color(key: &str, value: &Value, refs: &mut Vec<String>) -> Result<String, String> {
+    let Value::String(color) = value else {
+        return Err(format!(
+            "color value for key '{key}' must be a string, got {value:?}"
+        ));
+    };
+    Ok(match color.strip_prefix('$') {
+        Some(alias) => {
+            let css_key = alias.replace('.', "-");
+            let var = format!("var(--{css_key})");
+            refs.push(css_key);
+            var
+        }
+        None => color.clone(),
+    })
+}
+
+/// `{ "mix": ["$primary", "white", "20%"], "in": "oklch" }` as
+/// `color-mix(in oklch,var(--primary),white 20%)`, the amount being the
+/// share of the second color
+fn compile_color_mix(
+    key: &str,
+    mix: &serde_json::Map<String, Value>,
+    refs: &mut Vec<String>,
+) -> Result<String, String> {
+    let space = match mix.get("in") {
+        None => "srgb",
+        Some(Value::String(space)) => space,
+        Some(space) => {
+            return Err(format!(
+                "color space for key '{key}' must be a string, got {space:?}"
+            ));
+        }
+    };
+    let (first, second, amount) = match mix.get("mix").and_then(Value::as_array).map(Vec::as_slice)
+    {
+        Some([first, second]) => (first, second, None),
+        Some([first, second, amount]) => (first, second, Some(amount)),
+        _ => {
+            return Err(format!(
+                "mix for key '{key}' must be [color, color] or [color, color, amount]"
+            ));
+        }
+    };
+    let first = compile_color(key, first, refs)?;
+    let second = compile_color(key, second, refs)?;
+    Ok(match amount {
+        None => format!("color-mix(in {space},{first},{second})"),
+        Some(Value::Number(amount)) => format!("color-mix(in {space},{first},{second} {amount}%)"),
+        Some(Value::String(amount)) => format!("color-mix(in {space},{first},{second} {amount})"),
+        Some(amount) => {
+            return Err(format!(
+                "mix amount for key '{key}' must be a number or a percentage, got {amount:?}"
+            ));
+        }
+    })
+}
+
+/// The first alias cycle among `refs`, as interface keys
+fn find_color_cycle(
+    entries: &HashMap<String, ColorEntry>,
+    refs: &HashMap<String, Vec<String>>,
+) -> Option<Vec<String>> {
+    fn visit<'a>(
+        key: &'a str,
+        refs: &'a HashMap<String, Vec<String>>,
+        done: &mut HashSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+    ) -> Option<Vec<&'a str>> {
+        if let Some(start) = stack.iter().position(|visited| *visited == key) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(key);
+            return Some(cycle);
+        }
+        if !done.insert(key) {
+            return None;
+        }
+        stack.push(key);
+        let cycle = refs
+            .get(key)
+            .into_iter()
+            .flatten()
+            .find_map(|next| visit(next, refs, done, stack));
+        stack.pop();
+        cycle
+    }
+
+    let mut keys = refs.keys().collect::<Vec<_>>();
+    keys.sort();
+    let mut done = HashSet::new();
+    keys.into_iter()
+        .find_map(|key| visit(key, refs, &mut done, &mut vec![]))
+        .map(|cycle| {
+            cycle
+                .into_iter()
+                .map(|key| {
+                    entries
+                        .get(key)
+                        .map_or(key, |entry| &entry.interface_key)
+                        .to_string()
+                })
+                .collect()
+        })
+}
+
 impl<'de> Deserialize<'de> for ColorTheme {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -78,10 +215,18 @@ impl<'de> Deserialize<'de> for ColorTheme {
 
         let raw: HashMap<String, Value> = HashMap::deserialize(deserializer)?;
         let mut entries = HashMap::new();
+        let mut refs = HashMap::new();
 
         for (key, value) in raw {
             let css_key = key.replace('.', "-");
-            flatten_color_value(&key, &css_key, &value, &mut entries).map_err(D::Error::custom)?;
+            flatten_color_value(&key, &css_key, &value, &mut entries, &mut refs)
+                .map_err(D::Error::custom)?;
+        }
+        if let Some(cycle) = find_color_cycle(&entries, &refs) {
+            return Err(D::Error::custom(format!(
+                "circular color reference: {}",
+                cycle.join(" -> ")
+            )));
         }
 
         Ok(ColorTheme { entries })
@@ -147,17 +292,44 @@ where
         StringOrNumber::Float(n) => Ok(Some(n.to_string())),
     }
 }
-#[derive(Serialize, Deserialize, Debug)]
+/// Typography properties by JSON key, in the order `typo-*` classes declare them
+const TYPOGRAPHY_PROPERTIES: [(&str, &str); 11] = [
+    ("fontFamily", "font-family"),
+    ("fontSize", "font-size"),
+    ("fontWeight", "font-weight"),
+    ("lineHeight", "line-height"),
+    ("letterSpacing", "letter-spacing"),
+    ("fontStyle", "font-style"),
+    ("textTransform", "text-transform"),
+    ("textDecoration", "text-decoration"),
+    ("fontVariationSettings", "font-variation-settings"),
+    ("fontFeatureSettings", "font-feature-settings"),
+    ("textWrap", "text-wrap"),
+];
+
+#[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Typography {
     pub font_family: Option<String>,
     pub font_size: Option<String>,
-
-    #[serde(deserialize_with = "deserialize_string_from_number", default)]
     pub font_weight: Option<String>,
-    #[serde(deserialize_with = "deserialize_string_from_number", default)]
     pub line_height: Option<String>,
     pub letter_spacing: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_transform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_decoration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_variation_settings: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_feature_settings: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_wrap: Option<String>,
+    /// Values growing with the viewport by JSON key, e.g. `fontSize`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fluid: BTreeMap<String, Fluid>,
 }
 impl Typography {
     #[must_use]
@@ -174,8 +346,95 @@ impl Typography {
             font_weight,
             line_height,
             letter_spacing,
+            font_style: None,
+            text_transform: None,
+            text_decoration: None,
+            font_variation_settings: None,
+            font_feature_settings: None,
+            text_wrap: None,
+            fluid: BTreeMap::new(),
+        }
+    }
+
+    /// The property of a JSON key of [`TYPOGRAPHY_PROPERTIES`]
+    fn property(&self, key: &str) -> Option<&str> {
+        match key {
+            "fontFamily" => self.font_family.as_deref(),
+            "fontSize" => self.font_size.as_deref(),
+            "fontWeight" => self.font_weight.as_deref(),
+            "lineHeight" => self.line_height.as_deref(),
+            "letterSpacing" => self.letter_spacing.as_deref(),
+            "fontStyle" => self.font_style.as_deref(),
+            "textTransform" => self.text_transform.as_deref(),
+            "textDecoration" => self.text_decoration.as_deref(),
+            "fontVariationSettings" => self.font_variation_settings.as_deref(),
+            "fontFeatureSettings" => self.font_feature_settings.as_deref(),
+            "textWrap" => self.text_wrap.as_deref(),
+            _ => None,
         }
     }
+
+    fn property_mut(&mut self, key: &str) -> Option<&mut Option<String>> {
+        match key {
+            "fontFamily" => Some(&mut self.font_family),
+            "fontSize" => Some(&mut self.font_size),
+            "fontWeight" => Some(&mut self.font_weight),
+            "lineHeight" => Some(&mut self.line_height),
+            "letterSpacing" => Some(&mut self.letter_spacing),
+            "fontStyle" => Some(&mut self.font_style),
+            "textTransform" => Some(&mut self.text_transform),
+            "textDecoration" => Some(&mut self.text_decoration),
+            "fontVariationSettings" => Some(&mut self.font_variation_settings),
+            "fontFeatureSettings" => Some(&mut self.font_feature_settings),
+            "textWrap" => Some(&mut self.text_wrap),
+            _ => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.fluid.is_empty()
+            && TYPOGRAPHY_PROPERTIES
+                .iter()
+                .all(|(key, _)| self.property(key).is_none())
+    }
+}
+
+impl<'de> Deserialize<'de> for Typography {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = Value::deserialize(deserializer)?;
+        let Value::Object(obj) = &value else {
+            return Err(D::Error::custom(format!(
+                "Typography must be an object, got: {value:?}"
+            )));
+        };
+        let mut typography = Self::default();
+        for (key, value) in obj {
+            let Some(property) = typography.property_mut(key) else {
+                continue;
+            };
+            if Fluid::is_fluid(value) {
+                let fluid = Fluid::deserialize(value).map_err(D::Error::custom)?;
+                typography.fluid.insert(key.clone(), fluid);
+                continue;
+            }
+            *property = match value {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => {
+                    return Err(D::Error::custom(format!(
+                        "Invalid typography property value: {value:?}"
+                    )));
+                }
+            };
+        }
+        Ok(typography)
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -240,90 +499,48 @@ impl<'de> Deserialize<'de> for Typographies {
             }
             // Compact object format: { fontFamily: "Arial", fontSize: ["16px", null, "20px"], ... }
             Value::Object(obj) => {
-                // Extract each property, which can be a single value or an array
-                let font_family = obj
-                    .get("fontFamily")
-                    .map(deserialize_typo_prop)
-                    .transpose()
-                    .map_err(D::Error::custom)?
-                    .unwrap_or_else(|| vec![None]);
-
-                let font_size = obj
-                    .get("fontSize")
-                    .map(deserialize_typo_prop)
-                    .transpose()
-                    .map_err(D::Error::custom)?
-                    .unwrap_or_else(|| vec![None]);
-
-                let font_weight = obj
-                    .get("fontWeight")
-                    .map(deserialize_typo_prop)
-                    .transpose()
-                    .map_err(D::Error::custom)?
-                    .unwrap_or_else(|| vec![None]);
-
-                let line_height = obj
-                    .get("lineHeight")
-                    .map(deserialize_typo_prop)
-                    .transpose()
-                    .map_err(D::Error::custom)?
-                    .unwrap_or_else(|| vec![None]);
-
-                let letter_spacing = obj
-                    .get("letterSpacing")
-                    .map(deserialize_typo_prop)
-                    .transpose()
-                    .map_err(D::Error::custom)?
-                    .unwrap_or_else(|| vec![None]);
-
-                let font_style = obj
-                    .get("fontStyle")
-                    .map(deserialize_typo_prop)
-                    .transpose()
-                    .map_err(D::Error::custom)?
-                    .unwrap_or_else(|| vec![None]);
+                // Extract each property, which can be a single value, an array or a fluid value
+                let mut fluid = BTreeMap::new();
+                let mut properties = Vec::with_capacity(TYPOGRAPHY_PROPERTIES.len());
+                for (key, _) in TYPOGRAPHY_PROPERTIES {
+                    let Some(value) = obj.get(key) else {
+                        continue;
+                    };
+                    if Fluid::is_fluid(value) {
+                        let value = Fluid::deserialize(value).map_err(D::Error::custom)?;
+                        fluid.insert(key.to_string(), value);
+                    } else {
+                        properties
+                            .push((key, deserialize_typo_prop(value).map_err(D::Error::custom)?));
+                    }
+                }
 
                 // Find the maximum length among all properties
-                let max_len = [
-                    font_family.len(),
-                    font_size.len(),
-                    font_weight.len(),
-                    line_height.len(),
-                    letter_spacing.len(),
-                    font_style.len(),
-                ]
-                .into_iter()
-                .max()
-                .unwrap_or(1);
+                let max_len = properties
+                    .iter()
+                    .map(|(_, values)| values.len())
+                    .max()
+                    .unwrap_or(1);
 
-                // Build typography for each breakpoint level
+                // Build typography for each breakpoint level, fluid values spanning them all
                 let mut result = Vec::with_capacity(max_len);
                 for i in 0..max_len {
-                    let ff = font_family.get(i).cloned().unwrap_or(None);
-                    let fs = font_size.get(i).cloned().unwrap_or(None);
-                    let fw = font_weight.get(i).cloned().unwrap_or(None);
-                    let lh = line_height.get(i).cloned().unwrap_or(None);
-                    let ls = letter_spacing.get(i).cloned().unwrap_or(None);
-                    let fst = font_style.get(i).cloned().unwrap_or(None);
+                    let mut typography = Typography::default();
+                    if i == 0 {
+                        typography.fluid = std::mem::take(&mut fluid);
+                    }
+                    for (key, values) in &properties {
+                        if let Some(property) = typography.property_mut(key) {
+                            *property = values.get(i).cloned().flatten();
+                        }
+                    }
 
                     // If all properties are None for this level, push None
-                    if ff.is_none()
-                        && fs.is_none()
-                        && fw.is_none()
-                        && lh.is_none()
-                        && ls.is_none()
-                        && fst.is_none()
-                    {
-                        result.push(None);
+                    result.push(if typography.is_empty() {
+                        None
                     } else {
-                        result.push(Some(Typography {
-                            font_family: ff,
-                            font_size: fs,
-                            font_weight: fw,
-                            line_height: lh,
-                            letter_spacing: ls,
-                        }));
-                    }
+                        Some(typography)
+                    });
                 }
 
                 Ok(Self(result))
@@ -335,13 +552,47 @@ impl<'de> Deserialize<'de> for Typographies {
     }
 }
 
-/// Responsive theme token values (shared by length and shadow tokens).
+/// Responsive theme token values (shared by every token category but colors and typography).
 /// Supports:
-/// - Single string: `"8px"` -> vec![Some("8px")]
-/// - Single number: `4` -> vec![Some("4")]
-/// - Responsive array: `["2px", null, "4px"]` -> vec![Some("2px"), None, Some("4px")]
-#[derive(Serialize, Debug)]
-pub struct TokenValues(pub Vec<Option<String>>);
+/// - Single string: `"8px"` -> `Levels(vec![Some("8px")])`
+/// - Single number: `4` -> `Levels(vec![Some("4")])`
+/// - Responsive array: `["2px", null, "4px"]` -> `Levels(vec![Some("2px"), None, Some("4px")])`
+/// - Fluid length: `{ "min": 16, "max": 32 }` -> `Fluid(..)`, one `clamp()` variable
+#[derive(Debug)]
+pub enum TokenValues {
+    /// One value per breakpoint level, `None` skipping a level
+    Levels(Vec<Option<String>>),
+    Fluid(Fluid),
+}
+
+impl TokenValues {
+    /// The values per breakpoint level, none for a fluid value
+    #[must_use]
+    pub fn levels(&self) -> &[Option<String>] {
+        match self {
+            Self::Levels(levels) => levels,
+            Self::Fluid(_) => &[],
+        }
+    }
+}
+
+impl From<Vec<Option<String>>> for TokenValues {
+    fn from(values: Vec<Option<String>>) -> Self {
+        Self::Levels(values)
+    }
+}
+
+impl Serialize for TokenValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Levels(levels) => levels.serialize(serializer),
+            Self::Fluid(fluid) => fluid.serialize(serializer),
+        }
+    }
+}
 
 impl<'de> Deserialize<'de> for TokenValues {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -350,8 +601,8 @@ impl<'de> Deserialize<'de> for TokenValues {
     {
         let value = Value::deserialize(deserializer)?;
         match &value {
-            Value::String(s) => Ok(Self(vec![Some(s.clone())])),
-            Value::Number(n) => Ok(Self(vec![Some(n.to_string())])),
+            Value::String(s) => Ok(vec![Some(s.clone())].into()),
+            Value::Number(n) => Ok(vec![Some(n.to_string())].into()),
             Value::Array(arr) => {
                 let result = arr
                     .iter()
@@ -365,7 +616,7 @@ impl<'de> Deserialize<'de> for TokenValues {
                         }
                     })
                     .collect::<Result<Vec<_>, _>>()?;
-                Ok(Self(result))
+                Ok(result.into())
             }
             other => Err(serde::de::Error::custom(format!(
                 "Expected string, number, or array, got: {other:?}"
@@ -384,6 +635,12 @@ pub type LengthTheme = BTreeMap<String, TokenValues>;
 /// e.g., `{ "sm": "0 1px 2px rgba(0,0,0,0.1)", "md": ["0 2px 4px rgba(0,0,0,0.1)", null, "0 4px 8px rgba(0,0,0,0.2)"] }`
 pub type ShadowTheme = BTreeMap<String, TokenValues>;
 
+/// `TokenTheme` stores the named tokens of one category for one theme variant
+///
+/// e.g., radii `{ "sm": "4px", "card": [8, null, 16] }` or z-index
+/// `{ "modal": 1000 }`.
+pub type TokenTheme = BTreeMap<String, TokenValues>;
+
 fn default_variant_key<T>(themes: &BTreeMap<String, T>) -> Option<&str> {
     themes
         .keys()
@@ -393,6 +650,44 @@ fn default_variant_key<T>(themes: &BTreeMap<String, T>) -> Option<&str> {
         .map(String::as_str)
 }
 
+/// Levels each token of `categories` has a value at, across variants
+fn token_levels<'a>(
+    categories: impl IntoIterator<Item = &'a BTreeMap<String, TokenTheme>>,
+) -> BTreeMap<String, Vec<u8>> {
+    categories
+        .into_iter()
+        .flat_map(BTreeMap::values)
+        .flat_map(|theme| theme.iter())
+        .fold(
+            BTreeMap::<String, Vec<u8>>::new(),
+            |mut acc, (name, values)| {
+                let entry = acc.entry(name.clone()).or_default();
+                for (idx, value) in values.levels().iter().enumerate() {
+                    if value.is_some()
+                        && let Ok(level) = u8::try_from(idx)
+                        && !entry.contains(&level)
+                    {
+                        entry.push(level);
+                    }
+                }
+                acc
+            },
+        )
+}
+
+/// Base value of `token` in the default variant of `themes`
+fn default_token_value<'a>(
+    themes: &'a BTreeMap<String, TokenTheme>,
+    token: &str,
+) -> Option<&'a str> {
+    themes
+        .get(default_variant_key(themes)?)?
+        .get(token)?
+        .levels()
+        .first()?
+        .as_deref()
+}
+
 /// Convert a JSON number to a length value: `n * 4` + "px".
 fn number_to_length(n: &serde_json::Number) -> String {
     // as_f64() covers both integer and float JSON numbers
@@ -409,8 +704,12 @@ fn number_to_length(n: &serde_json::Number) -> String {
 /// Deserialize a single length token value, converting numbers via `number_to_length`.
 fn deserialize_length_value(value: &Value) -> Result<TokenValues, String> {
     match value {
-        Value::String(s) => Ok(TokenValues(vec![Some(s.clone())])),
-        Value::Number(n) => Ok(TokenValues(vec![Some(number_to_length(n))])),
+        Value::String(s) => Ok(vec![Some(s.clone())].into()),
+        Value::Number(n) => Ok(vec![Some(number_to_length(n))].into()),
+        // plain numbers of a fluid length are pixels
+        Value::Object(_) if Fluid::is_fluid(value) => Fluid::deserialize(value)
+            .map(TokenValues::Fluid)
+            .map_err(|err| err.to_string()),
         Value::Array(arr) => {
             let mut result = Vec::with_capacity(arr.len());
             for item in arr {
@@ -423,10 +722,10 @@ fn deserialize_length_value(value: &Value) -> Result<TokenValues, String> {
                     }
                 }
             }
-            Ok(TokenValues(result))
+            Ok(result.into())
         }
         _ => Err(format!(
-            "Length value must be a string, number, or array, got: {value:?}"
+            "Length value must be a string, number, array or fluid value, got: {value:?}"
         )),
     }
 }
@@ -451,47 +750,416 @@ where
     Ok(result)
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Deserialize one token value of a category, suffixing plain numbers with `unit`
+fn deserialize_token_value(
+    value: &Value,
+    unit: &str,
+    responsive: bool,
+) -> Result<TokenValues, String> {
+    let item = |item: &Value| match item {
+        Value::Null => Ok(None),
+        Value::String(s) => Ok(Some(s.clone())),
+        Value::Number(n) => Ok(Some(format!("{n}{unit}"))),
+        other => Err(format!("Invalid token value: {other:?}")),
+    };
+    match value {
+        Value::Array(arr) if responsive => arr
+            .iter()
+            .map(item)
+            .collect::<Result<Vec<_>, _>>()
+            .map(TokenValues::from),
+        Value::String(_) | Value::Number(_) => item(value).map(|v| vec![v].into()),
+        other if responsive => Err(format!("Expected string, number, or array, got: {other:?}")),
+        other => Err(format!("Expected string or number, got: {other:?}")),
+    }
+}
+
+fn deserialize_token_themes<'de, D>(
+    deserializer: D,
+    unit: &str,
+    responsive: bool,
+) -> Result<BTreeMap<String, TokenTheme>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw: BTreeMap<String, BTreeMap<String, Value>> = BTreeMap::deserialize(deserializer)?;
+    raw.into_iter()
+        .map(|(variant, tokens)| {
+            tokens
+                .into_iter()
+                .map(|(name, value)| {
+                    deserialize_token_value(&value, unit, responsive)
+                        .map(|values| (name, values))
+                        .map_err(serde::de::Error::custom)
+                })
+                .collect::<Result<_, _>>()
+                .map(|theme| (variant, theme))
+        })
+        .collect()
+}
+
+/// Responsive radii, plain numbers being pixels
+fn deserialize_radii_themes<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, TokenTheme>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_token_themes(deserializer, "px", true)
+}
+
+/// Durations, plain numbers being milliseconds
+fn deserialize_duration_themes<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, TokenTheme>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_token_themes(deserializer, "ms", false)
+}
+
+/// Single value tokens kept as written
+fn deserialize_plain_themes<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, TokenTheme>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_token_themes(deserializer, "", false)
+}
+
+/// A value of `color-scheme`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ColorSchemeKind {
+    Light,
+    Dark,
+}
+
+impl std::fmt::Display for ColorSchemeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        })
+    }
+}
+
+/// How a color theme applies, by theme name in `colorSchemes`
+///
+/// e.g., `{ "colorScheme": "dark", "prefersColorScheme": "dark" }`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorScheme {
+    /// `color-scheme` of the theme, `light` for the default theme and `dark`
+    /// for the others when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_scheme: Option<ColorSchemeKind>,
+    /// Apply the theme while no `data-theme` is set and the user prefers this
+    /// color scheme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefers_color_scheme: Option<ColorSchemeKind>,
+    /// Apply the theme while no `data-theme` is set and the user prefers this
+    /// contrast (`more`, `less`, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefers_contrast: Option<String>,
+}
+
+impl ColorScheme {
+    /// Media features selecting the theme automatically, if any
+    #[must_use]
+    pub fn media(&self) -> Option<String> {
+        let features = self
+            .prefers_color_scheme
+            .map(|scheme| format!("(prefers-color-scheme:{scheme})"))
+            .into_iter()
+            .chain(
+                self.prefers_contrast
+                    .as_ref()
+                    .map(|contrast| format!("(prefers-contrast:{contrast})")),
+            )
+            .collect::<Vec<_>>();
+        (!features.is_empty()).then(|| features.join("and "))
+    }
+
+    /// Number of media features of [`ColorScheme::media`]
+    fn media_conditions(&self) -> usize {
+        usize::from(self.prefers_color_scheme.is_some())
+            + usize::from(self.prefers_contrast.is_some())
+    }
+}
+
+/// What the levels of responsive arrays respond to
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
+pub enum ResponsiveMode {
+    /// `@media(min-width:…)`: the viewport
+    #[default]
+    Media,
+    /// `@container(min-width:…)`: the nearest size container
+    Container,
+}
+
+/// Changes between two themes, see [`Theme::diff`]
+///
+/// Tokens are listed by path, e.g. `colors.dark.primary` or `typography.h1`.
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeDiff {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    /// Breakpoints, color schemes, the responsive mode, the Tailwind theme,
+    /// the levels of responsive tokens or the color and typography names
+    /// changed, so the extracted styles are stale too
+    pub settings_changed: bool,
+}
+
+impl ThemeDiff {
+    /// Whether the themes compile to the same CSS
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.changed.is_empty()
+            && self.removed.is_empty()
+            && !self.settings_changed
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", remote = "Self")]
 pub struct Theme {
     #[serde(default)]
     pub colors: BTreeMap<String, ColorTheme>,
-    #[serde(default = "default_breakpoints")]
-    pub breakpoints: Vec<u16>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub color_schemes: BTreeMap<String, ColorScheme>,
+    #[serde(default)]
+    pub breakpoints: Breakpoints,
     #[serde(default)]
     pub typography: BTreeMap<String, Typographies>,
     #[serde(default, deserialize_with = "deserialize_length_themes")]
     pub length: BTreeMap<String, LengthTheme>,
     #[serde(default, alias = "shadow")]
     pub shadows: BTreeMap<String, ShadowTheme>,
+    #[serde(
+        default,
+        alias = "radius",
+        deserialize_with = "deserialize_radii_themes"
+    )]
+    pub radii: BTreeMap<String, TokenTheme>,
+    /// Spacing scale, plain numbers being multiplied by 4 like [`Theme::length`]
+    #[serde(default, deserialize_with = "deserialize_length_themes")]
+    pub spacing: BTreeMap<String, TokenTheme>,
+    #[serde(
+        default,
+        alias = "zIndices",
+        deserialize_with = "deserialize_plain_themes"
+    )]
+    pub z_index: BTreeMap<String, TokenTheme>,
+    #[serde(
+        default,
+        alias = "duration",
+        deserialize_with = "deserialize_duration_themes"
+    )]
+    pub durations: BTreeMap<String, TokenTheme>,
+    #[serde(
+        default,
+        alias = "easing",
+        deserialize_with = "deserialize_plain_themes"
+    )]
+    pub easings: BTreeMap<String, TokenTheme>,
+    #[serde(default, deserialize_with = "deserialize_plain_themes")]
+    pub opacity: BTreeMap<String, TokenTheme>,
+    #[serde(default)]
+    pub responsive_mode: ResponsiveMode,
+    /// Container queried in [`ResponsiveMode::Container`], the nearest one if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responsive_container: Option<String>,
+    /// Responsive levels apply below their breakpoint (`max-width`) instead of
+    /// from it, the base level being the widest
+    ///
+    /// Level `i` keeps breakpoint `i`, so level 1 is the narrowest range and
+    /// the levels after the base one widen as they go up.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub desktop_first: bool,
+    /// Tailwind theme the Tailwind classes resolve against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tailwind: Option<TailwindConfig>,
 }
 
-fn default_breakpoints() -> Vec<u16> {
-    vec![0, 480, 768, 992, 1280, 1600]
+impl Serialize for Theme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
 }
 
-impl Default for Theme {
-    fn default() -> Self {
-        Self {
-            colors: Default::default(),
-            breakpoints: default_breakpoints(),
-            typography: BTreeMap::new(),
-            length: BTreeMap::new(),
-            shadows: BTreeMap::new(),
-        }
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let theme = Self::deserialize(deserializer)?;
+        theme
+            .check_token_names()
+            .and_then(|()| theme.check_fluid_bounds())
+            .map_err(serde::de::Error::custom)?;
+        Ok(theme)
     }
 }
 
 impl Theme {
-    pub fn update_breakpoints(&mut self, breakpoints: Vec<u16>) {
-        for (idx, value) in breakpoints.iter().enumerate() {
-            let prev = self.breakpoints.get_mut(idx);
-            if let Some(prev) = prev {
-                *prev = *value;
-            } else {
-                self.breakpoints.push(*value);
+    /// Token categories are all declared as `--{name}`, so a name may only be
+    /// used in one of them
+    fn check_token_names(&self) -> Result<(), String> {
+        let mut categories: HashMap<&str, &str> = HashMap::new();
+        for (category, themes) in [
+            ("length", &self.length),
+            ("shadows", &self.shadows),
+            ("radii", &self.radii),
+            ("spacing", &self.spacing),
+            ("zIndex", &self.z_index),
+            ("durations", &self.durations),
+            ("easings", &self.easings),
+            ("opacity", &self.opacity),
+        ] {
+            for name in themes.values().flat_map(BTreeMap::keys) {
+                match categories.insert(name, category) {
+                    Some(other) if other != category => {
+                        return Err(format!(
+                            "Token `{name}` is defined in both {other} and {category}"
+                        ));
+                    }
+                    _ => {}
+                }
             }
         }
+        Ok(())
+    }
+
+    /// Fluid values may only grow between breakpoints of this theme
+    fn check_fluid_bounds(&self) -> Result<(), String> {
+        let typography = self
+            .typography
+            .values()
+            .flat_map(|typographies| typographies.0.iter().flatten())
+            .flat_map(|typography| typography.fluid.values());
+        let tokens = [&self.length, &self.radii, &self.spacing]
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .flat_map(BTreeMap::values)
+            .filter_map(|values| match values {
+                TokenValues::Fluid(fluid) => Some(fluid),
+                TokenValues::Levels(_) => None,
+            });
+        typography
+            .chain(tokens)
+            .try_for_each(|fluid| fluid.check_bounds(&self.breakpoints))
+    }
+
+    /// The media feature of a responsive level at `breakpoint`: from it, or
+    /// below it when [`Theme::desktop_first`]
+    #[must_use]
+    pub fn breakpoint_feature(&self, breakpoint: u16) -> String {
+        if self.desktop_first {
+            self.breakpoints.max_width(breakpoint)
+        } else {
+            self.breakpoints.min_width(breakpoint)
+        }
+    }
+
+    /// The at-rule prelude applying a responsive level at `breakpoint`
+    ///
+    /// Theme variables on `:root` always use `@media`, as the root element is
+    /// never inside a container.
+    #[must_use]
+    pub fn breakpoint_query(&self, breakpoint: u16) -> String {
+        let feature = self.breakpoint_feature(breakpoint);
+        match (self.responsive_mode, &self.responsive_container) {
+            (ResponsiveMode::Media, _) => format!("@media{feature}"),
+            (ResponsiveMode::Container, None) => format!("@container{feature}"),
+            (ResponsiveMode::Container, Some(name)) => format!("@container {name} {feature}"),
+        }
+    }
+
+    /// Entries of a map by responsive level, in the order they must be emitted
+    /// for the higher priority level to win where several apply
+    ///
+    /// Mobile-first levels cover ever narrower ranges as they go up, so they
+    /// are emitted in order; desktop-first ones are emitted from the widest
+    /// down, after the base level.
+    pub fn cascade_order<'a, K: Default + PartialEq, V>(
+        &self,
+        levels: &'a BTreeMap<K, V>,
+    ) -> Vec<(&'a K, &'a V)> {
+        let mut levels = levels.iter().collect::<Vec<_>>();
+        if self.desktop_first {
+            let base = usize::from(
+                levels
+                    .first()
+                    .is_some_and(|(level, _)| **level == K::default()),
+            );
+            levels[base..].reverse();
+        }
+        levels
+    }
+
+    /// Keys of responsive objects, see [`Breakpoints::responsive_keys`]
+    #[must_use]
+    pub fn responsive_keys(&self) -> HashMap<String, ResponsiveKey> {
+        match self.responsive_mode {
+            ResponsiveMode::Media => self.breakpoints.responsive_keys(AtRuleKind::Media, None),
+            ResponsiveMode::Container => self
+                .breakpoints
+                .responsive_keys(AtRuleKind::Container, self.responsive_container.as_deref()),
+        }
+    }
+
+    /// At-rule of the responsive mode applying `query`
+    #[must_use]
+    pub fn responsive_at_rule(&self, query: String) -> (AtRuleKind, String) {
+        match self.responsive_mode {
+            ResponsiveMode::Media => (AtRuleKind::Media, query),
+            ResponsiveMode::Container => (
+                AtRuleKind::Container,
+                self.responsive_container
+                    .as_deref()
+                    .map_or_else(|| query.clone(), |name| format!("{name} {query}")),
+            ),
+        }
+    }
+
+    /// Scales and screens of [`Theme::tailwind`], see
+    /// [`TailwindConfig::to_tailwind_theme`], and the at-rules below every
+    /// breakpoint for `max-*` prefixes
+    #[must_use]
+    pub fn tailwind_theme(&self) -> TailwindTheme {
+        let mut theme = self
+            .tailwind
+            .as_ref()
+            .map_or_else(TailwindTheme::default, |tailwind| {
+                tailwind.to_tailwind_theme(self)
+            });
+        theme.below = self
+            .breakpoints
+            .values()
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value > 0)
+            .filter_map(|(level, value)| {
+                Some((
+                    u8::try_from(level).ok()?,
+                    self.responsive_at_rule(self.breakpoints.max_width(*value)),
+                ))
+            })
+            .collect();
+        theme
+    }
+
+    pub fn update_breakpoints(&mut self, breakpoints: Vec<u16>) {
+        self.breakpoints.update(breakpoints);
     }
 
     pub fn add_color_theme(&mut self, name: &str, theme: ColorTheme) {
@@ -506,78 +1174,149 @@ impl Theme {
         self.length
             .entry(variant.to_string())
             .or_default()
-            .insert(name.to_string(), TokenValues(values));
+            .insert(name.to_string(), values.into());
     }
 
     pub fn add_shadow(&mut self, variant: &str, name: &str, values: Vec<Option<String>>) {
         self.shadows
             .entry(variant.to_string())
             .or_default()
-            .insert(name.to_string(), TokenValues(values));
+            .insert(name.to_string(), values.into());
+    }
+
+    /// `color-scheme` of color theme `name`, see [`ColorScheme::color_scheme`]
+    #[must_use]
+    pub fn color_scheme_kind(&self, name: &str) -> ColorSchemeKind {
+        self.color_schemes
+            .get(name)
+            .and_then(|scheme| scheme.color_scheme)
+            .unwrap_or_else(|| {
+                if self.get_default_theme().as_deref() == Some(name) {
+                    ColorSchemeKind::Light
+                } else {
+                    ColorSchemeKind::Dark
+                }
+            })
     }
 
     pub fn get_default_theme(&self) -> Option<String> {
         default_variant_key(&self.colors).map(str::to_string)
     }
 
+    /// Responsive levels of the length tokens, including spacing and radii
     #[must_use]
     pub fn get_length_token_levels(&self) -> BTreeMap<String, Vec<u8>> {
-        self.length.values().flat_map(|theme| theme.iter()).fold(
-            BTreeMap::<String, Vec<u8>>::new(),
-            |mut acc, (name, values)| {
-                let entry = acc.entry(name.clone()).or_default();
-                for (idx, value) in values.0.iter().enumerate() {
-                    if value.is_some()
-                        && let Ok(level) = u8::try_from(idx)
-                        && !entry.contains(&level)
-                    {
-                        entry.push(level);
-                    }
-                }
-                acc
-            },
-        )
+        token_levels([&self.length, &self.spacing, &self.radii])
     }
 
     #[must_use]
     pub fn get_shadow_token_levels(&self) -> BTreeMap<String, Vec<u8>> {
-        self.shadows.values().flat_map(|theme| theme.iter()).fold(
-            BTreeMap::<String, Vec<u8>>::new(),
-            |mut acc, (name, values)| {
-                let entry = acc.entry(name.clone()).or_default();
-                for (idx, value) in values.0.iter().enumerate() {
-                    if value.is_some()
-                        && let Ok(level) = u8::try_from(idx)
-                        && !entry.contains(&level)
-                    {
-                        entry.push(level);
-                    }
-                }
-                acc
-            },
-        )
+        token_levels([&self.shadows])
     }
 
+    /// First value of a length token, looked up in length, spacing then radii
     #[must_use]
     pub fn get_default_length_value(&self, token: &str) -> Option<&str> {
-        let default_key = default_variant_key(&self.length)?;
-        self.length
-            .get(default_key)?
-            .get(token)?
-            .0
-            .first()?
-            .as_deref()
+        [&self.length, &self.spacing, &self.radii]
+            .into_iter()
+            .find_map(|themes| default_token_value(themes, token))
     }
 
     #[must_use]
     pub fn get_default_shadow_value(&self, token: &str) -> Option<&str> {
-        let default_key = default_variant_key(&self.shadows)?;
-        self.shadows
-            .get(default_key)?
-            .get(token)?
-            .0
-            .first()?
-            .as_deref()
+        default_token_value(&self.shadows, token)
+    }
+
+    /// Every token by path (`colors.dark.primary`, `typography.h1`,
+    /// `length.default.gutter`, ...) with its value
+    fn tokens(&self) -> BTreeMap<String, Value> {
+        let mut tokens = BTreeMap::new();
+        for (variant, colors) in &self.colors {
+            for entry in colors.entries.values() {
+                tokens.insert(
+                    format!("colors.{variant}.{}", entry.interface_key),
+                    Value::String(entry.value.clone()),
+                );
+            }
+        }
+        for (name, typography) in &self.typography {
+            tokens.insert(
+                format!("typography.{name}"),
+                serde_json::to_value(typography).unwrap_or_default(),
+            );
+        }
+        for (category, themes) in [
+            ("length", &self.length),
+            ("shadows", &self.shadows),
+            ("radii", &self.radii),
+            ("spacing", &self.spacing),
+            ("zIndex", &self.z_index),
+            ("durations", &self.durations),
+            ("easings", &self.easings),
+            ("opacity", &self.opacity),
+        ] {
+            for (variant, theme) in themes {
+                for (name, values) in theme {
+                    tokens.insert(
+                        format!("{category}.{variant}.{name}"),
+                        serde_json::to_value(values).unwrap_or_default(),
+                    );
+                }
+            }
+        }
+        tokens
+    }
+
+    /// What the extracted styles depend on: everything but the token values,
+    /// the levels of responsive length and shadow tokens and the names of
+    /// color and typography tokens included
+    fn settings(&self) -> Value {
+        let color_names = self
+            .colors
+            .values()
+            .flat_map(ColorTheme::css_keys)
+            .collect::<BTreeSet<_>>();
+        let typography_names = self.typography.keys().collect::<BTreeSet<_>>();
+        serde_json::json!([
+            self.breakpoints,
+            self.color_schemes,
+            self.responsive_mode,
+            self.responsive_container,
+            self.desktop_first,
+            self.tailwind,
+            self.get_length_token_levels(),
+            self.get_shadow_token_levels(),
+            color_names,
+            typography_names,
+        ])
+    }
+
+    /// Tokens of `other` added, changed or removed from this theme
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> ThemeDiff {
+        let (before, after) = (self.tokens(), other.tokens());
+        let mut diff = ThemeDiff {
+            settings_changed: self.settings() != other.settings(),
+            ..ThemeDiff::default()
+        };
+        for (path, value) in &after {
+            match before.get(path) {
+                None => diff.added.push(path.clone()),
+                Some(prev) if prev != value => diff.changed.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = before
+            .into_keys()
+            .filter(|path| !after.contains_key(path))
+            .collect();
+        diff
+    }
+
+    /// [`Self::to_css`] in `format`
+    #[must_use]
+    pub fn to_css_with_format(&self, format: CssFormat) -> String {
+        format.apply(&self.to_css()).into_owned()
     }
 
     #[must_use]
@@ -600,49 +1339,59 @@ impl Theme {
                 col
             };
             let single_theme = entries.len() <= 1;
-            // if other theme is exists, should use light-dark function
+            let default_scheme = self.color_scheme_kind(&default_theme_key);
+            // two themes of opposite color schemes share their variables through light-dark()
             let other_theme_key = if entries.len() == 2 {
                 entries
                     .iter()
                     .find(|(k, _)| *k != &default_theme_key)
                     .map(|(k, _)| (*k).clone())
+                    .filter(|k| self.color_scheme_kind(k) != default_scheme)
             } else {
                 None
             };
+            let mut media_declarations = vec![];
             for (theme_name, theme_properties) in entries {
                 let mut theme_contents = String::new();
+                let scheme = self
+                    .color_schemes
+                    .get(theme_name)
+                    .filter(|_| *theme_name != *default_theme_key);
                 let theme_key = if *theme_name == *default_theme_key {
                     None
                 } else {
                     Some(theme_name)
                 };
                 if let Some(theme_key) = theme_key {
-                    theme_declaration.push_str(":root[data-theme=");
+                    // works on any element, so every variable is declared again for its subtree
+                    theme_declaration.push_str("[data-theme=");
                     theme_declaration.push_str(theme_key);
                     theme_declaration.push_str("]{");
-                    push_css_declaration(&mut theme_contents, "color-scheme:dark");
+                    push_css_declaration(
+                        &mut theme_contents,
+                        &format!("color-scheme:{}", self.color_scheme_kind(theme_key)),
+                    );
                 } else {
-                    theme_declaration.push_str(":root{");
+                    theme_declaration.push_str(":root");
                     if !single_theme {
-                        push_css_declaration(&mut theme_contents, "color-scheme:light");
+                        theme_declaration.push_str(",[data-theme=");
+                        theme_declaration.push_str(theme_name);
+                        theme_declaration.push(']');
+                    }
+                    theme_declaration.push('{');
+                    if !single_theme || self.color_schemes.contains_key(theme_name) {
+                        push_css_declaration(
+                            &mut theme_contents,
+                            &format!("color-scheme:{default_scheme}"),
+                        );
                     }
                 }
                 for (prop, value) in theme_properties.css_entries() {
                     let optimized_value = optimize_value(value);
                     if theme_key.is_some() {
-                        if other_theme_key.is_none()
-                            && let Some(default_value) =
-                                self.colors.get(&default_theme_key).and_then(|v| {
-                                    v.get(prop).and_then(|v| {
-                                        if optimize_value(v) == optimized_value {
-                                            None
-                                        } else {
-                                            Some(optimized_value)
-                                        }
-                                    })
-                                })
-                        {
-                            push_css_variable(&mut theme_contents, prop, &default_value);
+                        // light-dark() already follows the color-scheme of the subtree
+                        if other_theme_key.is_none() {
+                            push_css_variable(&mut theme_contents, prop, &optimized_value);
                         }
                     } else {
                         let other_theme_value =
@@ -666,18 +1415,47 @@ impl Theme {
                         theme_contents.push_str(prop);
                         theme_contents.push(':');
                         if let Some(other_theme_value) = other_theme_value {
+                            let (light, dark) = match default_scheme {
+                                ColorSchemeKind::Light => (&optimized_value, &other_theme_value),
+                                ColorSchemeKind::Dark => (&other_theme_value, &optimized_value),
+                            };
                             theme_contents.push_str("light-dark(");
-                            theme_contents.push_str(&optimized_value);
+                            theme_contents.push_str(light);
                             theme_contents.push(',');
-                            theme_contents.push_str(&other_theme_value);
+                            theme_contents.push_str(dark);
                             theme_contents.push(')');
                         } else {
                             theme_contents.push_str(&optimized_value);
                         }
                     }
                 }
+                // colors the theme leaves out fall back to the default ones, not to an outer theme
+                if theme_key.is_some()
+                    && other_theme_key.is_none()
+                    && let Some(default_theme) = self.colors.get(&default_theme_key)
+                {
+                    for (prop, value) in default_theme.css_entries() {
+                        if !theme_properties.contains_key(prop) {
+                            push_css_variable(&mut theme_contents, prop, &optimize_value(value));
+                        }
+                    }
+                }
                 theme_declaration.push_str(&theme_contents);
                 theme_declaration.push('}');
+                // selected by the user preference until a theme is set
+                if let Some(scheme) = scheme
+                    && let Some(media) = scheme.media()
+                {
+                    media_declarations.push((
+                        scheme.media_conditions(),
+                        format!("@media{media}{{:root:not([data-theme]){{{theme_contents}}}}}"),
+                    ));
+                }
+            }
+            // the more conditions a theme needs, the later it comes to win where several match
+            media_declarations.sort_by_key(|(conditions, _)| *conditions);
+            for (_, declaration) in media_declarations {
+                theme_declaration.push_str(&declaration);
             }
         }
         let mut css = theme_declaration;
@@ -693,41 +1471,27 @@ impl Theme {
                         }
                     };
                     let mut css_content = String::new();
-                    push_typography_property(
-                        &mut css_content,
-                        "font-family",
-                        t.font_family.as_deref(),
-                        &resolve,
-                    );
-                    push_typography_property(
-                        &mut css_content,
-                        "font-size",
-                        t.font_size.as_deref(),
-                        &resolve,
-                    );
-                    push_typography_property(
-                        &mut css_content,
-                        "font-weight",
-                        t.font_weight.as_deref(),
-                        &resolve,
-                    );
-                    push_typography_property(
-                        &mut css_content,
-                        "line-height",
-                        t.line_height.as_deref(),
-                        &resolve,
-                    );
-                    push_typography_property(
-                        &mut css_content,
-                        "letter-spacing",
-                        t.letter_spacing.as_deref(),
-                        &resolve,
-                    );
+                    for (key, property) in TYPOGRAPHY_PROPERTIES {
+                        if let Some(fluid) = t.fluid.get(key) {
+                            push_css_declaration(
+                                &mut css_content,
+                                &format!("{property}:{}", fluid.to_clamp(&self.breakpoints)),
+                            );
+                        } else {
+                            push_typography_property(
+                                &mut css_content,
+                                property,
+                                t.property(key),
+                                &resolve,
+                            );
+                        }
+                    }
 
                     if !css_content.is_empty() {
                         let level_css = level_map.entry(idx as u8).or_default();
                         level_css.push_str(".typo-");
-                        level_css.push_str(ty.0);
+                        // `heading.h1` stays one class
+                        level_css.push_str(&ty.0.replace('.', "\\."));
                         level_css.push('{');
                         level_css.push_str(&css_content);
                         level_css.push('}');
@@ -735,30 +1499,39 @@ impl Theme {
                 }
             }
         }
-        for (level, css_vec) in level_map {
-            if level == 0 {
-                css.push_str(&css_vec);
-            } else if let Some(bp) = self.breakpoints.get(level as usize) {
-                write!(css, "@media(min-width:{bp}px)")
-                    .unwrap_or_else(|err| panic!("failed to write CSS into string: {err}"));
+        for (level, css_vec) in self.cascade_order(&level_map) {
+            if *level == 0 {
+                css.push_str(css_vec);
+            } else if let Some(bp) = self.breakpoints.get(*level as usize) {
+                css.push_str(&self.breakpoint_query(bp));
                 css.push('{');
-                css.push_str(&css_vec);
+                css.push_str(css_vec);
                 css.push('}');
             }
         }
         // Generate CSS variables for length tokens
-        Self::write_themed_css_vars(&mut css, &self.length, &self.breakpoints);
+        self.write_themed_css_vars(&mut css, &self.length);
         // Generate CSS variables for shadow tokens
-        Self::write_themed_css_vars(&mut css, &self.shadows, &self.breakpoints);
+        self.write_themed_css_vars(&mut css, &self.shadows);
+        for themes in [
+            &self.radii,
+            &self.spacing,
+            &self.z_index,
+            &self.durations,
+            &self.easings,
+            &self.opacity,
+        ] {
+            self.write_themed_css_vars(&mut css, themes);
+        }
         css
     }
 
     /// Shared helper: generates CSS custom properties from themed token maps.
-    /// Used by both length and shadow tokens (and any future token types with the same shape).
+    /// Used by every token category but colors and typography.
     fn write_themed_css_vars(
+        &self,
         css: &mut String,
         themes: &BTreeMap<String, BTreeMap<String, TokenValues>>,
-        breakpoints: &[u16],
     ) {
         let Some(default_key) = themes
             .keys()
@@ -778,52 +1551,60 @@ impl Theme {
             if ad || bd { bd.cmp(&ad) } else { a.0.cmp(b.0) }
         });
 
+        let default_theme = themes.get(default_key.as_str());
         for (variant_name, token_theme) in &sorted_variants {
-            let is_default = *variant_name == &default_key;
-            let selector = if is_default {
-                ":root".to_string()
+            let selector = if *variant_name == &default_key {
+                if themes.len() > 1 {
+                    format!(":root,[data-theme={variant_name}]")
+                } else {
+                    ":root".to_string()
+                }
             } else {
-                format!(":root[data-theme={variant_name}]")
+                // works on any element, so every token is declared again for its subtree
+                format!("[data-theme={variant_name}]")
             };
 
+            // tokens the variant leaves out fall back to the default ones
+            let mut tokens: BTreeMap<&String, &TokenValues> = default_theme
+                .into_iter()
+                .flat_map(|theme| theme.iter())
+                .collect();
+            tokens.extend(token_theme.iter());
+
             // Group variables by breakpoint level without allocating one String per variable.
             let mut level_map = BTreeMap::<usize, String>::new();
-            for (name, values) in *token_theme {
-                for (idx, val) in values.0.iter().enumerate() {
-                    if let Some(v) = val {
-                        let optimized = optimize_value(v);
-                        let is_same_as_default = !is_default
-                            && themes
-                                .get(default_key.as_str())
-                                .and_then(|dt| dt.get(name))
-                                .and_then(|dv| dv.0.get(idx))
-                                .is_some_and(|dval| {
-                                    dval.as_ref()
-                                        .is_some_and(|d| optimize_value(d) == optimized)
-                                });
-                        if !is_same_as_default {
-                            let vars = level_map.entry(idx).or_default();
-                            if !vars.is_empty() {
-                                vars.push(';');
+            for (name, values) in tokens {
+                // `$space.4` reads `var(--space-4)`
+                let name = name.replace('.', "-");
+                match values {
+                    TokenValues::Levels(levels) => {
+                        for (idx, value) in levels.iter().enumerate() {
+                            if let Some(value) = value {
+                                push_css_variable(
+                                    level_map.entry(idx).or_default(),
+                                    &name,
+                                    &optimize_value(value),
+                                );
                             }
-                            vars.push_str("--");
-                            vars.push_str(name);
-                            vars.push(':');
-                            vars.push_str(&optimized);
                         }
                     }
+                    TokenValues::Fluid(fluid) => push_css_variable(
+                        level_map.entry(0).or_default(),
+                        &name,
+                        &fluid.to_clamp(&self.breakpoints),
+                    ),
                 }
             }
 
-            for (level, vars) in &level_map {
+            for (level, vars) in self.cascade_order(&level_map) {
                 if !vars.is_empty() {
                     if *level == 0 {
                         css.push_str(&selector);
                         css.push('{');
                         css.push_str(vars);
                         css.push('}');
-                    } else if let Some(bp) = breakpoints.get(*level) {
-                        write!(css, "@media(min-width:{bp}px){{")
+                    } else if let Some(bp) = self.breakpoints.get(*level) {
+                        write!(css, "@media{}{{", self.breakpoint_feature(bp))
                             .unwrap_or_else(|err| panic!("failed to write CSS into string: {err}"));
                         css.push_str(&selector);
                         css.push('{');
@@ -977,6 +1758,48 @@ mod tests {
         assert_debug_snapshot!(theme.to_css());
     }
 
+    #[rstest]
+    #[case("{}", "@media(min-width:480px)")]
+    #[case(r#"{"responsiveMode":"container"}"#, "@container(min-width:480px)")]
+    #[case(
+        r#"{"responsiveMode":"container","responsiveContainer":"panel"}"#,
+        "@container panel (min-width:480px)"
+    )]
+    #[case(r#"{"responsiveContainer":"panel"}"#, "@media(min-width:480px)")]
+    #[case(r#"{"desktopFirst":true}"#, "@media(max-width:479.98px)")]
+    #[case(
+        r#"{"desktopFirst":true,"responsiveMode":"container"}"#,
+        "@container(max-width:479.98px)"
+    )]
+    fn test_breakpoint_query(#[case] theme: &str, #[case] expected: &str) {
+        let theme: Theme = serde_json::from_str(theme).unwrap();
+        assert_eq!(theme.breakpoint_query(480), expected);
+    }
+
+    #[rstest]
+    #[case(false, vec![0, 1, 2, 3])]
+    #[case(true, vec![0, 3, 2, 1])]
+    fn test_cascade_order(#[case] desktop_first: bool, #[case] expected: Vec<u8>) {
+        let theme = Theme {
+            desktop_first,
+            ..Theme::default()
+        };
+        let levels = BTreeMap::from([(3u8, "c"), (1, "a"), (0, "base"), (2, "b")]);
+        assert_eq!(
+            theme
+                .cascade_order(&levels)
+                .into_iter()
+                .map(|(level, _)| *level)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        let levels = BTreeMap::from([(2u8, "b"), (1, "a")]);
+        assert_eq!(
+            theme.cascade_order(&levels)[0].1,
+            &if desktop_first { "b" } else { "a" }
+        );
+    }
+
     #[rstest]
     #[case(
         vec![0, 480, 768, 992, 1280],
@@ -993,7 +1816,168 @@ mod tests {
     fn update_breakpoints(#[case] input: Vec<u16>, #[case] expected: Vec<u16>) {
         let mut theme = Theme::default();
         theme.update_breakpoints(input);
-        assert_eq!(theme.breakpoints, expected);
+        assert_eq!(theme.breakpoints.values(), expected);
+    }
+
+    #[test]
+    fn test_color_alias_and_mix() {
+        let theme: Theme = serde_json::from_str(
+            r##"{
+                "colors": {
+                    "light": {
+                        "primary": "#0066ff",
+                        "gray": { "100": "#eee" },
+                        "primaryHover": "$primary",
+                        "surface": "$gray.100",
+                        "primaryLight": { "mix": ["$primary", "white", "20%"] },
+                        "overlay": { "mix": ["$primary", "transparent", 50], "in": "oklch" },
+                        "blend": { "mix": ["red", "$primaryHover"] }
+                    },
+                    "dark": {
+                        "primary": "#3388ff",
+                        "gray": { "100": "#111" },
+                        "primaryHover": "$primary",
+                        "surface": "$gray.100",
+                        "primaryLight": { "mix": ["$primary", "black", "20%"] },
+                        "overlay": { "mix": ["$primary", "transparent", 50], "in": "oklch" },
+                        "blend": { "mix": ["red", "$primaryHover"] }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+        let light = &theme.colors["light"];
+        assert_eq!(light.get("primaryHover").unwrap(), "var(--primary)");
+        assert_eq!(light.get("surface").unwrap(), "var(--gray-100)");
+        assert_eq!(
+            light.get("overlay").unwrap(),
+            "color-mix(in oklch,var(--primary),transparent 50%)"
+        );
+
+        let css = theme.to_css();
+        for declaration in [
+            "--primaryHover:var(--primary)",
+            "--surface:var(--gray-100)",
+            "--primaryLight:light-dark(color-mix(in srgb,var(--primary),white 20%),color-mix(in srgb,var(--primary),black 20%))",
+            "--blend:color-mix(in srgb,red,var(--primaryHover))",
+        ] {
+            assert!(css.contains(declaration), "{declaration} missing in {css}");
+        }
+    }
+
+    #[rstest]
+    #[case(r#"{"a": "$b", "b": "$a"}"#, "circular color reference: a -> b -> a")]
+    #[case(
+        r#"{"a": {"mix": ["$a", "white"]}}"#,
+        "circular color reference: a -> a"
+    )]
+    #[case(
+        r#"{"a": "$b.c", "b": {"c": {"mix": ["red", "$a", "10%"]}}}"#,
+        "circular color reference: a -> b.c -> a"
+    )]
+    #[case(
+        r#"{"a": {"mix": ["red"]}}"#,
+        "mix for key 'a' must be [color, color] or [color, color, amount]"
+    )]
+    #[case(
+        r#"{"a": {"mix": ["red", 1]}}"#,
+        "color value for key 'a' must be a string, got Number(1)"
+    )]
+    #[case(
+        r#"{"a": {"mix": ["red", "blue", true]}}"#,
+        "mix amount for key 'a' must be a number or a percentage, got Bool(true)"
+    )]
+    #[case(
+        r#"{"a": {"mix": ["red", "blue"], "in": 1}}"#,
+        "color space for key 'a' must be a string, got Number(1)"
+    )]
+    fn test_color_theme_deserialization_error(#[case] json: &str, #[case] expected: &str) {
+        assert_eq!(
+            serde_json::from_str::<ColorTheme>(json)
+                .unwrap_err()
+                .to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::four_schemes(
+        r##"{
+            "colors": {
+                "light": { "primary": "#000", "bg": "#fff" },
+                "dark": { "primary": "#fff", "bg": "#000" },
+                "high-contrast": { "primary": "#000", "bg": "#ff0" },
+                "dim": { "primary": "#ddd", "bg": "#333" }
+            },
+            "colorSchemes": {
+                "dark": { "prefersColorScheme": "dark" },
+                "high-contrast": { "colorScheme": "light", "prefersContrast": "more" },
+                "dim": { "colorScheme": "dark", "prefersColorScheme": "dark", "prefersContrast": "less" }
+            }
+        }"##
+    )]
+    #[case::same_scheme(
+        r##"{
+            "colors": {
+                "light": { "primary": "#000" },
+                "high-contrast": { "primary": "#00f" }
+            },
+            "colorSchemes": { "high-contrast": { "colorScheme": "light" } }
+        }"##
+    )]
+    #[case::dark_default(
+        r##"{
+            "colors": {
+                "default": { "primary": "#fff" },
+                "paper": { "primary": "#000" }
+            },
+            "colorSchemes": {
+                "default": { "colorScheme": "dark" },
+                "paper": { "colorScheme": "light", "prefersColorScheme": "light" }
+            }
+        }"##
+    )]
+    #[case::single_dark(
+        r##"{
+            "colors": { "default": { "primary": "#fff" } },
+            "colorSchemes": { "default": { "colorScheme": "dark" } }
+        }"##
+    )]
+    #[case::more_conditions_win(
+        r##"{
+            "colors": {
+                "light": { "primary": "#fff" },
+                "amoled-dim": { "primary": "#000" },
+                "dim": { "primary": "#333" }
+            },
+            "colorSchemes": {
+                "amoled-dim": { "prefersColorScheme": "dark", "prefersContrast": "more" },
+                "dim": { "prefersColorScheme": "dark" }
+            }
+        }"##
+    )]
+    fn test_color_schemes(#[case] theme: &str) {
+        let theme: Theme = serde_json::from_str(theme).unwrap();
+        // variables of one block are not ordered
+        let mut css = String::new();
+        let mut segment = String::new();
+        for c in theme.to_css().chars() {
+            match c {
+                '{' => css.push_str(&segment),
+                '}' => {
+                    let mut declarations = segment.split(';').collect::<Vec<_>>();
+                    declarations.sort_unstable();
+                    css.push_str(&declarations.join(";"));
+                }
+                _ => {
+                    segment.push(c);
+                    continue;
+                }
+            }
+            segment.clear();
+            css.push(c);
+        }
+        assert_debug_snapshot!(css);
     }
 
     #[test]
@@ -1429,6 +2413,54 @@ mod tests {
         assert!(css.contains("font-size:52px"));
     }
 
+    #[test]
+    fn test_typography_text_properties() {
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "typography": {
+                    "caption": {
+                        "fontSize": "12px",
+                        "fontStyle": "italic",
+                        "textTransform": ["uppercase", null, "none"],
+                        "textDecoration": "underline dotted",
+                        "fontVariationSettings": "\"wght\" 450",
+                        "fontFeatureSettings": "\"tnum\", \"ss01\"",
+                        "textWrap": "balance"
+                    },
+                    "quote": [{ "fontStyle": "oblique 10deg", "textWrap": "pretty" }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(theme.to_css());
+    }
+
+    #[test]
+    fn test_fluid_typography() {
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "typography": {
+                    "display": {
+                        "fontSize": { "min": "2rem", "max": "4rem", "from": "sm", "to": "xl" },
+                        "lineHeight": [1.2, null, 1.1]
+                    },
+                    "body": [{ "fontSize": { "min": 14, "max": 18 } }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(theme.to_css());
+
+        let err = serde_json::from_str::<Theme>(
+            r#"{ "typography": { "h1": { "fontSize": { "min": "1rem", "max": "32px" } } } }"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("Fluid value needs min and max in the same unit"));
+    }
+
     #[test]
     fn test_invalid_top_level_array_should_fail() {
         // Top-level array that's not traditional format should fail
@@ -1926,8 +2958,8 @@ mod tests {
 
         let default_length = theme.length.get("default").unwrap();
         let gutter = default_length.get("gutterMd").unwrap();
-        assert_eq!(gutter.0.len(), 1);
-        assert_eq!(gutter.0[0], Some("8px".to_string()));
+        assert_eq!(gutter.levels().len(), 1);
+        assert_eq!(gutter.levels()[0], Some("8px".to_string()));
     }
 
     #[test]
@@ -1945,8 +2977,8 @@ mod tests {
 
         let default_length = theme.length.get("default").unwrap();
         let gap = default_length.get("gap").unwrap();
-        assert_eq!(gap.0.len(), 1);
-        assert_eq!(gap.0[0], Some("16px".to_string()));
+        assert_eq!(gap.levels().len(), 1);
+        assert_eq!(gap.levels()[0], Some("16px".to_string()));
     }
 
     #[test]
@@ -1964,9 +2996,9 @@ mod tests {
 
         let default_length = theme.length.get("default").unwrap();
         let gutter = default_length.get("gutterMd").unwrap();
-        assert_eq!(gutter.0.len(), 2);
-        assert_eq!(gutter.0[0], Some("2px".to_string()));
-        assert_eq!(gutter.0[1], Some("4px".to_string()));
+        assert_eq!(gutter.levels().len(), 2);
+        assert_eq!(gutter.levels()[0], Some("2px".to_string()));
+        assert_eq!(gutter.levels()[1], Some("4px".to_string()));
     }
 
     #[test]
@@ -1984,12 +3016,12 @@ mod tests {
 
         let default_length = theme.length.get("default").unwrap();
         let gutter = default_length.get("gutterLg").unwrap();
-        assert_eq!(gutter.0.len(), 5);
-        assert_eq!(gutter.0[0], Some("8px".to_string()));
-        assert!(gutter.0[1].is_none());
-        assert!(gutter.0[2].is_none());
-        assert!(gutter.0[3].is_none());
-        assert_eq!(gutter.0[4], Some("16px".to_string()));
+        assert_eq!(gutter.levels().len(), 5);
+        assert_eq!(gutter.levels()[0], Some("8px".to_string()));
+        assert!(gutter.levels()[1].is_none());
+        assert!(gutter.levels()[2].is_none());
+        assert!(gutter.levels()[3].is_none());
+        assert_eq!(gutter.levels()[4], Some("16px".to_string()));
     }
 
     #[test]
@@ -2007,10 +3039,10 @@ mod tests {
 
         let default_length = theme.length.get("default").unwrap();
         let gap = default_length.get("gap").unwrap();
-        assert_eq!(gap.0.len(), 3);
-        assert_eq!(gap.0[0], Some("16px".to_string()));
-        assert!(gap.0[1].is_none());
-        assert_eq!(gap.0[2], Some("32px".to_string()));
+        assert_eq!(gap.levels().len(), 3);
+        assert_eq!(gap.levels()[0], Some("16px".to_string()));
+        assert!(gap.levels()[1].is_none());
+        assert_eq!(gap.levels()[2], Some("32px".to_string()));
     }
 
     #[test]
@@ -2119,14 +3151,121 @@ mod tests {
     }
 
     #[test]
-    fn test_length_css_generation_variant_skips_same_values() {
+    fn test_theme_diff() {
+        let before: Theme = serde_json::from_str(
+            r##"{
+                "colors": { "light": { "primary": "#000", "gray": { "100": "#eee" } } },
+                "typography": { "h1": { "fontSize": "32px" } },
+                "length": { "default": { "gutter": [16, null, 24] } },
+                "zIndex": { "default": { "modal": 1000 } }
+            }"##,
+        )
+        .unwrap();
+        let after: Theme = serde_json::from_str(
+            r##"{
+                "colors": { "light": { "primary": "#111", "gray": { "100": "#eee" } }, "dark": { "primary": "#fff" } },
+                "typography": { "h1": { "fontSize": "32px" } },
+                "length": { "default": { "gutter": [16, null, 32] } }
+            }"##,
+        )
+        .unwrap();
+
+        assert!(before.diff(&before).is_empty());
+        assert_debug_snapshot!(before.diff(&after));
+
+        let moved: Theme = serde_json::from_str(
+            r##"{
+                "colors": { "light": { "primary": "#000", "gray": { "100": "#eee" } } },
+                "typography": { "h1": { "fontSize": "32px" } },
+                "length": { "default": { "gutter": [16, null, 24] } },
+                "zIndex": { "default": { "modal": 1000 } },
+                "breakpoints": { "tablet": 768 }
+            }"##,
+        )
+        .unwrap();
+        let diff = before.diff(&moved);
+        assert!(diff.settings_changed);
+        assert!(diff.added.is_empty() && diff.changed.is_empty() && diff.removed.is_empty());
+    }
+
+    #[rstest]
+    #[case(r#"{ "length": { "default": { "gap": [2, null, 4] } } }"#, true)]
+    #[case(r#"{ "length": { "default": { "gap": 4 } } }"#, false)]
+    #[case(
+        r#"{ "shadows": { "default": { "card": ["0 1px red", "0 2px red"] } } }"#,
+        true
+    )]
+    #[case(r##"{ "length": { "default": { "gap": 2 } }, "colors": { "light": { "primary": "#000" } } }"##, true)]
+    #[case(r#"{ "length": { "default": { "gap": 2 } }, "typography": { "h1": { "fontSize": "32px" } } }"#, true)]
+    fn test_theme_diff_token_settings(#[case] after: &str, #[case] settings_changed: bool) {
+        let before: Theme =
+            serde_json::from_str(r#"{ "length": { "default": { "gap": 2 } } }"#).unwrap();
+        let after: Theme = serde_json::from_str(after).unwrap();
+        assert_eq!(before.diff(&after).settings_changed, settings_changed);
+    }
+
+    #[test]
+    fn test_fluid_unknown_breakpoint() {
+        for json in [
+            r#"{"length": {"default": {"gutter": {"min": 16, "max": 32, "from": "tablt"}}}}"#,
+            r#"{"typography": {"h1": {"fontSize": {"min": 16, "max": 32, "to": "wide"}}}}"#,
+            r#"{"breakpoints": {"tablet": 768}, "spacing": {"default": {"gap": {"min": 1, "max": 2, "from": "md"}}}}"#,
+        ] {
+            let err = serde_json::from_str::<Theme>(json).unwrap_err().to_string();
+            assert!(err.contains("is not a breakpoint"), "{err}");
+        }
+        assert!(
+            serde_json::from_str::<Theme>(
+                r#"{"breakpoints": {"tablet": 768}, "length": {"default": {"gutter": {"min": 16, "max": 32, "from": "tablet"}}}}"#
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_fluid_length_tokens() {
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "length": {
+                    "default": {
+                        "gutter": { "min": 16, "max": 32, "from": 480, "to": 1280 },
+                        "section": { "min": "4rem", "max": "8rem", "from": "md" },
+                        "gap": [2, null, 4]
+                    },
+                    "compact": { "gutter": { "min": 8, "max": 16, "from": 480, "to": 1280 } }
+                },
+                "spacing": { "default": { "page": { "min": "1rem", "max": "3rem" } } }
+            }"#,
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(theme.to_css());
+        let levels = theme.get_length_token_levels();
+        assert_eq!(levels["gutter"], Vec::<u8>::new());
+        assert_eq!(levels["gap"], vec![0, 2]);
+        assert_eq!(theme.get_default_length_value("gutter"), None);
+
+        let err = serde_json::from_str::<Theme>(
+            r#"{ "length": { "default": { "gutter": { "min": "1rem", "max": 32 } } } }"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("Fluid value needs min and max in the same unit"));
+        let err = serde_json::from_str::<Theme>(r#"{ "length": { "default": { "gutter": {} } } }"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Length value must be a string, number, array or fluid value"));
+    }
+
+    #[test]
+    fn test_length_css_generation_variant_redeclares_same_values() {
         let mut theme = Theme::default();
         theme.add_length(
             "default",
             "gutterMd",
             vec![Some("2px".to_string()), Some("4px".to_string())],
         );
-        // Dark variant has same base value as default, different responsive
+        // Dark variant has same base value as default, redeclared for nested themes
         theme.add_length(
             "dark",
             "gutterMd",
@@ -2213,10 +3352,10 @@ mod tests {
         let default_shadows = theme.shadows.get("default").unwrap();
         assert_eq!(default_shadows.len(), 2);
         assert_eq!(
-            default_shadows.get("sm").unwrap().0,
+            default_shadows.get("sm").unwrap().levels(),
             vec![Some("0 1px 2px rgba(0,0,0,0.1)".to_string())]
         );
-        assert_eq!(default_shadows.get("md").unwrap().0.len(), 3);
+        assert_eq!(default_shadows.get("md").unwrap().levels().len(), 3);
     }
 
     #[test]
@@ -2293,7 +3432,7 @@ mod tests {
     fn test_token_values_deserialize_number() {
         // Covers TokenValues::deserialize Number branch (used by shadows)
         let tv: TokenValues = serde_json::from_str("42").unwrap();
-        assert_eq!(tv.0, vec![Some("42".to_string())]);
+        assert_eq!(tv.levels(), vec![Some("42".to_string())]);
     }
 
     #[test]
@@ -2301,7 +3440,7 @@ mod tests {
         // Covers array Number branch in TokenValues::deserialize
         let tv: TokenValues = serde_json::from_str(r#"["a", 10, null]"#).unwrap();
         assert_eq!(
-            tv.0,
+            tv.levels(),
             vec![Some("a".to_string()), Some("10".to_string()), None]
         );
     }
@@ -2382,7 +3521,7 @@ mod tests {
 
         let shadow = theme.shadows.get("light").unwrap().get("card").unwrap();
         assert_eq!(
-            shadow.0,
+            shadow.levels(),
             vec![
                 Some("0 1px 2px #0003".to_string()),
                 None,
@@ -2434,6 +3573,84 @@ mod tests {
         assert_eq!(empty.get_default_shadow_value("card"), None);
     }
 
+    #[test]
+    fn test_token_categories_css_generation() {
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "radii": {
+                    "default": { "sm": 4, "card": ["8px", null, "16px"] },
+                    "dark": { "sm": 4, "card": "12px" }
+                },
+                "spacing": { "default": { "gutter": [4, 6] } },
+                "zIndex": { "default": { "modal": 1000, "toast": "1100" } },
+                "durations": { "default": { "fast": 150, "slow": "0.5s" } },
+                "easings": { "default": { "standard": "cubic-bezier(0.4, 0, 0.2, 1)" } },
+                "opacity": { "default": { "disabled": 0.4 } }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            theme.radii["default"]["card"].levels(),
+            vec![Some("8px".to_string()), None, Some("16px".to_string())]
+        );
+        assert_eq!(
+            theme.spacing["default"]["gutter"].levels()[1].as_deref(),
+            Some("24px")
+        );
+        assert_eq!(
+            theme.durations["default"]["fast"].levels(),
+            vec![Some("150ms".to_string())]
+        );
+        assert_debug_snapshot!(theme.to_css());
+    }
+
+    #[test]
+    fn test_token_categories_levels_and_default_value() {
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "length": { "default": { "gap": "8px" } },
+                "radii": { "default": { "card": ["8px", null, "16px"] } },
+                "spacing": { "default": { "gutter": [4, 6] } }
+            }"#,
+        )
+        .unwrap();
+
+        let levels = theme.get_length_token_levels();
+        assert_eq!(levels["card"], vec![0, 2]);
+        assert_eq!(levels["gutter"], vec![0, 1]);
+        assert_eq!(theme.get_default_length_value("card"), Some("8px"));
+        assert_eq!(theme.get_default_length_value("gutter"), Some("16px"));
+        assert_eq!(theme.get_default_length_value("gap"), Some("8px"));
+        assert_eq!(theme.get_default_length_value("missing"), None);
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"zIndex": {"default": {"modal": [1, 2]}}}"#,
+        "Expected string or number, got: Array"
+    )]
+    #[case(
+        r#"{"radii": {"default": {"sm": true}}}"#,
+        "Expected string, number, or array, got: Bool(true)"
+    )]
+    #[case(
+        r#"{"radii": {"default": {"sm": [true]}}}"#,
+        "Invalid token value: Bool(true)"
+    )]
+    #[case(
+        r#"{"length": {"default": {"gap": "8px"}}, "spacing": {"dark": {"gap": "2px"}}}"#,
+        "Token `gap` is defined in both length and spacing"
+    )]
+    #[case(
+        r#"{"radii": {"default": {"md": "8px"}}, "opacity": {"default": {"md": 0.5}}}"#,
+        "Token `md` is defined in both radii and opacity"
+    )]
+    fn test_token_categories_deserialization_error(#[case] json: &str, #[case] expected: &str) {
+        let error = serde_json::from_str::<Theme>(json).unwrap_err().to_string();
+        assert!(error.starts_with(expected), "{error}");
+    }
+
     // ===== Coverage: push_typography_property edge cases =====
 
     #[test]
@@ -21,7 +21,10 @@ export {
 export type {
   DevupConfig,
   DevupTheme,
+  FluidValue,
   ImportAliases,
+  TailwindScreen,
+  TailwindTheme,
   ThemeColors,
   ThemeTypography,
   Typography,
@@ -1,3 +1,15 @@
+/**
+ * Value growing linearly with the viewport between two breakpoints,
+ * compiled to `clamp()`
+ * `from` and `to` are breakpoint names or widths in px
+ */
+export interface FluidValue {
+  min: string | number
+  max: string | number
+  from?: string | number
+  to?: string | number
+}
+
 /**
  * Typography definition for a single breakpoint or non-responsive typography
  */
@@ -5,9 +17,14 @@ export interface Typography {
   fontFamily?: string
   fontStyle?: string
   fontWeight?: number | string
-  fontSize?: string
-  lineHeight?: number | string
-  letterSpacing?: string
+  fontSize?: string | FluidValue
+  lineHeight?: number | string | FluidValue
+  letterSpacing?: string | FluidValue
+  textTransform?: string
+  textDecoration?: string
+  fontVariationSettings?: string
+  fontFeatureSettings?: string
+  textWrap?: string
 }
 
 /**
@@ -25,11 +42,11 @@ export type ThemeTypography = Record<string, Typography | (Typography | null)[]>
 /**
  * Theme length definition
  * Each theme variant maps length token names to values
- * Values can be a single string/number or responsive array
+ * Values can be a single string/number, responsive array or fluid value
  */
 export type ThemeLength = Record<
   string,
-  Record<string, string | number | (string | number | null)[]>
+  Record<string, string | number | (string | number | null)[] | FluidValue>
 >
 
 /**
@@ -42,14 +59,59 @@ export type ThemeShadows = Record<
   Record<string, string | (string | null)[]>
 >
 
+/**
+ * Theme definition of a token category other than colors, typography,
+ * length and shadows
+ * Each theme variant maps token names to values
+ */
+export type ThemeTokens<V = string | number> = Record<string, Record<string, V>>
+
+/**
+ * How a color theme applies, by theme name
+ */
+export interface ColorScheme {
+  colorScheme?: 'light' | 'dark'
+  prefersColorScheme?: 'light' | 'dark'
+  prefersContrast?: string
+}
+
+/**
+ * Width of a Tailwind screen, from `min` and below `max`
+ */
+export type TailwindScreen =
+  | string
+  | number
+  | { min?: string | number; max?: string | number }
+
+/**
+ * Tailwind theme the Tailwind classes resolve against
+ * Accepts the `theme` section of a `tailwind.config`, where scales replace
+ * the default ones and `extend` adds to them, and the variables of a v4
+ * `@theme` block (`'--color-brand': '#123456'`)
+ * Screens at the width of a breakpoint use its responsive level
+ */
+export interface TailwindTheme {
+  screens?: Record<string, TailwindScreen>
+  extend?: Omit<TailwindTheme, 'extend'>
+  [scale: string]: unknown
+}
+
 /**
  * Theme configuration
  */
 export interface DevupTheme {
   colors?: ThemeColors
+  colorSchemes?: Record<string, ColorScheme>
   typography?: ThemeTypography
   length?: ThemeLength
   shadows?: ThemeShadows
+  radii?: ThemeTokens<string | number | (string | number | null)[]>
+  spacing?: ThemeLength
+  zIndex?: ThemeTokens
+  durations?: ThemeTokens
+  easings?: ThemeTokens<string>
+  opacity?: ThemeTokens
+  tailwind?: TailwindTheme
 }
 
 /**
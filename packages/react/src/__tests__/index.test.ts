@@ -17,6 +17,7 @@ describe('export', () => {
       css: expect.any(Function),
       globalCss: expect.any(Function),
       keyframes: expect.any(Function),
+      recipe: expect.any(Function),
       styled: expect.any(Object),
       stylex: expect.any(Object),
 
@@ -10,7 +10,16 @@ export { ThemeScript } from './components/ThemeScript'
 export { VStack } from './components/VStack'
 export { useTheme } from './hooks/use-theme'
 export type { DevupProps } from './types/props'
-export type { DevupTheme } from './types/theme'
+export type { DevupBreakpoints } from './types/responsive-value'
+export type {
+  DevupTheme,
+  DevupThemeDurations,
+  DevupThemeEasings,
+  DevupThemeOpacity,
+  DevupThemeRadii,
+  DevupThemeSpacing,
+  DevupThemeZIndex,
+} from './types/theme'
 export type {
   DevupThemeTypography,
   DevupThemeTypographyKeys,
@@ -20,6 +29,8 @@ export { getTheme } from './utils/get-theme'
 export { globalCss } from './utils/global-css'
 export { initTheme } from './utils/init-theme'
 export { keyframes } from './utils/keyframes'
+export type { RecipeConfig, RecipeVariantProps } from './utils/recipe'
+export { recipe } from './utils/recipe'
 export { setTheme } from './utils/set-theme'
 export { styled } from './utils/styled'
 export * as stylex from './utils/stylex'
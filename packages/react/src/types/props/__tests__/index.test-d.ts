@@ -1,6 +1,7 @@
 import type { Property } from 'csstype-extra'
 
 import type { ResponsiveValue } from '../../responsive-value'
+import type { DevupThemeRadii, DevupThemeZIndex } from '../../theme'
 import type {
   DevupCommonProps,
   DevupComponentAdditionalProps,
@@ -23,6 +24,19 @@ describe('index', () => {
       .toEqualTypeOf<ResponsiveValue<Property.Background>>()
   })
 
+  it('DevupProps theme tokens', () => {
+    expectTypeOf<DevupProps>()
+      .toHaveProperty('zIndex')
+      .toEqualTypeOf<
+        ResponsiveValue<Property.ZIndex | keyof DevupThemeZIndex>
+      >()
+    expectTypeOf<DevupProps>()
+      .toHaveProperty('borderRadius')
+      .toEqualTypeOf<
+        ResponsiveValue<Property.BorderRadius | keyof DevupThemeRadii>
+      >()
+  })
+
   it('Selectors', () => {
     expectTypeOf<Selectors>().toHaveProperty('&:hover')
   })
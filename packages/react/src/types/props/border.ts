@@ -1,18 +1,21 @@
 import type { Property } from 'csstype-extra'
 
 import type { ResponsiveValue } from '../responsive-value'
+import type { DevupThemeRadii } from '../theme'
+
+type Radius = keyof DevupThemeRadii
 
 export interface DevupUiBorderProps {
   borderBottomRadius?: ResponsiveValue<
-    Property.BorderBottomRightRadius | Property.BorderBottomLeftRadius
+    Property.BorderBottomRightRadius | Property.BorderBottomLeftRadius | Radius
   >
   borderLeftRadius?: ResponsiveValue<
-    Property.BorderBottomLeftRadius | Property.BorderTopLeftRadius
+    Property.BorderBottomLeftRadius | Property.BorderTopLeftRadius | Radius
   >
   borderRightRadius?: ResponsiveValue<
-    Property.BorderBottomRightRadius | Property.BorderTopRightRadius
+    Property.BorderBottomRightRadius | Property.BorderTopRightRadius | Radius
   >
   borderTopRadius?: ResponsiveValue<
-    Property.BorderTopRightRadius | Property.BorderTopLeftRadius
+    Property.BorderTopRightRadius | Property.BorderTopLeftRadius | Radius
   >
 }
@@ -1,21 +1,24 @@
 import type { Property } from 'csstype-extra'
 
 import type { ResponsiveValue } from '../responsive-value'
+import type { DevupThemeSpacing } from '../theme'
+
+type Spacing = keyof DevupThemeSpacing
 
 export interface DevupUiBoxModelProps {
-  m?: ResponsiveValue<Property.Margin>
-  mx?: ResponsiveValue<Property.MarginLeft | Property.MarginRight>
-  my?: ResponsiveValue<Property.MarginTop | Property.MarginBottom>
-  mb?: ResponsiveValue<Property.MarginBottom>
-  ml?: ResponsiveValue<Property.MarginLeft>
-  mr?: ResponsiveValue<Property.MarginRight>
-  mt?: ResponsiveValue<Property.MarginTop>
+  m?: ResponsiveValue<Property.Margin | Spacing>
+  mx?: ResponsiveValue<Property.MarginLeft | Property.MarginRight | Spacing>
+  my?: ResponsiveValue<Property.MarginTop | Property.MarginBottom | Spacing>
+  mb?: ResponsiveValue<Property.MarginBottom | Spacing>
+  ml?: ResponsiveValue<Property.MarginLeft | Spacing>
+  mr?: ResponsiveValue<Property.MarginRight | Spacing>
+  mt?: ResponsiveValue<Property.MarginTop | Spacing>
 
-  p?: ResponsiveValue<Property.Padding>
-  px?: ResponsiveValue<Property.PaddingLeft | Property.PaddingRight>
-  py?: ResponsiveValue<Property.PaddingTop | Property.PaddingBottom>
-  pb?: ResponsiveValue<Property.PaddingBottom>
-  pl?: ResponsiveValue<Property.PaddingLeft>
-  pr?: ResponsiveValue<Property.PaddingRight>
-  pt?: ResponsiveValue<Property.PaddingTop>
+  p?: ResponsiveValue<Property.Padding | Spacing>
+  px?: ResponsiveValue<Property.PaddingLeft | Property.PaddingRight | Spacing>
+  py?: ResponsiveValue<Property.PaddingTop | Property.PaddingBottom | Spacing>
+  pb?: ResponsiveValue<Property.PaddingBottom | Spacing>
+  pl?: ResponsiveValue<Property.PaddingLeft | Spacing>
+  pr?: ResponsiveValue<Property.PaddingRight | Spacing>
+  pt?: ResponsiveValue<Property.PaddingTop | Spacing>
 }
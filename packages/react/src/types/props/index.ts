@@ -13,6 +13,7 @@ import type { DevupUiMotionPathProps } from './motion-path'
 import type { DevupUiPositionProps } from './position'
 import type { DevupSelectorProps, DevupThemeSelectorProps } from './selector'
 import type { DevupUiTextProps } from './text'
+import type { DevupUiTokenProps } from './token'
 
 export interface DevupShortcutsProps
   extends
@@ -25,7 +26,8 @@ export interface DevupShortcutsProps
     DevupUiMotionPathProps,
     DevupUiPositionProps,
     DevupUiMaskProps,
-    DevupUiTextProps {}
+    DevupUiTextProps,
+    DevupUiTokenProps {}
 
 export type DevupCommonProps = Merge<
   {
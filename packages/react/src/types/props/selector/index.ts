@@ -15,9 +15,18 @@ type PascalCase<S extends string> = Capitalize<CamelCase<S>>
 
 export type SelectorProps<T> = ResponsiveValue<T | string | false>
 export type DevupThemeSelectorProps = keyof DevupTheme extends undefined
-  ? Partial<Record<`_theme${string}`, SelectorProps<DevupProps>>>
+  ? Partial<
+      Record<
+        `_theme${string}` | `_scopedTheme${string}`,
+        SelectorProps<DevupProps>
+      >
+    >
   : Partial<
-      Record<`_theme${PascalCase<keyof DevupTheme>}`, SelectorProps<DevupProps>>
+      Record<
+        | `_theme${PascalCase<keyof DevupTheme>}`
+        | `_scopedTheme${PascalCase<keyof DevupTheme>}`,
+        SelectorProps<DevupProps>
+      >
     >
 
 export type NormalizedSelector<T> = Exclude<T, `:-${string}` | `::-${string}`>
@@ -0,0 +1,54 @@
+import type { Property } from 'csstype-extra'
+
+import type { ResponsiveValue } from '../responsive-value'
+import type {
+  DevupThemeDurations,
+  DevupThemeEasings,
+  DevupThemeOpacity,
+  DevupThemeRadii,
+  DevupThemeSpacing,
+  DevupThemeZIndex,
+} from '../theme'
+
+type Radius = keyof DevupThemeRadii
+type Spacing = keyof DevupThemeSpacing
+type Duration = keyof DevupThemeDurations
+type Easing = keyof DevupThemeEasings
+
+export interface DevupUiTokenProps {
+  borderRadius?: ResponsiveValue<Property.BorderRadius | Radius>
+  borderTopLeftRadius?: ResponsiveValue<Property.BorderTopLeftRadius | Radius>
+  borderTopRightRadius?: ResponsiveValue<Property.BorderTopRightRadius | Radius>
+  borderBottomLeftRadius?: ResponsiveValue<
+    Property.BorderBottomLeftRadius | Radius
+  >
+  borderBottomRightRadius?: ResponsiveValue<
+    Property.BorderBottomRightRadius | Radius
+  >
+
+  margin?: ResponsiveValue<Property.Margin | Spacing>
+  marginTop?: ResponsiveValue<Property.MarginTop | Spacing>
+  marginRight?: ResponsiveValue<Property.MarginRight | Spacing>
+  marginBottom?: ResponsiveValue<Property.MarginBottom | Spacing>
+  marginLeft?: ResponsiveValue<Property.MarginLeft | Spacing>
+  padding?: ResponsiveValue<Property.Padding | Spacing>
+  paddingTop?: ResponsiveValue<Property.PaddingTop | Spacing>
+  paddingRight?: ResponsiveValue<Property.PaddingRight | Spacing>
+  paddingBottom?: ResponsiveValue<Property.PaddingBottom | Spacing>
+  paddingLeft?: ResponsiveValue<Property.PaddingLeft | Spacing>
+  gap?: ResponsiveValue<Property.Gap | Spacing>
+  rowGap?: ResponsiveValue<Property.RowGap | Spacing>
+  columnGap?: ResponsiveValue<Property.ColumnGap | Spacing>
+
+  zIndex?: ResponsiveValue<Property.ZIndex | keyof DevupThemeZIndex>
+  opacity?: ResponsiveValue<Property.Opacity | keyof DevupThemeOpacity>
+
+  animationDuration?: ResponsiveValue<Property.AnimationDuration | Duration>
+  transitionDuration?: ResponsiveValue<Property.TransitionDuration | Duration>
+  animationTimingFunction?: ResponsiveValue<
+    Property.AnimationTimingFunction | Easing
+  >
+  transitionTimingFunction?: ResponsiveValue<
+    Property.TransitionTimingFunction | Easing
+  >
+}
@@ -1,4 +1,22 @@
 type Value<T> = T | null | undefined | false
+
+/* eslint-disable @typescript-eslint/no-empty-object-type */
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user breakpoints.
+export interface DevupBreakpoints {}
+
+type BreakpointName = keyof DevupBreakpoints & string
+
+type ResponsiveObject<T> = { base?: T } & {
+  [K in
+    | BreakpointName
+    | `${BreakpointName}Down`
+    | `${BreakpointName}Only`
+    | `${BreakpointName}To${Capitalize<BreakpointName>}`]?: T
+}
+
 export type ResponsiveValue<T> = 0 extends T
-  ? Value<number | T> | Value<number | T>[]
-  : Value<T> | Value<T>[]
+  ?
+      | Value<number | T>
+      | Value<number | T>[]
+      | ResponsiveObject<Value<number | T>>
+  : Value<T> | Value<T>[] | ResponsiveObject<Value<T>>
@@ -1,3 +1,16 @@
 /* eslint-disable @typescript-eslint/no-empty-object-type */
 // biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user themes.
 export interface DevupTheme {}
+
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user radius tokens.
+export interface DevupThemeRadii {}
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user spacing tokens.
+export interface DevupThemeSpacing {}
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user z-index tokens.
+export interface DevupThemeZIndex {}
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user duration tokens.
+export interface DevupThemeDurations {}
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user easing tokens.
+export interface DevupThemeEasings {}
+// biome-ignore lint/suspicious/noEmptyInterface: public module augmentation point for user opacity tokens.
+export interface DevupThemeOpacity {}
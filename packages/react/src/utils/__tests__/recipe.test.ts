@@ -0,0 +1,15 @@
+import { describe, expect, it } from 'bun:test'
+
+import { recipe } from '../recipe'
+
+describe('recipe', () => {
+  it('should throw on the runtime', () => {
+    expect(() =>
+      recipe({
+        base: { p: 1 },
+        variants: { size: { sm: { p: 2 }, lg: { p: 4 } } },
+        defaultVariants: { size: 'sm' },
+      }),
+    ).toThrowError('Cannot run on the runtime')
+  })
+})
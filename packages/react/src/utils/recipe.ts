@@ -0,0 +1,22 @@
+import type { DevupPropsWithTheme } from '../types/props'
+
+type RecipeVariants = Record<string, Record<string, DevupPropsWithTheme>>
+
+type VariantKey<T> = T extends 'true' ? true : T extends 'false' ? false : T
+
+export type RecipeVariantProps<V extends RecipeVariants> = {
+  [K in keyof V]?: VariantKey<keyof V[K]>
+}
+
+export interface RecipeConfig<V extends RecipeVariants> {
+  base?: DevupPropsWithTheme
+  variants?: V
+  compoundVariants?: (RecipeVariantProps<V> & { css: DevupPropsWithTheme })[]
+  defaultVariants?: RecipeVariantProps<V>
+}
+
+export function recipe<V extends RecipeVariants = Record<never, never>>(
+  _config: RecipeConfig<V>,
+): (props?: RecipeVariantProps<V>) => string {
+  throw new Error('Cannot run on the runtime')
+}